@@ -0,0 +1,11 @@
+use std::path::Path;
+
+/// The checked-in corpus (`benches/data/corpus.txt`), one input per line.
+///
+/// Regenerate it with `python3 gen_corpus.py` from `benches/data`.
+pub fn load_corpus() -> Vec<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("benches/data/corpus.txt");
+    let text = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e));
+    text.lines().map(str::to_string).collect()
+}
@@ -0,0 +1,49 @@
+"""Regenerates corpus.txt: 10,000 lines in the mix a real style audit sees.
+
+Roughly half hex, a quarter rgb()/rgba(), then hsl(), keywords and a few
+lines that don't parse. Seeded, so the output is stable.
+"""
+
+import random
+
+NAMES = [
+    "red", "green", "blue", "white", "black", "yellow", "cyan", "magenta",
+    "gray", "grey", "silver", "maroon", "purple", "fuchsia", "lime", "olive",
+    "navy", "teal", "aqua", "orange", "rebeccapurple", "transparent",
+]
+BROKEN = [
+    "#12", "#ggg", "#1234567", "rgb(300, 0, 0)", "rgb(1, 2)", "hsl(120 100 50)",
+    "not a color", "blurple", "rgba(1 2 3 4)", "", "#", "hsl(0, 100%, 50%",
+]
+
+
+def line(rng):
+    kind = rng.random()
+    r, g, b = (rng.randrange(256) for _ in range(3))
+    if kind < 0.35:
+        s = f"#{r:02x}{g:02x}{b:02x}"
+        return s.upper() if rng.random() < 0.2 else s
+    if kind < 0.45:
+        return "#" + "".join(rng.choice("0123456789abcdef") for _ in range(3))
+    if kind < 0.50:
+        return f"#{r:02x}{g:02x}{b:02x}{rng.randrange(256):02x}"
+    if kind < 0.62:
+        return f"rgb({r}, {g}, {b})"
+    if kind < 0.67:
+        return f"rgba({r}, {g}, {b}, {rng.choice(['0', '0.25', '0.5', '.8', '1'])})"
+    if kind < 0.75:
+        return f"rgb({r} {g} {b} / {rng.randrange(101)}%)"
+    if kind < 0.85:
+        h, s, l = rng.randrange(360), rng.randrange(101), rng.randrange(101)
+        return f"hsl({h}, {s}%, {l}%)" if rng.random() < 0.6 else f"hsl({h} {s}% {l}%)"
+    if kind < 0.97:
+        name = rng.choice(NAMES)
+        return name.capitalize() if rng.random() < 0.1 else name
+    return rng.choice(BROKEN)
+
+
+if __name__ == "__main__":
+    rng = random.Random(333)
+    with open("corpus.txt", "w") as f:
+        for _ in range(10_000):
+            f.write(line(rng) + "\n")
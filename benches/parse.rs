@@ -0,0 +1,64 @@
+//! Parser throughput.
+//!
+//! Every benchmark reports elements per second. The target for the hex
+//! path is **at least 5 million parses/sec** (under 200 ns each) on a
+//! modern laptop; the other single-input groups should stay within a
+//! small multiple of that, and the mixed corpus within 10 ms per pass.
+//!
+//! Run with `cargo bench --bench parse`; add `-- --save-baseline before`
+//! and `-- --baseline before` to compare an optimization against main.
+
+mod common;
+
+use std::hint::black_box;
+
+use colourss::parse_color;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+fn single(c: &mut Criterion, group: &str, cases: &[(&str, &str)]) {
+    let mut group = c.benchmark_group(group);
+    group.throughput(Throughput::Elements(1));
+    for (label, input) in cases {
+        group.bench_function(*label, |b| b.iter(|| parse_color(black_box(input))));
+    }
+    group.finish();
+}
+
+fn hex(c: &mut Criterion) {
+    single(c, "hex", &[("short", "#f80"), ("long", "#ff8800"), ("long_alpha", "#ff880080")]);
+}
+
+fn functional(c: &mut Criterion) {
+    single(
+        c,
+        "functional",
+        &[
+            ("rgb_legacy", "rgb(255, 136, 0)"),
+            ("rgb_modern", "rgb(255 136 0 / 50%)"),
+            ("hsl", "hsl(32, 100%, 50%)"),
+        ],
+    );
+}
+
+fn named(c: &mut Criterion) {
+    // the miss walks the whole table and allocates the error message
+    single(c, "named", &[("hit", "rebeccapurple"), ("miss", "blurple")]);
+}
+
+fn corpus(c: &mut Criterion) {
+    let lines = common::load_corpus();
+    let mut group = c.benchmark_group("corpus");
+    group.throughput(Throughput::Elements(lines.len() as u64));
+    group.bench_function("mixed_10k", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter(|line| parse_color(black_box(line)).is_ok())
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, hex, functional, named, corpus);
+criterion_main!(benches);
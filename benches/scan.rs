@@ -0,0 +1,23 @@
+//! Text scanner throughput over the same corpus, joined into one
+//! stylesheet-sized string. Reported in bytes per second.
+
+mod common;
+
+use std::hint::black_box;
+
+use colourss::find_colors;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+fn scan(c: &mut Criterion) {
+    let text = common::load_corpus()
+        .iter()
+        .map(|line| format!("  color: {};\n", line))
+        .collect::<String>();
+    let mut group = c.benchmark_group("scan");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.bench_function("corpus", |b| b.iter(|| find_colors(black_box(&text)).len()));
+    group.finish();
+}
+
+criterion_group!(benches, scan);
+criterion_main!(benches);
@@ -0,0 +1,164 @@
+//! Generates the color tables from the CSV files in `data/`: the CSS
+//! keywords in `src/named.rs` from `named-colors.csv`, and the Material
+//! Design, Tailwind and X11 palettes in `src/material.rs`, `src/tailwind.rs`
+//! and `src/x11.rs` from `material-colors.csv` and so on. Adding a name is
+//! a one-line data change, and a malformed row fails the build instead of
+//! parsing to the wrong color.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+const DATA: &str = "data/named-colors.csv";
+const HEADER: &str = "name,constant,r,g,b,level";
+const MATERIAL_DATA: &str = "data/material-colors.csv";
+const TAILWIND_DATA: &str = "data/tailwind-colors.csv";
+/// From `rgb.txt`, lowercased and without spaces.
+const X11_DATA: &str = "data/x11-colors.csv";
+/// The header of the palette files.
+const PALETTE_HEADER: &str = "name,hex";
+/// `extra` is for names browsers don't know, kept for compatibility.
+const LEVELS: &[&str] = &["css1", "css2", "css3", "css4", "extra"];
+
+struct Keyword<'a> {
+    name: &'a str,
+    constant: &'a str,
+    rgb: [u8; 3],
+    level: &'a str,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", DATA);
+    println!("cargo:rerun-if-changed={}", MATERIAL_DATA);
+    println!("cargo:rerun-if-changed={}", TAILWIND_DATA);
+    println!("cargo:rerun-if-changed={}", X11_DATA);
+
+    write("named_colors.rs", &keywords(&read(DATA)));
+    write("material_colors.rs", &palette(MATERIAL_DATA, "MATERIAL_COLORS"));
+    write("tailwind_colors.rs", &palette(TAILWIND_DATA, "TAILWIND_COLORS"));
+    write("x11_colors.rs", &palette(X11_DATA, "X11_COLORS"));
+}
+
+fn read(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+fn write(file: &str, code: &str) {
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join(file);
+    fs::write(&dest, code).unwrap_or_else(|e| panic!("{}: {}", dest.display(), e));
+}
+
+/// The `color_keywords!` call, `NAMES_BY_VALUE` and `NONSTANDARD_NAMES`.
+fn keywords(data: &str) -> String {
+    let keywords = parse(data);
+
+    let mut out = String::from("color_keywords! {\n");
+    for k in &keywords {
+        if k.level == "extra" {
+            out.push_str("    /// Not a CSS keyword; kept for compatibility, see [`is_standard_name`].\n");
+        }
+        let [r, g, b] = k.rgb;
+        writeln!(out, "    {:?} {} ({}, {}, {}),", k.name, k.constant, r, g, b).unwrap();
+    }
+    out.push_str("}\n\n");
+
+    // the first name for each value, by value, for `Color::name`
+    let mut by_value: BTreeMap<[u8; 3], &str> = BTreeMap::new();
+    for k in &keywords {
+        by_value.entry(k.rgb).or_insert(k.constant);
+    }
+    out.push_str("/// The first name in [`NAMED_COLORS`] for each value, sorted by value.\n");
+    out.push_str("static NAMES_BY_VALUE: &[(Color, &str)] = &[\n");
+    for constant in by_value.values() {
+        let name = &keywords.iter().find(|k| k.constant == *constant).unwrap().name;
+        writeln!(out, "    ({}, {:?}),", constant, name).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("/// Names in [`NAMED_COLORS`] that aren't CSS keywords.\n");
+    out.push_str("const NONSTANDARD_NAMES: &[&str] = &[");
+    let extras: Vec<String> = keywords.iter().filter(|k| k.level == "extra").map(|k| format!("{:?}", k.name)).collect();
+    out.push_str(&extras.join(", "));
+    out.push_str("];\n");
+    out
+}
+
+/// The palette in `path` as a static slice `table`, checked like
+/// [`parse`]: names of lowercase ASCII letters, digits and `-`, in
+/// strictly increasing order, and `#rrggbb` values.
+fn palette(path: &str, table: &str) -> String {
+    let data = read(path);
+    let mut lines = data.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == PALETTE_HEADER => {}
+        _ => panic!("{}:1: expected the header {:?}", path, PALETTE_HEADER),
+    }
+
+    let mut out = format!("static {}: &[(&str, Color)] = &[\n", table);
+    let mut last = "";
+    for (i, line) in lines {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fail = |msg: &str| -> ! { panic!("{}:{}: {}: {:?}", path, i + 1, msg, line) };
+        let Some((name, hex)) = line.split_once(',') else {
+            fail("expected 2 fields")
+        };
+        if name.is_empty() || !name.bytes().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-') {
+            fail("names are lowercase ASCII letters, digits and `-`");
+        }
+        if name <= last {
+            fail("names are sorted and unique");
+        }
+        let digits = hex.strip_prefix('#').filter(|d| d.len() == 6 && d.is_ascii()).unwrap_or_else(|| fail("values are #rrggbb"));
+        let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).unwrap_or_else(|_| fail("values are #rrggbb"));
+        writeln!(out, "    ({:?}, Color::new({}, {}, {})),", name, channel(0), channel(2), channel(4)).unwrap();
+        last = name;
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// The rows of `data`, checked: lowercase ASCII names in strictly
+/// increasing order, channels that fit a byte and a known level.
+fn parse(data: &str) -> Vec<Keyword<'_>> {
+    let mut lines = data.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == HEADER => {}
+        _ => panic!("{}:1: expected the header {:?}", DATA, HEADER),
+    }
+
+    let mut keywords: Vec<Keyword> = Vec::new();
+    for (i, line) in lines {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fail = |msg: &str| -> ! { panic!("{}:{}: {}: {:?}", DATA, i + 1, msg, line) };
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [name, constant, r, g, b, level] = fields[..] else {
+            fail("expected 6 fields")
+        };
+        if name.is_empty() || !name.bytes().all(|c| c.is_ascii_lowercase()) {
+            fail("names are lowercase ASCII letters");
+        }
+        if !constant.bytes().all(|c| c.is_ascii_uppercase() || c == b'_') {
+            fail("constants are uppercase ASCII letters and `_`");
+        }
+        let channel = |c: &str| c.parse::<u8>().unwrap_or_else(|_| fail("channels are 0 to 255"));
+        if !LEVELS.contains(&level) {
+            fail(&format!("the level is one of {}", LEVELS.join(", ")));
+        }
+        if let Some(last) = keywords.last()
+            && last.name >= name
+        {
+            fail("names are sorted and unique");
+        }
+        keywords.push(Keyword { name, constant, rgb: [channel(r), channel(g), channel(b)], level });
+    }
+    keywords
+}
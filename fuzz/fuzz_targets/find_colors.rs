@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let mut last_end = 0;
+        for m in colourss::find_colors(text) {
+            // matches are in order, don't overlap and reparse on their own
+            assert!(last_end <= m.start && m.start < m.end);
+            assert_eq!(colourss::parse_color(&text[m.start..m.end]).unwrap(), m.color);
+            last_end = m.end;
+        }
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        // anything that parses must survive a round trip through hex
+        if let Ok(color) = colourss::parse_color(input) {
+            assert_eq!(colourss::parse_color(&color.to_hex()).unwrap(), color);
+        }
+    }
+});
@@ -0,0 +1,120 @@
+#ifndef COLOURSS_H
+#define COLOURSS_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit by hand. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Input was not a valid hex code.
+ */
+#define COLOURSS_ERR_INVALID_HEX -1
+
+/**
+ * Input was not a valid `rgb()`/`rgba()` call.
+ */
+#define COLOURSS_ERR_INVALID_RGB -2
+
+/**
+ * Input was not a valid `hsl()`/`hsla()` call.
+ */
+#define COLOURSS_ERR_INVALID_HSL -3
+
+/**
+ * A component was out of range.
+ */
+#define COLOURSS_ERR_INVALID_COMPONENT -4
+
+/**
+ * Input was not a known color name.
+ */
+#define COLOURSS_ERR_UNKNOWN_NAME -5
+
+/**
+ * A number inside the input could not be parsed.
+ */
+#define COLOURSS_ERR_PARSE_FAILURE -6
+
+/**
+ * A required pointer argument was null.
+ */
+#define COLOURSS_ERR_NULL_POINTER -7
+
+/**
+ * The input string was not valid UTF-8.
+ */
+#define COLOURSS_ERR_INVALID_UTF8 -8
+
+/**
+ * The output buffer was too small.
+ */
+#define COLOURSS_ERR_BUFFER_TOO_SMALL -9
+
+/**
+ * The library panicked; this is always a bug.
+ */
+#define COLOURSS_ERR_PANIC -10
+
+/**
+ * The input was a CSS-wide keyword like `inherit`, not a color.
+ */
+#define COLOURSS_ERR_CSS_WIDE_KEYWORD -11
+
+/**
+ * A parsed color as seen from C.
+ */
+typedef struct ColourssColor {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+  /**
+   * Alpha in `0.0..=1.0`; `1.0` for inputs without an alpha component.
+   */
+  float a;
+} ColourssColor;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Parses the NUL-terminated string `input` into `*out`.
+ *
+ * Returns `0` on success or a negative `COLOURSS_ERR_*` code; `*out` is
+ * left untouched on failure.
+ *
+ * # Safety
+ *
+ * `input` must be null or point to a NUL-terminated string, and `out`
+ * must be null or valid for writing a `ColourssColor`.
+ */
+int colourss_parse(const char *input, struct ColourssColor *out);
+
+/**
+ * A static, NUL-terminated description of `code`.
+ *
+ * Never returns null; unknown codes get a generic message. The string
+ * must not be freed.
+ */
+const char *colourss_error_message(int code);
+
+/**
+ * Writes `color` as a NUL-terminated lowercase `#rrggbb` into `buf`.
+ *
+ * `len` is the size of `buf` in bytes and must be at least 8. Returns the
+ * number of characters written, not counting the NUL, or a negative
+ * `COLOURSS_ERR_*` code. Alpha is ignored.
+ *
+ * # Safety
+ *
+ * `color` must be null or point to a valid `ColourssColor`, and `buf`
+ * must be null or valid for writing `len` bytes.
+ */
+int colourss_to_hex(const struct ColourssColor *color, char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* COLOURSS_H */
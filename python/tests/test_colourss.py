@@ -0,0 +1,60 @@
+import pytest
+
+import colourss
+from colourss import Color
+
+
+def test_parse_color():
+    assert colourss.parse_color("#ff8000") == (255, 128, 0, 1.0)
+    assert colourss.parse_color("rgba(0, 0, 255, 0.5)") == (0, 0, 255, 0.5)
+    assert colourss.parse_color("  Navy ") == (0, 0, 128, 1.0)
+
+
+def test_parse_color_errors():
+    with pytest.raises(ValueError, match='Unknown color name: "notacolor"'):
+        colourss.parse_color("notacolor")
+    with pytest.raises(ValueError, match='Invalid hex code format in "#12"'):
+        colourss.parse_color("#12")
+    with pytest.raises(ValueError):
+        Color.parse("rgb(300, 0, 0)")
+
+
+def test_color_class():
+    red = Color(255, 0, 0)
+    assert (red.r, red.g, red.b) == (255, 0, 0)
+    assert red == Color.parse("red")
+    assert red.to_hex() == "#ff0000"
+    assert red.to_hsl() == (0.0, 1.0, 0.5)
+    assert red.name() == "red"
+    assert repr(red) == "Color('#ff0000')"
+    assert str(red) == "#ff0000"
+    with pytest.raises(OverflowError):
+        Color(256, 0, 0)
+
+
+def test_manipulation():
+    red = Color(255, 0, 0)
+    assert red.darken(0.5) == Color(0, 0, 0)
+    assert red.lighten(0.5) == Color(255, 255, 255)
+    assert red.desaturate(1.0).to_hex() == "#808080"
+    assert red.rotate_hue(120) == Color(0, 255, 0)
+    assert red.invert() == Color(0, 255, 255)
+    assert red.mix(Color(0, 0, 255), 0.0) == red
+    assert Color(0, 0, 0).is_dark() and Color(255, 255, 255).is_light()
+    assert red.delta_e(red) == 0.0
+
+
+def test_find_colors():
+    text = "a { color: #f00; border: 1px solid navy }"
+    found = colourss.find_colors(text)
+    assert [(text[s:e], c.to_hex()) for s, e, c in found] == [
+        ("#f00", "#ff0000"),
+        ("navy", "#000080"),
+    ]
+
+
+def test_find_colors_offsets_are_code_points():
+    text = "«couleur» — #00ff00"
+    ((start, end, color),) = colourss.find_colors(text)
+    assert text[start:end] == "#00ff00"
+    assert color == Color(0, 255, 0)
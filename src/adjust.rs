@@ -0,0 +1,46 @@
+use crate::hsl::{hsl_to_rgb, rgb_to_hsl};
+use crate::Color;
+
+/// HSL-based adjustments.
+///
+/// Every `amount` is an *absolute* change on the `0.0..=1.0` scale, added
+/// to (or subtracted from) the current value, the same way Sass's
+/// `lighten()`/`darken()` work: `red.darken(0.1)` takes lightness from 50%
+/// to 40%, not to 45%. Results are clamped, so lightening white or
+/// darkening black returns the color unchanged. For exposure-style
+/// changes in linear light, see [`Color::scale_brightness`] and
+/// [`Color::apply_gamma`].
+impl Color {
+    /// Increases HSL lightness by `amount`.
+    pub fn lighten(&self, amount: f32) -> Color {
+        let (h, s, l) = rgb_to_hsl(self);
+        hsl_to_rgb(h, s, l + amount)
+    }
+
+    /// Decreases HSL lightness by `amount`.
+    pub fn darken(&self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Increases HSL saturation by `amount`.
+    ///
+    /// Grays have no hue to saturate towards, so they come out as red-ish
+    /// tints, same as in Sass.
+    pub fn saturate(&self, amount: f32) -> Color {
+        let (h, s, l) = rgb_to_hsl(self);
+        hsl_to_rgb(h, s + amount, l)
+    }
+
+    /// Decreases HSL saturation by `amount`.
+    pub fn desaturate(&self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// Rotates the hue by `degrees` (positive or negative, wraps around).
+    ///
+    /// Grays stay gray.
+    pub fn rotate_hue(&self, degrees: f32) -> Color {
+        let (h, s, l) = rgb_to_hsl(self);
+        hsl_to_rgb(h + degrees, s, l)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::Color;
+
+/// Channel levels of the 6x6x6 color cube (indices 16–231).
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The 16 basic colors, using xterm's default palette.
+const ANSI16: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+fn distance_sq(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// Index (0–5) of the cube level closest to `c`.
+fn nearest_cube_level(c: u8) -> usize {
+    CUBE_LEVELS
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, level)| (c as i32 - **level as i32).abs())
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// Terminal color helpers.
+impl Color {
+    /// Nearest xterm 256-color palette index.
+    ///
+    /// Only the 6x6x6 cube (16–231) and the 24-step gray ramp (232–255)
+    /// are considered, because the first 16 colors are themeable and
+    /// can't be relied on. Each channel snaps to the nearest real cube
+    /// level, and the gray ramp wins when it's closer.
+    pub fn to_ansi256(&self) -> u8 {
+        let rgb = (self.r, self.g, self.b);
+
+        let (ri, gi, bi) = (
+            nearest_cube_level(self.r),
+            nearest_cube_level(self.g),
+            nearest_cube_level(self.b),
+        );
+        let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+        let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+        // gray ramp levels are 8, 18, ..., 238
+        let avg = (self.r as u32 + self.g as u32 + self.b as u32) / 3;
+        let gray_step = (avg.saturating_sub(3) / 10).min(23) as u8;
+        let gray_level = 8 + 10 * gray_step;
+        let gray = (gray_level, gray_level, gray_level);
+
+        if distance_sq(rgb, gray) < distance_sq(rgb, cube) {
+            232 + gray_step
+        } else {
+            cube_index as u8
+        }
+    }
+
+    /// Nearest of the 16 basic ANSI colors (0–15), using xterm's default palette.
+    pub fn to_ansi16(&self) -> u8 {
+        let rgb = (self.r, self.g, self.b);
+        ANSI16
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, c)| distance_sq(rgb, **c))
+            .map(|(i, _)| i as u8)
+            .unwrap_or(0)
+    }
+
+    /// Truecolor escape sequence setting the foreground: `\x1b[38;2;r;g;bm`.
+    pub fn ansi_fg(&self) -> String {
+        format!("\x1b[38;2;{};{};{}m", self.r, self.g, self.b)
+    }
+
+    /// Truecolor escape sequence setting the background: `\x1b[48;2;r;g;bm`.
+    pub fn ansi_bg(&self) -> String {
+        format!("\x1b[48;2;{};{};{}m", self.r, self.g, self.b)
+    }
+}
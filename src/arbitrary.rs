@@ -0,0 +1,147 @@
+//! [`proptest`] support, enabled with the `arbitrary` feature.
+//!
+//! `Color` implements [`Arbitrary`], so `any::<Color>()` works. The
+//! functions here generate syntactically valid color strings paired with
+//! what they should parse to, with whitespace, letter case and separator
+//! style picked at random.
+
+use proptest::prelude::*;
+
+use crate::Color;
+
+impl Arbitrary for Color {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Color>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        any::<(u8, u8, u8)>()
+            .prop_map(|(r, g, b)| Color { r, g, b })
+            .boxed()
+    }
+}
+
+/// Zero to two spaces or tabs.
+fn ws() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop_oneof![Just(' '), Just('\t')], 0..=2)
+        .prop_map(|chars| chars.into_iter().collect())
+}
+
+/// Upper- or lower-cases each character of `s` by the matching bit of `mask`.
+fn random_case(s: &str, mask: u64) -> String {
+    s.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if mask >> (i % 64) & 1 == 1 {
+                c.to_ascii_uppercase()
+            } else {
+                c.to_ascii_lowercase()
+            }
+        })
+        .collect()
+}
+
+/// An alpha value written as a number or a percentage, with the value
+/// the parser should read from it. Only exact steps, so comparisons
+/// don't need a tolerance.
+fn alpha() -> impl Strategy<Value = (f32, String)> {
+    prop_oneof![
+        (0u8..=4).prop_map(|q| (q as f32 / 4.0, format!("{}", q as f32 / 4.0))),
+        (0u8..=4).prop_map(|q| (q as f32 / 4.0, format!("{}%", q * 25))),
+    ]
+}
+
+/// `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` in random case, with the
+/// color and alpha it encodes. Short forms only come up for colors
+/// whose channels have repeated nibbles.
+pub fn hex_string() -> impl Strategy<Value = (Color, f32, String)> {
+    // `0xab` -> `0xaa`, so the value survives being written as one digit
+    fn short(n: u8) -> u8 {
+        n & 0xf0 | n >> 4
+    }
+    (any::<Color>(), any::<u8>(), 0usize..4, any::<u64>()).prop_map(|(c, a, form, mask)| {
+        let (c, a, body) = match form {
+            0 | 1 => {
+                let c = Color { r: short(c.r), g: short(c.g), b: short(c.b) };
+                let body = format!("{:x}{:x}{:x}", c.r >> 4, c.g >> 4, c.b >> 4);
+                if form == 0 {
+                    (c, 255, body)
+                } else {
+                    let a = short(a);
+                    (c, a, format!("{}{:x}", body, a >> 4))
+                }
+            }
+            2 => (c, 255, format!("{:02x}{:02x}{:02x}", c.r, c.g, c.b)),
+            _ => (c, a, format!("{:02x}{:02x}{:02x}{:02x}", c.r, c.g, c.b, a)),
+        };
+        (c, a as f32 / 255.0, format!("#{}", random_case(&body, mask)))
+    })
+}
+
+/// `rgb()`/`rgba()` in legacy comma or modern space syntax, with or
+/// without alpha, plus the color and alpha it should parse to.
+pub fn rgb_string() -> impl Strategy<Value = (Color, f32, String)> {
+    (
+        any::<Color>(),
+        prop::option::of(alpha()),
+        any::<bool>(),
+        any::<u64>(),
+        prop::collection::vec(ws(), 6),
+    )
+        .prop_map(|(c, alpha, legacy, mask, ws)| {
+            let name = random_case(if alpha.is_some() && legacy { "rgba" } else { "rgb" }, mask);
+            let body = if legacy {
+                let mut body = format!(
+                    "{}{}{},{}{}{},{}{}",
+                    ws[0], c.r, ws[1], ws[2], c.g, ws[3], ws[4], c.b
+                );
+                if let Some((_, a)) = &alpha {
+                    body.push_str(&format!(",{}", a));
+                }
+                body
+            } else {
+                let mut body = format!("{}{} {}{} {}{}", ws[0], c.r, ws[1], c.g, ws[2], c.b);
+                if let Some((_, a)) = &alpha {
+                    body.push_str(&format!("{}/{}{}", ws[3], ws[4], a));
+                }
+                body
+            };
+            let a = alpha.map_or(1.0, |(a, _)| a);
+            (c, a, format!("{}({}{})", name, body, ws[5]))
+        })
+}
+
+/// `hsl()`/`hsla()` with integer hue, saturation and lightness, in either
+/// syntax, together with those three values (saturation and lightness in
+/// `0..=100`).
+pub fn hsl_string() -> impl Strategy<Value = ((u16, u8, u8), String)> {
+    (
+        0u16..360,
+        0u8..=100,
+        0u8..=100,
+        any::<bool>(),
+        any::<bool>(),
+        any::<u64>(),
+        prop::collection::vec(ws(), 4),
+    )
+        .prop_map(|(h, s, l, legacy, deg, mask, ws)| {
+            let name = random_case("hsl", mask);
+            let unit = if deg { "deg" } else { "" };
+            let body = if legacy {
+                format!("{}{}{},{}{}%,{}{}%{}", ws[0], h, unit, ws[1], s, ws[2], l, ws[3])
+            } else {
+                format!("{}{}{} {}{}% {}{}%{}", ws[0], h, unit, ws[1], s, ws[2], l, ws[3])
+            };
+            ((h, s, l), format!("{}({})", name, body))
+        })
+}
+
+/// Any of the above, or a color keyword in random case, as a string.
+pub fn color_string() -> impl Strategy<Value = String> {
+    prop_oneof![
+        hex_string().prop_map(|(_, _, s)| s),
+        rgb_string().prop_map(|(_, _, s)| s),
+        hsl_string().prop_map(|(_, s)| s),
+        (prop::sample::select(crate::named::NAMED_COLORS), any::<u64>())
+            .prop_map(|((name, _), mask)| random_case(name, mask)),
+    ]
+}
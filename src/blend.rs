@@ -0,0 +1,108 @@
+use crate::Color;
+
+/// Separable blend modes from the CSS Compositing and Blending spec.
+///
+/// In every formula below `Cb` is the backdrop channel and `Cs` the
+/// source channel, both normalized to `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    /// `Cs`
+    Normal,
+    /// `Cb * Cs`
+    Multiply,
+    /// `Cb + Cs - Cb * Cs`
+    Screen,
+    /// `HardLight` with source and backdrop swapped.
+    Overlay,
+    /// `min(Cb, Cs)`
+    Darken,
+    /// `max(Cb, Cs)`
+    Lighten,
+    /// Brightens the backdrop towards the source: `Cb / (1 - Cs)`.
+    ColorDodge,
+    /// Darkens the backdrop towards the source: `1 - (1 - Cb) / Cs`.
+    ColorBurn,
+    /// `Multiply` or `Screen` depending on the source.
+    HardLight,
+    /// A softer version of `HardLight`.
+    SoftLight,
+    /// `|Cb - Cs|`
+    Difference,
+    /// `Cb + Cs - 2 * Cb * Cs`
+    Exclusion,
+}
+
+impl BlendMode {
+    /// Applies the blend function to one normalized channel.
+    pub(crate) fn apply(self, cb: f32, cs: f32) -> f32 {
+        match self {
+            BlendMode::Normal => cs,
+            BlendMode::Multiply => cb * cs,
+            BlendMode::Screen => screen(cb, cs),
+            BlendMode::Overlay => BlendMode::HardLight.apply(cs, cb),
+            BlendMode::Darken => cb.min(cs),
+            BlendMode::Lighten => cb.max(cs),
+            BlendMode::ColorDodge => {
+                if cb == 0.0 {
+                    0.0
+                } else if cs >= 1.0 {
+                    1.0
+                } else {
+                    (cb / (1.0 - cs)).min(1.0)
+                }
+            }
+            BlendMode::ColorBurn => {
+                if cb >= 1.0 {
+                    1.0
+                } else if cs == 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - cb) / cs).min(1.0)
+                }
+            }
+            BlendMode::HardLight => {
+                if cs <= 0.5 {
+                    cb * 2.0 * cs
+                } else {
+                    screen(cb, 2.0 * cs - 1.0)
+                }
+            }
+            BlendMode::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 {
+                        ((16.0 * cb - 12.0) * cb + 4.0) * cb
+                    } else {
+                        cb.sqrt()
+                    };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            BlendMode::Difference => (cb - cs).abs(),
+            BlendMode::Exclusion => cb + cs - 2.0 * cb * cs,
+        }
+    }
+}
+
+fn screen(cb: f32, cs: f32) -> f32 {
+    cb + cs - cb * cs
+}
+
+impl Color {
+    /// Blends `self` (the source) onto `backdrop` with the given mode.
+    ///
+    /// Channels are normalized to `0.0..=1.0`, blended, and rounded back
+    /// to bytes. Both colors are treated as fully opaque.
+    pub fn blend(&self, backdrop: &Color, mode: BlendMode) -> Color {
+        let channel = |cs: u8, cb: u8| {
+            let v = mode.apply(cb as f32 / 255.0, cs as f32 / 255.0);
+            (v.clamp(0.0, 1.0) * 255.0).round() as u8
+        };
+        Color {
+            r: channel(self.r, backdrop.r),
+            g: channel(self.g, backdrop.g),
+            b: channel(self.b, backdrop.b),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! `colourss check-contrast`: the contrast of every pair in a file.
+//!
+//! Each line holds a text color and a background color, separated by a
+//! comma or by whitespace (`#333 #fff`), or by `--separator`. Either side
+//! can have a label before `=`, as in `text.primary=#333, bg=#fff`, which
+//! is printed next to its hex. Separators inside `rgb()` and the other
+//! functions don't count.
+//!
+//! Every pair gets its ratio and the normal-text AA and AAA verdicts
+//! (see [`contrast`](super::contrast)), and the summary lists the pairs
+//! that fail `--require` (AA if not given), worst first. `--json` prints
+//! one object instead: `{"pairs": [{"line", "foreground", "background",
+//! "ratio", "aa_normal", "aa_large", "aaa_normal", "aaa_large"}],
+//! "errors": [{"line", "input", "error"}], "summary": {"total", "failed",
+//! "worst": [line numbers]}}`, with `foreground_label` and
+//! `background_label` on labelled pairs.
+//!
+//! Exit status is 1 when `--require` is given and a pair fails it, and 2
+//! when a line isn't a pair of colors (the other lines are still checked).
+
+use anyhow::{anyhow, Result};
+use clap::Args;
+use colourss::{parse_color_with, Color, ParseOptions};
+use serde_json::{json, Value};
+use std::process::ExitCode;
+
+use super::contrast::{Level, CHECKS};
+use super::input::read_lines;
+use super::mode::ModeArgs;
+
+#[derive(Args, Debug)]
+pub struct CheckContrastArgs {
+    /// File with one pair of colors per line; stdin if missing or `-`
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    file: Option<String>,
+    /// What separates the two colors, instead of a comma or whitespace
+    #[arg(long, value_name = "SEP")]
+    separator: Option<String>,
+    /// Exit with 1 unless every pair passes this level for normal text
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    require: Option<Level>,
+    /// How many of the failing pairs to list in the summary
+    #[arg(long, value_name = "N", default_value_t = 5)]
+    worst: usize,
+    /// Print the results as a JSON object
+    #[arg(long)]
+    json: bool,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+/// One side of a pair: the color and the label it was given, if any.
+struct Side {
+    label: Option<String>,
+    color: Color,
+}
+
+struct Pair {
+    line: usize,
+    fg: Side,
+    bg: Side,
+    ratio: f32,
+}
+
+pub fn run(args: CheckContrastArgs) -> Result<ExitCode> {
+    let options = args.mode.options();
+    let level = args.require.unwrap_or(Level::Aa);
+    let mut pairs = Vec::new();
+    let mut errors = Vec::new();
+    for (line, text) in read_lines(args.file.as_deref())? {
+        match pair(&text, args.separator.as_deref(), &options) {
+            Ok((fg, bg)) => {
+                let ratio = fg.color.contrast_ratio(&bg.color);
+                pairs.push(Pair { line, fg, bg, ratio });
+            }
+            Err(e) => errors.push((line, text, e)),
+        }
+    }
+
+    let mut failing: Vec<&Pair> = pairs.iter().filter(|p| p.ratio < level.threshold(false)).collect();
+    failing.sort_by(|a, b| a.ratio.total_cmp(&b.ratio));
+    let worst = &failing[..failing.len().min(args.worst)];
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&to_json(&pairs, &errors, failing.len(), worst))?);
+    } else {
+        for (line, _, e) in &errors {
+            eprintln!("Error: line {}: {}", line, e);
+        }
+        for p in &pairs {
+            let verdict = |level: Level| if p.ratio >= level.threshold(false) { "PASS" } else { "FAIL" };
+            println!(
+                "{}: {:>6.2}:1  AA {}  AAA {}  {}",
+                p.line,
+                p.ratio,
+                verdict(Level::Aa),
+                verdict(Level::Aaa),
+                describe(p)
+            );
+        }
+        let name = if level == Level::Aa { "AA" } else { "AAA" };
+        println!("\n{} pair(s) checked, {} below {}.", pairs.len(), failing.len(), name);
+        if !worst.is_empty() {
+            println!("Worst:");
+            for p in worst {
+                println!("  {}: {:.2}:1  {}", p.line, p.ratio, describe(p));
+            }
+        }
+    }
+
+    Ok(if !errors.is_empty() {
+        ExitCode::from(crate::EXIT_ERROR)
+    } else if args.require.is_some() && !failing.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// The two colors on `line`.
+fn pair(line: &str, separator: Option<&str>, options: &ParseOptions) -> Result<(Side, Side)> {
+    let (fg, bg) = split(line, separator).ok_or_else(|| anyhow!("expected two colors"))?;
+    Ok((side(fg, "foreground", options)?, side(bg, "background", options)?))
+}
+
+/// `line` around the first `separator` outside parentheses, or by
+/// default the first comma, or else the first run of whitespace.
+fn split<'a>(line: &'a str, separator: Option<&str>) -> Option<(&'a str, &'a str)> {
+    let line = line.trim();
+    let (at, len) = match separator {
+        Some(sep) => (find_outside_parens(line, |rest| rest.starts_with(sep))?, sep.len()),
+        None => match find_outside_parens(line, |rest| rest.starts_with(',')) {
+            Some(at) => (at, 1),
+            None => {
+                let at = find_outside_parens(line, |rest| rest.starts_with(char::is_whitespace))?;
+                (at, line[at..].len() - line[at..].trim_start().len())
+            }
+        },
+    };
+    let (fg, bg) = (line[..at].trim(), line[at + len..].trim());
+    (!fg.is_empty() && !bg.is_empty()).then_some((fg, bg))
+}
+
+fn find_outside_parens(text: &str, matches: impl Fn(&str) -> bool) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ if depth == 0 && matches(&text[i..]) => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// `label=color` or just `color`.
+fn side(text: &str, what: &str, options: &ParseOptions) -> Result<Side> {
+    let (label, input) = match text.split_once('=') {
+        Some((label, color)) if !label.contains('(') => (Some(label.trim().to_string()), color.trim()),
+        _ => (None, text),
+    };
+    let color = parse_color_with(input, options).map_err(|e| anyhow!("invalid {} color: {}", what, e))?;
+    Ok(Side { label, color })
+}
+
+/// `#333333 on #ffffff`, with labels in front of the hex when given.
+fn describe(pair: &Pair) -> String {
+    let side = |side: &Side| match &side.label {
+        Some(label) => format!("{} ({})", label, side.color.to_hex()),
+        None => side.color.to_hex(),
+    };
+    format!("{} on {}", side(&pair.fg), side(&pair.bg))
+}
+
+fn to_json(pairs: &[Pair], errors: &[(usize, String, anyhow::Error)], failed: usize, worst: &[&Pair]) -> Value {
+    let pairs: Vec<Value> = pairs
+        .iter()
+        .map(|p| {
+            let mut value = json!({
+                "line": p.line,
+                "foreground": p.fg.color.to_hex(),
+                "background": p.bg.color.to_hex(),
+                "ratio": (p.ratio as f64 * 100.0).round() / 100.0,
+            });
+            for (name, level, large) in CHECKS {
+                value[name.to_lowercase().replace(' ', "_")] = json!(p.ratio >= level.threshold(large));
+            }
+            if let Some(label) = &p.fg.label {
+                value["foreground_label"] = json!(label);
+            }
+            if let Some(label) = &p.bg.label {
+                value["background_label"] = json!(label);
+            }
+            value
+        })
+        .collect();
+    let errors: Vec<Value> = errors
+        .iter()
+        .map(|(line, input, e)| json!({ "line": line, "input": input, "error": e.to_string() }))
+        .collect();
+    let worst: Vec<usize> = worst.iter().map(|p| p.line).collect();
+    json!({
+        "pairs": pairs,
+        "errors": errors,
+        "summary": { "total": pairs.len(), "failed": failed, "worst": worst },
+    })
+}
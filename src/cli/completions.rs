@@ -0,0 +1,45 @@
+//! `colourss completions`: print a shell completion script.
+//!
+//! The script is generated by `clap_complete` from the same definitions
+//! as `--help`, so it stays in step with the commands. Value enums like
+//! `--format` complete to their values, file arguments to paths, and the
+//! colors of `convert` and `name` to the color keywords (see
+//! [`ColorHint`]).
+
+use anyhow::Result;
+use clap::builder::{PossibleValue, StringValueParser, TypedValueParser};
+use clap::{Args, Command};
+use clap_complete::Shell;
+use std::ffi::OsStr;
+use std::io;
+use std::process::ExitCode;
+
+#[derive(Args, Debug)]
+pub struct CompletionsArgs {
+    /// Shell to print the script for
+    #[arg(value_enum)]
+    shell: Shell,
+}
+
+pub fn run(args: CompletionsArgs, command: &mut Command) -> Result<ExitCode> {
+    let name = command.get_name().to_string();
+    clap_complete::generate(args.shell, command, name, &mut io::stdout());
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Accepts any string, but offers the color keywords as completions.
+/// Pair it with `hide_possible_values` so `--help` doesn't list all 149.
+#[derive(Clone)]
+pub struct ColorHint;
+
+impl TypedValueParser for ColorHint {
+    type Value = String;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&clap::Arg>, value: &OsStr) -> Result<String, clap::Error> {
+        StringValueParser::new().parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(colourss::named_colors().map(|(name, _)| PossibleValue::new(name))))
+    }
+}
@@ -0,0 +1,92 @@
+//! `colourss contrast`: WCAG 2.x contrast ratio of two colors.
+//!
+//! Prints the ratio to two decimals and a PASS/FAIL verdict for each of
+//! the four WCAG levels. Verdicts compare the unrounded ratio, so 4.499
+//! fails AA even though it prints as 4.50.
+//!
+//! With `--require aa|aaa` the exit status is 1 when the ratio is below
+//! that level's threshold for normal text. Colors that don't parse exit
+//! with 2.
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use colourss::{parse_color_with, Color, ParseOptions};
+use serde_json::json;
+use std::process::ExitCode;
+
+use super::mode::ModeArgs;
+
+#[derive(Args, Debug)]
+pub struct ContrastArgs {
+    /// Text color
+    foreground: String,
+    /// Background color
+    background: String,
+    /// Print the result as a JSON object
+    #[arg(long)]
+    json: bool,
+    /// Exit with 1 unless normal text passes this level
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    require: Option<Level>,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Level {
+    Aa,
+    Aaa,
+}
+
+impl Level {
+    /// Minimum ratio for normal text, or for large text
+    /// (18pt, or 14pt bold) when `large` is set.
+    pub fn threshold(self, large: bool) -> f32 {
+        match (self, large) {
+            (Level::Aa, false) => 4.5,
+            (Level::Aa, true) => 3.0,
+            (Level::Aaa, false) => 7.0,
+            (Level::Aaa, true) => 4.5,
+        }
+    }
+}
+
+/// The four checks in output order, with their names.
+pub const CHECKS: [(&str, Level, bool); 4] = [
+    ("AA normal", Level::Aa, false),
+    ("AA large", Level::Aa, true),
+    ("AAA normal", Level::Aaa, false),
+    ("AAA large", Level::Aaa, true),
+];
+
+pub fn run(args: ContrastArgs) -> Result<ExitCode> {
+    let options = args.mode.options();
+    let fg = parse(&args.foreground, "foreground", &options)?;
+    let bg = parse(&args.background, "background", &options)?;
+    let ratio = fg.contrast_ratio(&bg);
+
+    if args.json {
+        let mut value = json!({
+            "foreground": fg.to_hex(),
+            "background": bg.to_hex(),
+            "ratio": (ratio as f64 * 100.0).round() / 100.0,
+        });
+        for (name, level, large) in CHECKS {
+            value[name.to_lowercase().replace(' ', "_")] = json!(ratio >= level.threshold(large));
+        }
+        println!("{}", value);
+    } else {
+        println!("Contrast ratio: {:.2}:1", ratio);
+        for (name, level, large) in CHECKS {
+            let verdict = if ratio >= level.threshold(large) { "PASS" } else { "FAIL" };
+            println!("  {:<11} {}", name, verdict);
+        }
+    }
+
+    let failed = args.require.is_some_and(|level| ratio < level.threshold(false));
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn parse(input: &str, what: &str, options: &ParseOptions) -> Result<Color> {
+    parse_color_with(input, options).with_context(|| format!("Invalid {} color", what))
+}
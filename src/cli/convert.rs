@@ -0,0 +1,182 @@
+//! `colourss convert`: rewrite colors in another notation.
+//!
+//! Every input is parsed with the library and printed on its own line as
+//! `--to hex|rgb|hsl|hwb`. Hue, saturation and the like are rounded to two
+//! decimals, alpha to three. Inputs that don't parse are reported on
+//! stderr and make the command exit with 1, but the rest still print.
+
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use colourss::{Color, ParseOptions, Rgba};
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use super::completions::ColorHint;
+use super::mode::ModeArgs;
+
+#[derive(Args, Debug)]
+pub struct ConvertArgs {
+    /// Colors to convert, or `-` to read one per line from stdin
+    #[arg(value_name = "COLOR", value_parser = ColorHint, hide_possible_values = true)]
+    colors: Vec<String>,
+    /// Notation to print
+    #[arg(long, value_enum, default_value_t = Notation::Hex)]
+    to: Notation,
+    /// Print hex digits in uppercase
+    #[arg(long)]
+    uppercase: bool,
+    /// What to do with alpha: `keep` it, `strip` it, or `force:<value>`
+    /// to set it (`force:0.5` or `force:50%`)
+    #[arg(long, value_name = "MODE", default_value = "keep", value_parser = AlphaMode::from_str)]
+    alpha: AlphaMode,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Notation {
+    Hex,
+    Rgb,
+    Hsl,
+    Hwb,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum AlphaMode {
+    /// Print alpha when the input had one that isn't opaque.
+    Keep,
+    /// Never print alpha.
+    Strip,
+    /// Always print this alpha, whatever the input had.
+    Force(f32),
+}
+
+impl FromStr for AlphaMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keep" => return Ok(AlphaMode::Keep),
+            "strip" => return Ok(AlphaMode::Strip),
+            _ => {}
+        }
+        let value = s
+            .strip_prefix("force:")
+            .ok_or_else(|| format!("expected keep, strip or force:<value>, got `{}`", s))?;
+        let alpha = match value.strip_suffix('%') {
+            Some(percent) => percent.parse::<f32>().map(|p| p / 100.0),
+            None => value.parse::<f32>(),
+        }
+        .map_err(|_| format!("`{}` is not a number", value))?;
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(format!("alpha must be between 0 and 1 (or 0% and 100%), got `{}`", value));
+        }
+        Ok(AlphaMode::Force(alpha))
+    }
+}
+
+pub fn run(args: ConvertArgs) -> Result<ExitCode> {
+    let ConvertArgs { mut colors, to, uppercase, alpha, mode } = args;
+    let options = mode.options();
+    if colors.is_empty() {
+        if io::stdin().is_terminal() {
+            anyhow::bail!("nothing to convert: give a color, or pipe lines into stdin");
+        }
+        colors.push("-".to_string());
+    }
+
+    let mut out = io::stdout().lock();
+    let mut failed = false;
+    for input in &colors {
+        if input == "-" {
+            for line in io::stdin().lock().lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                failed |= !convert(&mut out, &line, &options, to, uppercase, alpha)?;
+            }
+        } else {
+            failed |= !convert(&mut out, input, &options, to, uppercase, alpha)?;
+        }
+    }
+
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+/// Prints `input` in the new notation, or its parse error on stderr.
+/// Returns whether it parsed.
+fn convert(
+    out: &mut impl Write,
+    input: &str,
+    options: &ParseOptions,
+    to: Notation,
+    uppercase: bool,
+    mode: AlphaMode,
+) -> io::Result<bool> {
+    let color = match Rgba::parse_with(input, options) {
+        Ok(color) => color,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Ok(false);
+        }
+    };
+    let alpha = match mode {
+        AlphaMode::Keep => Some(color.a).filter(|&a| a < 1.0),
+        AlphaMode::Strip => None,
+        AlphaMode::Force(a) => Some(a),
+    };
+
+    let color = Color { r: color.r, g: color.g, b: color.b };
+    writeln!(out, "{}", serialize(&color, alpha, to, uppercase))?;
+    Ok(true)
+}
+
+/// `color` in the given notation, with `alpha` if there is one. Shared
+/// by the other commands that take `--format`.
+pub fn serialize(color: &Color, alpha: Option<f32>, to: Notation, uppercase: bool) -> String {
+    match to {
+        Notation::Hex => {
+            let mut hex = color.to_hex();
+            if let Some(a) = alpha {
+                hex.push_str(&format!("{:02x}", (a * 255.0).round() as u8));
+            }
+            if uppercase {
+                hex.make_ascii_uppercase();
+            }
+            hex
+        }
+        Notation::Rgb => function("rgb", &[color.r.to_string(), color.g.to_string(), color.b.to_string()], alpha),
+        Notation::Hsl => {
+            let (h, s, l) = color.to_hsl();
+            function("hsl", &[number(h, 2), percent(s), percent(l)], alpha)
+        }
+        Notation::Hwb => {
+            let (h, _, _) = color.to_hsl();
+            let max = color.r.max(color.g).max(color.b) as f32 / 255.0;
+            let min = color.r.min(color.g).min(color.b) as f32 / 255.0;
+            function("hwb", &[number(h, 2), percent(min), percent(1.0 - max)], alpha)
+        }
+    }
+}
+
+/// `name(a b c)` or `name(a b c / alpha)`, in the modern space syntax.
+fn function(name: &str, args: &[String], alpha: Option<f32>) -> String {
+    match alpha {
+        Some(a) => format!("{}({} / {})", name, args.join(" "), number(a, 3)),
+        None => format!("{}({})", name, args.join(" ")),
+    }
+}
+
+/// A `0.0..=1.0` fraction as a percentage.
+fn percent(v: f32) -> String {
+    format!("{}%", number(v * 100.0, 2))
+}
+
+/// `v` with at most `places` decimals and no trailing zeros.
+fn number(v: f32, places: usize) -> String {
+    let s = format!("{:.*}", places, v);
+    let s = if s.contains('.') { s.trim_end_matches('0').trim_end_matches('.') } else { &s };
+    if s == "-0" { "0".to_string() } else { s.to_string() }
+}
@@ -0,0 +1,75 @@
+//! `colourss dedupe`: drop repeated colors from a list.
+//!
+//! Colors are compared by value, so `#ff0000`, `rgb(255,0,0)` and `red`
+//! are the same. With `--tolerance`, a color within that CIEDE2000
+//! distance of an earlier one is merged into it (see
+//! [`Palette::dedup`](colourss::Palette::dedup)). The first occurrence is
+//! printed as written, in order of first appearance, followed by a
+//! `# merged: ...` line listing what was folded into it; `--count`
+//! prints a count column instead. Lines that don't parse are reported on
+//! stderr and make the command exit with 1.
+
+use anyhow::Result;
+use clap::Args;
+use colourss::{parse_color_with, Color};
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use super::input::read_lines;
+use super::mode::ModeArgs;
+
+#[derive(Args, Debug)]
+pub struct DedupeArgs {
+    /// File with one color per line; stdin if missing or `-`
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    file: Option<String>,
+    /// Merge colors at most this far apart (CIEDE2000); 0 keeps
+    /// everything that isn't an exact duplicate
+    #[arg(long, value_name = "DELTA_E", default_value_t = 0.0)]
+    tolerance: f32,
+    /// Print `count<TAB>color` lines instead of `# merged:` comments
+    #[arg(long)]
+    count: bool,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+/// A color that was kept, with the lines merged into it.
+struct Kept {
+    color: Color,
+    line: String,
+    merged: Vec<String>,
+}
+
+pub fn run(args: DedupeArgs) -> Result<ExitCode> {
+    let mut kept: Vec<Kept> = Vec::new();
+    let mut failed = false;
+    let options = args.mode.options();
+    for (n, line) in read_lines(args.file.as_deref())? {
+        let color = match parse_color_with(&line, &options) {
+            Ok(color) => color,
+            Err(e) => {
+                eprintln!("Error: line {}: {}", n, e);
+                failed = true;
+                continue;
+            }
+        };
+        match kept.iter_mut().find(|k| k.color.delta_e2000(&color) <= args.tolerance) {
+            Some(k) => k.merged.push(line),
+            None => kept.push(Kept { color, line, merged: Vec::new() }),
+        }
+    }
+
+    let mut out = io::stdout().lock();
+    for k in &kept {
+        if args.count {
+            writeln!(out, "{}\t{}", k.merged.len() + 1, k.line)?;
+        } else {
+            writeln!(out, "{}", k.line)?;
+            if !k.merged.is_empty() {
+                writeln!(out, "# merged: {}", k.merged.join(", "))?;
+            }
+        }
+    }
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
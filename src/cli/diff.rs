@@ -0,0 +1,144 @@
+//! `colourss diff`: compare two palette files.
+//!
+//! Colors are matched by value first, so reordering or rewriting `red`
+//! as `#ff0000` isn't a difference. With `--tolerance`, colors left over
+//! on both sides that are within that CIEDE2000 distance are paired up as
+//! changed; everything else is removed or added.
+//!
+//! The listing starts with `--- old` / `+++ new` headers. Removed lines
+//! start with `-`, added ones with `+`, and a changed color is a `-`/`+`
+//! pair with its distance. `--json` prints
+//! `{"removed": [..], "added": [..], "changed": [{"old", "new", "distance"}], "unchanged": N}`
+//! where each entry is `{"line", "input", "hex"}`.
+//!
+//! Exit status is 0 when the palettes match, 1 when they differ and 2
+//! when a file couldn't be read.
+
+use anyhow::Result;
+use clap::Args;
+use colourss::{parse_color_with, Color, ParseOptions};
+use serde_json::{json, Value};
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use super::input::read_lines;
+use super::mode::ModeArgs;
+use super::preview::{self, When};
+
+#[derive(Args, Debug)]
+pub struct DiffArgs {
+    /// The old palette, one color per line
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    old: String,
+    /// The new palette
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    new: String,
+    /// Pair up leftover colors at most this far apart (CIEDE2000) as changed
+    #[arg(long, value_name = "DELTA_E", default_value_t = 0.0)]
+    tolerance: f32,
+    /// Print a JSON object instead of a listing
+    #[arg(long, conflicts_with = "preview")]
+    json: bool,
+    /// Draw a swatch after each color
+    #[arg(long, value_enum, value_name = "WHEN", num_args = 0..=1, require_equals = true, default_missing_value = "auto")]
+    preview: Option<When>,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+struct Entry {
+    line: usize,
+    input: String,
+    color: Color,
+}
+
+/// Reads a palette file, warning about (and skipping) lines that don't parse.
+fn read_palette(path: &str, options: &ParseOptions) -> Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+    for (line, input) in read_lines(Some(path))? {
+        match parse_color_with(&input, options) {
+            Ok(color) => entries.push(Entry { line, input, color }),
+            Err(e) => eprintln!("Warning: {}:{}: {}", path, line, e),
+        }
+    }
+    Ok(entries)
+}
+
+pub fn run(args: DiffArgs) -> Result<ExitCode> {
+    let options = args.mode.options();
+    let old = read_palette(&args.old, &options)?;
+    let new = read_palette(&args.new, &options)?;
+
+    // exact matches first, each new entry used at most once
+    let mut new_used = vec![false; new.len()];
+    let mut old_left = Vec::new();
+    for (i, o) in old.iter().enumerate() {
+        match (0..new.len()).find(|&j| !new_used[j] && new[j].color == o.color) {
+            Some(j) => new_used[j] = true,
+            None => old_left.push(i),
+        }
+    }
+    let unchanged = old.len() - old_left.len();
+
+    // then the nearest leftover within tolerance
+    let mut removed = Vec::new();
+    let mut changed = Vec::new();
+    for i in old_left {
+        let nearest = (0..new.len())
+            .filter(|&j| !new_used[j])
+            .map(|j| (j, old[i].color.delta_e2000(&new[j].color)))
+            .filter(|&(_, d)| d <= args.tolerance)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        match nearest {
+            Some((j, d)) => {
+                new_used[j] = true;
+                changed.push((i, j, d));
+            }
+            None => removed.push(i),
+        }
+    }
+    let added: Vec<usize> = (0..new.len()).filter(|&j| !new_used[j]).collect();
+
+    let mut out = io::stdout().lock();
+    if args.json {
+        let entry = |e: &Entry| json!({ "line": e.line, "input": e.input, "hex": e.color.to_hex() });
+        let value = json!({
+            "removed": removed.iter().map(|&i| entry(&old[i])).collect::<Vec<Value>>(),
+            "added": added.iter().map(|&j| entry(&new[j])).collect::<Vec<Value>>(),
+            "changed": changed
+                .iter()
+                .map(|&(i, j, d)| json!({
+                    "old": entry(&old[i]),
+                    "new": entry(&new[j]),
+                    "distance": (d as f64 * 100.0).round() / 100.0,
+                }))
+                .collect::<Vec<Value>>(),
+            "unchanged": unchanged,
+        });
+        writeln!(out, "{}", serde_json::to_string_pretty(&value)?)?;
+    } else {
+        let preview = args.preview.is_some_and(When::enabled);
+        let show = |e: &Entry| {
+            if preview {
+                format!("{} {}", e.input, preview::swatch(&e.color))
+            } else {
+                e.input.clone()
+            }
+        };
+        writeln!(out, "--- {}", args.old)?;
+        writeln!(out, "+++ {}", args.new)?;
+        for &i in &removed {
+            writeln!(out, "-{}", show(&old[i]))?;
+        }
+        for &(i, j, d) in &changed {
+            writeln!(out, "-{}", show(&old[i]))?;
+            writeln!(out, "+{}  (changed, ΔE {:.2})", show(&new[j]), d)?;
+        }
+        for &j in &added {
+            writeln!(out, "+{}", show(&new[j]))?;
+        }
+    }
+
+    let same = removed.is_empty() && added.is_empty() && changed.is_empty();
+    Ok(if same { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
@@ -0,0 +1,69 @@
+//! `colourss gradient`: evenly spaced samples along a gradient.
+//!
+//! Stops are spread evenly unless `--positions` places them; positions
+//! follow the CSS fix-up rules of [`Gradient`]. The first and last
+//! samples are always exactly the first and last colors.
+
+use anyhow::{Context, Result};
+use clap::Args;
+use colourss::{parse_color_with, Gradient};
+use std::process::ExitCode;
+
+use super::mix::Space;
+use super::mode::ModeArgs;
+use super::preview::{self, When};
+
+#[derive(Args, Debug)]
+pub struct GradientArgs {
+    /// Colors of the stops, in order
+    #[arg(value_name = "COLOR", num_args = 2.., required = true)]
+    colors: Vec<String>,
+    /// How many colors to print
+    #[arg(long, value_name = "N", default_value_t = 5, value_parser = clap::value_parser!(u64).range(2..))]
+    steps: u64,
+    /// Color space to interpolate in
+    #[arg(long, value_enum, default_value_t = Space::Srgb)]
+    space: Space,
+    /// Stop positions from 0 to 1, comma-separated, one per color
+    #[arg(long, value_name = "LIST", value_delimiter = ',')]
+    positions: Option<Vec<f32>>,
+    /// Draw a swatch after each color
+    #[arg(long, value_enum, value_name = "WHEN", num_args = 0..=1, require_equals = true, default_missing_value = "auto")]
+    preview: Option<When>,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+pub fn run(args: GradientArgs) -> Result<ExitCode> {
+    let options = args.mode.options();
+    let colors = args
+        .colors
+        .iter()
+        .map(|c| parse_color_with(c, &options).context("Invalid color"))
+        .collect::<Result<Vec<_>>>()?;
+    let gradient = match args.positions {
+        Some(positions) => {
+            if positions.len() != colors.len() {
+                anyhow::bail!("got {} positions for {} colors", positions.len(), colors.len());
+            }
+            if let Some(p) = positions.iter().find(|p| !p.is_finite()) {
+                anyhow::bail!("position {} is not a finite number", p);
+            }
+            Gradient::new(positions.into_iter().zip(colors).collect())
+        }
+        None => Gradient::from_colors(colors),
+    };
+
+    let preview = args.preview.is_some_and(When::enabled);
+    let mix = args.space.mixer();
+    let n = args.steps;
+    for i in 0..n {
+        let color = gradient.sample_with(i as f32 / (n - 1) as f32, mix);
+        if preview {
+            println!("{} {}", color.to_hex(), preview::swatch(&color));
+        } else {
+            println!("{}", color.to_hex());
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
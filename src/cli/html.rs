@@ -0,0 +1,48 @@
+//! Standalone HTML pages for `--format html`.
+//!
+//! Pages have their styles inline and load nothing, so a report can be
+//! mailed or attached as a single file. Anything that came from the
+//! input goes through [`escape`] first.
+
+use colourss::Color;
+
+/// `text` with the characters that mean something in HTML replaced by
+/// entities, safe both between tags and inside quoted attributes.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A table cell filled with `color`.
+pub fn swatch(color: &Color) -> String {
+    format!("<td class=\"swatch\" style=\"background-color: {}\"></td>", color.to_hex())
+}
+
+/// A whole document titled `title` around `body`, which must already be
+/// escaped.
+pub fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n{body}</body>\n</html>\n",
+        title = escape(title),
+    )
+}
+
+const STYLE: &str = "\
+body { font-family: system-ui, sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; margin-bottom: 1.5em; }
+th, td { border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: left; }
+td.swatch { width: 3em; }
+tr.fail { background-color: #fdecea; }
+tr.skip { color: #777; }
+code { font-family: ui-monospace, monospace; }
+";
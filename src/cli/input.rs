@@ -0,0 +1,31 @@
+//! Reading one color per line from a file or stdin.
+
+use anyhow::{Context, Result};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal};
+
+/// The non-empty lines of `path`, or of stdin when it's `None` or `-`,
+/// with their 1-based line numbers. Reading a terminal is an error, so a
+/// forgotten argument doesn't just hang.
+pub fn read_lines(path: Option<&str>) -> Result<Vec<(usize, String)>> {
+    let reader: Box<dyn BufRead> = match path {
+        Some(path) if path != "-" => Box::new(BufReader::new(
+            File::open(path).with_context(|| format!("Could not read file `{}`", path))?,
+        )),
+        _ => {
+            if io::stdin().is_terminal() {
+                anyhow::bail!("no input: give a file, or pipe lines into stdin");
+            }
+            Box::new(io::stdin().lock())
+        }
+    };
+
+    let mut lines = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.with_context(|| format!("Could not read {}", path.unwrap_or("stdin")))?;
+        if !line.trim().is_empty() {
+            lines.push((i + 1, line));
+        }
+    }
+    Ok(lines)
+}
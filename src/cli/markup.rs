@@ -0,0 +1,140 @@
+//! A lightweight walk over SVG and HTML tags, for `scan-svg` and
+//! `scan-html`.
+//!
+//! It is not a full XML or HTML parser: it finds start and end tags and
+//! their attributes, skips comments, CDATA, processing instructions and
+//! doctypes, and leaves entities alone. That's enough to find where an
+//! attribute is without choking on markup a real parser would reject.
+
+/// What [`items`] finds in a document.
+pub enum Item<'a> {
+    Start(Tag<'a>),
+    /// An end tag, with its name.
+    End(&'a str),
+    /// The contents of an element named in `raw`, as byte offsets.
+    Raw { start: usize, end: usize },
+}
+
+/// A start tag.
+pub struct Tag<'a> {
+    pub name: &'a str,
+    pub attributes: Vec<Attribute<'a>>,
+    /// Closed with `/>`, or cut off by the end of the text.
+    pub closed: bool,
+}
+
+/// An attribute of a start tag, with where its name and value start.
+pub struct Attribute<'a> {
+    pub name: &'a str,
+    pub value: &'a str,
+    pub name_at: usize,
+    pub value_at: usize,
+}
+
+/// Turns byte offsets into lines and columns, both from 1.
+pub struct Lines(Vec<usize>);
+
+impl Lines {
+    pub fn new(text: &str) -> Lines {
+        Lines(std::iter::once(0).chain(text.match_indices('\n').map(|(i, _)| i + 1)).collect())
+    }
+
+    pub fn position(&self, offset: usize) -> (usize, usize) {
+        let line = self.0.partition_point(|&start| start <= offset);
+        (line, offset - self.0[line - 1] + 1)
+    }
+}
+
+/// The tags in `text`, in order. The contents of elements named in `raw`
+/// (compared ignoring case), like HTML's `<script>` and `<style>`, come
+/// as one [`Item::Raw`] instead of being read as markup.
+pub fn items<'a>(text: &'a str, raw: &[&str]) -> Vec<Item<'a>> {
+    let mut found = Vec::new();
+    let mut i = 0;
+    while let Some(lt) = text[i..].find('<') {
+        let start = i + lt;
+        let rest = &text[start..];
+        let skip_to = |end: &str| text[start..].find(end).map_or(text.len(), |e| start + e + end.len());
+        if rest.starts_with("<!--") {
+            i = skip_to("-->");
+        } else if rest.starts_with("<![CDATA[") {
+            i = skip_to("]]>");
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            i = skip_to(">");
+        } else if let Some(rest) = rest.strip_prefix("</") {
+            found.push(Item::End(rest.split(|c: char| c.is_whitespace() || c == '>').next().unwrap_or_default()));
+            i = skip_to(">");
+        } else {
+            let (end, tag) = tag(text, start + 1);
+            i = end;
+            if tag.name.is_empty() {
+                continue;
+            }
+            let raw = !tag.closed && raw.iter().any(|name| name.eq_ignore_ascii_case(tag.name));
+            let close = format!("</{}", tag.name.to_ascii_lowercase());
+            found.push(Item::Start(tag));
+            if raw {
+                // the end tag is found ignoring case; offsets still line up
+                // since lowercasing ASCII keeps the length
+                let end = text[i..].to_ascii_lowercase().find(&close).map_or(text.len(), |e| i + e);
+                found.push(Item::Raw { start: i, end });
+                i = end;
+            }
+        }
+    }
+    found
+}
+
+/// Reads the start tag whose name begins at `i`: where it ends, and the
+/// tag.
+fn tag(text: &str, mut i: usize) -> (usize, Tag<'_>) {
+    let bytes = text.as_bytes();
+    let ends_name = |b: u8| b.is_ascii_whitespace() || matches!(b, b'>' | b'/' | b'=');
+    let name_end = |from: usize| from + bytes[from..].iter().take_while(|&&b| !ends_name(b)).count();
+
+    let end = name_end(i);
+    let mut tag = Tag { name: &text[i..end], attributes: Vec::new(), closed: false };
+    i = end;
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        match bytes.get(i) {
+            None => {
+                tag.closed = true;
+                return (i, tag);
+            }
+            Some(b'>') => return (i + 1, tag),
+            Some(b'/') if bytes.get(i + 1) == Some(&b'>') => {
+                tag.closed = true;
+                return (i + 2, tag);
+            }
+            Some(b'/') | Some(b'=') => {
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        let name_at = i;
+        i = name_end(i);
+        let name = &text[name_at..i];
+        if bytes.get(i) != Some(&b'=') {
+            continue;
+        }
+        i += 1;
+        let (value, value_at) = match bytes.get(i) {
+            Some(&quote @ (b'"' | b'\'')) => {
+                let start = i + 1;
+                let len = bytes[start..].iter().take_while(|&&b| b != quote).count();
+                i = (start + len + 1).min(bytes.len());
+                (&text[start..start + len], start)
+            }
+            _ => {
+                let start = i;
+                i += bytes[i..].iter().take_while(|&&b| !b.is_ascii_whitespace() && b != b'>').count();
+                (&text[start..i], start)
+            }
+        };
+        tag.attributes.push(Attribute { name, value, name_at, value_at });
+    }
+}
@@ -0,0 +1,87 @@
+//! `colourss mix`: mix two colors, or print a ramp between them.
+//!
+//! Output is one hex color per line, so it pipes straight into other
+//! commands. `--preview` adds a swatch after each one.
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use colourss::{parse_color_with, Color};
+use std::process::ExitCode;
+
+use super::mode::ModeArgs;
+use super::preview::{self, When};
+
+#[derive(Args, Debug)]
+pub struct MixArgs {
+    /// First color
+    first: String,
+    /// Second color
+    second: String,
+    /// How much of the second color to use, from 0 (all first) to 1
+    #[arg(long, default_value_t = 0.5, value_parser = ratio, conflicts_with = "steps")]
+    ratio: f32,
+    /// Color space to interpolate in
+    #[arg(long, value_enum, default_value_t = Space::Srgb)]
+    space: Space,
+    /// Print this many evenly spaced colors from first to second
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(2..))]
+    steps: Option<u64>,
+    /// Draw a swatch after each color
+    #[arg(long, value_enum, value_name = "WHEN", num_args = 0..=1, require_equals = true, default_missing_value = "auto")]
+    preview: Option<When>,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Space {
+    /// Gamma-encoded sRGB, like `color-mix(in srgb, ...)`
+    Srgb,
+    /// Linear-light sRGB
+    Linear,
+    /// HSL, taking the shorter way around the hue wheel
+    Hsl,
+    /// Oklab, for perceptually even steps
+    Oklab,
+}
+
+fn ratio(s: &str) -> Result<f32, String> {
+    let t: f32 = s.parse().map_err(|_| format!("`{}` is not a number", s))?;
+    if !(0.0..=1.0).contains(&t) {
+        return Err(format!("ratio must be between 0 and 1, got `{}`", s));
+    }
+    Ok(t)
+}
+
+pub fn run(args: MixArgs) -> Result<ExitCode> {
+    let options = args.mode.options();
+    let a = parse_color_with(&args.first, &options).context("Invalid color")?;
+    let b = parse_color_with(&args.second, &options).context("Invalid color")?;
+    let preview = args.preview.is_some_and(When::enabled);
+
+    let ratios: Vec<f32> = match args.steps {
+        Some(n) => (0..n).map(|i| i as f32 / (n - 1) as f32).collect(),
+        None => vec![args.ratio],
+    };
+    for t in ratios {
+        let color = args.space.mixer()(&a, &b, t);
+        if preview {
+            println!("{} {}", color.to_hex(), preview::swatch(&color));
+        } else {
+            println!("{}", color.to_hex());
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+impl Space {
+    /// The library's mixing function for this space.
+    pub fn mixer(self) -> fn(&Color, &Color, f32) -> Color {
+        match self {
+            Space::Srgb => Color::mix,
+            Space::Linear => Color::mix_linear,
+            Space::Hsl => Color::mix_hsl,
+            Space::Oklab => Color::mix_oklab,
+        }
+    }
+}
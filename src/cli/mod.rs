@@ -0,0 +1,30 @@
+//! Subcommand implementations for the `colourss` binary.
+
+pub mod check_contrast;
+pub mod completions;
+pub mod contrast;
+pub mod convert;
+pub mod dedupe;
+pub mod diff;
+pub mod gradient;
+pub mod html;
+pub mod input;
+pub mod markup;
+pub mod mix;
+pub mod mode;
+pub mod name;
+pub mod names;
+pub mod normalize;
+pub mod parse;
+pub mod preview;
+pub mod random;
+pub mod repl;
+pub mod scan;
+pub mod scan_html;
+pub mod scan_svg;
+pub mod scan_theme;
+pub mod scheme;
+pub mod show;
+pub mod sort;
+pub mod stats;
+pub mod walk;
@@ -0,0 +1,32 @@
+//! `--strict` and `--lenient`, shared by every command that parses colors.
+
+use clap::Args;
+use colourss::ParseOptions;
+
+/// Without either flag colors are parsed like `colourss::parse_color`
+/// does: commas and spaces may be mixed, `%` is optional in `hsl()`,
+/// alpha is clamped, and anything else out of range is an error.
+#[derive(Args, Debug, Clone, Copy)]
+pub struct ModeArgs {
+    /// Only accept what the CSS spec allows: one kind of separator, `%`
+    /// on hsl() saturation and lightness, nothing out of range
+    #[arg(long, conflicts_with = "lenient")]
+    strict: bool,
+    /// Also clamp out-of-range values, accept hex without `#` or with
+    /// `0x`, `;` or `|` between rgb() and hsl() components, a trailing
+    /// comma, and gray()
+    #[arg(long)]
+    lenient: bool,
+}
+
+impl ModeArgs {
+    pub fn options(&self) -> ParseOptions {
+        if self.strict {
+            ParseOptions::strict()
+        } else if self.lenient {
+            ParseOptions::lenient()
+        } else {
+            ParseOptions::default()
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! `colourss name`: the nearest color keyword for each input.
+//!
+//! Prints the closest CSS name, or the `--top N` closest, with their
+//! CIEDE2000 distance; a distance of 0 is an exact match. `--json`
+//! prints a JSON array with one object per input instead:
+//! `{"input", "hex", "matches": [{"name", "hex", "distance", "exact"}]}`.
+//! Inputs that don't parse are reported on stderr and make the command
+//! exit with 1.
+
+use anyhow::Result;
+use clap::Args;
+use colourss::{parse_color, parse_color_with, Color};
+use serde_json::{json, Value};
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+use super::completions::ColorHint;
+use super::mode::ModeArgs;
+
+#[derive(Args, Debug)]
+pub struct NameArgs {
+    /// Colors to name, or `-` to read one per line from stdin
+    #[arg(value_name = "COLOR", value_parser = ColorHint, hide_possible_values = true)]
+    colors: Vec<String>,
+    /// List this many of the closest names
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    top: u64,
+    /// Print a JSON array instead of text
+    #[arg(long)]
+    json: bool,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+pub fn run(args: NameArgs) -> Result<ExitCode> {
+    let NameArgs { mut colors, top, json, mode } = args;
+    let options = mode.options();
+    if colors.is_empty() {
+        if io::stdin().is_terminal() {
+            anyhow::bail!("nothing to name: give a color, or pipe lines into stdin");
+        }
+        colors.push("-".to_string());
+    }
+
+    let mut inputs = Vec::new();
+    for input in colors {
+        if input == "-" {
+            for line in io::stdin().lock().lines() {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    inputs.push(line);
+                }
+            }
+        } else {
+            inputs.push(input);
+        }
+    }
+
+    let mut out = io::stdout().lock();
+    let mut results = Vec::new();
+    let mut failed = false;
+    for input in &inputs {
+        let color = match parse_color_with(input, &options) {
+            Ok(color) => color,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                failed = true;
+                continue;
+            }
+        };
+        let matches = color.nearest_named_n(top as usize);
+        if json {
+            results.push(to_json(input, &color, &matches));
+        } else {
+            write_text(&mut out, input, &matches)?;
+        }
+    }
+    if json {
+        writeln!(out, "{}", serde_json::to_string_pretty(&results)?)?;
+    }
+
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn write_text(out: &mut impl Write, input: &str, matches: &[(&str, f32)]) -> io::Result<()> {
+    let describe = |(name, distance): (&str, f32)| {
+        if distance == 0.0 {
+            format!("{} (exact)", name)
+        } else {
+            format!("{} (ΔE {:.2})", name, distance)
+        }
+    };
+    if let [only] = matches {
+        return writeln!(out, "{}: {}", input, describe(*only));
+    }
+    writeln!(out, "{}:", input)?;
+    for (i, m) in matches.iter().enumerate() {
+        writeln!(out, "  {}. {}", i + 1, describe(*m))?;
+    }
+    Ok(())
+}
+
+fn to_json(input: &str, color: &Color, matches: &[(&str, f32)]) -> Value {
+    let matches: Vec<Value> = matches
+        .iter()
+        .map(|(name, distance)| {
+            json!({
+                "name": name,
+                "hex": parse_color(name).map(|c| c.to_hex()).ok(),
+                "distance": (*distance as f64 * 100.0).round() / 100.0,
+                "exact": *distance == 0.0,
+            })
+        })
+        .collect();
+    json!({ "input": input, "hex": color.to_hex(), "matches": matches })
+}
@@ -0,0 +1,66 @@
+//! `colourss names`: list the color keywords the parser knows.
+//!
+//! One `name<TAB>#hex` line per keyword. Keywords that aren't part of
+//! CSS get a third `nonstandard` column, and `--preview` adds a swatch
+//! at the end of the line.
+
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use colourss::{is_standard_name, named_colors, Palette};
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use super::preview::{self, When};
+
+#[derive(Args, Debug)]
+pub struct NamesArgs {
+    /// Only list names containing this text
+    #[arg(long, value_name = "TEXT")]
+    filter: Option<String>,
+    /// Order of the listing
+    #[arg(long, value_enum, default_value_t = Sort::Name)]
+    sort: Sort,
+    /// Draw a swatch after each color
+    #[arg(long, value_enum, value_name = "WHEN", num_args = 0..=1, require_equals = true, default_missing_value = "auto")]
+    preview: Option<When>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum Sort {
+    /// Alphabetical
+    Name,
+    /// By hue starting at red, grays first
+    Hue,
+    /// Darkest first
+    Luminance,
+}
+
+pub fn run(args: NamesArgs) -> Result<ExitCode> {
+    let filter = args.filter.map(|f| f.to_lowercase());
+    let mut palette = Palette::new();
+    for (name, color) in named_colors() {
+        if filter.as_ref().is_none_or(|f| name.contains(f.as_str())) {
+            palette.push_labeled(color, name);
+        }
+    }
+    match args.sort {
+        Sort::Name => {}
+        Sort::Hue => palette.sort_by_hue(),
+        Sort::Luminance => palette.sort_by_luminance(),
+    }
+
+    let preview = args.preview.is_some_and(When::enabled);
+    let mut out = io::stdout().lock();
+    for (i, color) in palette.iter().enumerate() {
+        let name = palette.label(i).unwrap_or_default();
+        write!(out, "{}\t{}", name, color.to_hex())?;
+        if !is_standard_name(name) {
+            write!(out, "\tnonstandard")?;
+        }
+        if preview {
+            write!(out, "\t{}", preview::swatch(color))?;
+        }
+        writeln!(out)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
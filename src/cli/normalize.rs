@@ -0,0 +1,170 @@
+//! `colourss normalize`: rewrite the colors in a stylesheet in one notation.
+//!
+//! Colors are found with [`colourss::find_colors`] and replaced with
+//! their `--to` serialization; every other byte is left exactly as it
+//! was. Comments (`/* */`, and `//` lines as in SCSS), quoted strings,
+//! `url(...)` and selectors are skipped, so `.red {` and
+//! `url("#fff.png")` stay as they are. Alpha below 1 is kept.
+//!
+//! The result goes to stdout, or back into the file with `--write` (after
+//! copying the original to `FILE.bak`, unless `--no-backup`). `--check`
+//! only reports files that would change, and exits with 1 if any would.
+
+use anyhow::{Context, Result};
+use clap::Args;
+use colourss::find_colors;
+use std::fs;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use super::convert::{serialize, Notation};
+
+#[derive(Args, Debug)]
+pub struct NormalizeArgs {
+    /// Files to rewrite
+    #[arg(value_name = "FILE", required = true, value_hint = clap::ValueHint::FilePath)]
+    files: Vec<PathBuf>,
+    /// Notation to write colors in
+    #[arg(long, value_enum, default_value_t = Notation::Hex)]
+    to: Notation,
+    /// Write hex digits in uppercase
+    #[arg(long)]
+    uppercase: bool,
+    /// Rewrite the files in place instead of printing them
+    #[arg(long, conflicts_with = "check")]
+    write: bool,
+    /// Don't keep a `.bak` copy of files rewritten with `--write`
+    #[arg(long, requires = "write")]
+    no_backup: bool,
+    /// Change nothing; exit with 1 if any file would change
+    #[arg(long)]
+    check: bool,
+}
+
+pub fn run(args: NormalizeArgs) -> Result<ExitCode> {
+    if args.files.len() > 1 && !args.write && !args.check {
+        anyhow::bail!("give one file to print, or use --write or --check for several");
+    }
+
+    let mut would_change = false;
+    for path in &args.files {
+        let text = fs::read_to_string(path).with_context(|| format!("Could not read file `{:?}`", path))?;
+        let (normalized, changed) = normalize(&text, args.to, args.uppercase);
+
+        if args.check {
+            if changed > 0 {
+                eprintln!("{}: {} color(s) would change", path.display(), changed);
+                would_change = true;
+            }
+        } else if args.write {
+            if changed > 0 {
+                write(path, &text, &normalized, !args.no_backup)?;
+                eprintln!("{}: {} color(s) rewritten", path.display(), changed);
+            }
+        } else {
+            io::stdout().lock().write_all(normalized.as_bytes())?;
+        }
+    }
+
+    Ok(if would_change { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn write(path: &Path, original: &str, normalized: &str, backup: bool) -> Result<()> {
+    if backup {
+        let mut bak = path.as_os_str().to_owned();
+        bak.push(".bak");
+        fs::write(&bak, original).with_context(|| format!("Could not write backup `{:?}`", bak))?;
+    }
+    fs::write(path, normalized).with_context(|| format!("Could not write file `{:?}`", path))
+}
+
+/// `text` with every color outside the skipped spans rewritten, and how
+/// many of them came out different.
+fn normalize(text: &str, to: Notation, uppercase: bool) -> (String, usize) {
+    let skipped = skipped_spans(text);
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut changed = 0;
+    for m in find_colors(text) {
+        if skipped.iter().any(|span| m.start < span.end && span.start < m.end) {
+            continue;
+        }
+        let alpha = Some(m.alpha).filter(|&a| a < 1.0);
+        let replacement = serialize(&m.color, alpha, to, uppercase);
+        if replacement != text[m.start..m.end] {
+            changed += 1;
+        }
+        out.push_str(&text[copied..m.start]);
+        out.push_str(&replacement);
+        copied = m.end;
+    }
+    out.push_str(&text[copied..]);
+    (out, changed)
+}
+
+/// Byte ranges of `text` whose colors are left alone: comments, strings,
+/// `url()` and selectors (whatever comes before a `{` since the last
+/// `;`, `{` or `}`).
+fn skipped_spans(text: &str) -> Vec<Range<usize>> {
+    let bytes = text.as_bytes();
+    let mut spans = Vec::new();
+    let mut statement = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        let end = if rest.starts_with(b"/*") {
+            find(bytes, i + 2, b"*/").map_or(bytes.len(), |at| at + 2)
+        } else if rest.starts_with(b"//") {
+            find(bytes, i, b"\n").unwrap_or(bytes.len())
+        } else if rest[0] == b'"' || rest[0] == b'\'' {
+            string_end(bytes, i)
+        } else if rest.len() >= 4
+            && rest[..4].eq_ignore_ascii_case(b"url(")
+            && (i == 0 || !is_ident(bytes[i - 1]))
+        {
+            find(bytes, i + 4, b")").map_or(bytes.len(), |at| at + 1)
+        } else {
+            match rest[0] {
+                b'{' => spans.push(statement..i),
+                b';' | b'}' => {}
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            }
+            statement = i + 1;
+            i += 1;
+            continue;
+        };
+        spans.push(i..end);
+        i = end;
+    }
+    spans
+}
+
+/// Just past the quote closing the string that opens at `start`. CSS
+/// strings end at an unescaped newline too.
+fn string_end(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'\n' => return i,
+            b if b == quote => return i + 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+fn find(bytes: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    bytes[from..].windows(needle.len()).position(|w| w == needle).map(|at| from + at)
+}
+
+fn is_ident(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'-' || b == b'_'
+}
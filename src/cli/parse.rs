@@ -0,0 +1,984 @@
+//! `colourss parse`: check files, stdin or literal colors line by line.
+//!
+//! Results go through an [`Output`], one per `--format`:
+//!
+//! * `human` (default): `[Line N] OK: ...` / `[Line N] FAIL: ...` lines
+//!   and a closing summary sentence.
+//! * `json`: one JSON array. Every element but the last describes one
+//!   input; the last is the summary.
+//! * `ndjson`: the same objects, one per line.
+//! * `html`: a standalone page with the summary at the top and a table
+//!   of line, input, a swatch, hex and error. Inputs are escaped.
+//! * `csv` / `tsv`: a header row, then one row per input with the
+//!   `--columns` picked. CSV fields are quoted as in RFC 4180; in TSV,
+//!   tabs, newlines and backslashes in a field are written as `\t`,
+//!   `\n` and `\\`. Failed inputs are left out unless
+//!   `--include-errors` is given, which also adds an `error` column.
+//!
+//! `--output FILE` writes any of them to a file instead of stdout.
+//!
+//! Result objects have the fields `line` (line number, or argument
+//! position for literal colors), `input`, `ok`, `r`, `g`, `b`, `hex` and
+//! `error`. On success `error` is null; on failure the color fields are
+//! null and `error` holds the message. The summary object is
+//! `{"summary": {"total": N, "ok": N, "failed": N}}`.
+//!
+//! CSS-wide keywords like `inherit` and `initial` aren't colors but
+//! aren't mistakes either, so they are counted as skipped rather than
+//! failed: `SKIP` in human output, `"skipped": true` on their JSON
+//! objects and a `skipped` count in the summaries when there are any.
+//! They don't make the exit status 1, count towards `--max-failures` or
+//! show with `--only-failures`; csv and tsv treat them like failures.
+//!
+//! Files and stdin are read one line at a time, so input of any size
+//! uses the same memory, and results are printed as lines arrive. A line
+//! that isn't valid UTF-8 fails on its own, like any other bad input.
+//! Files are parsed in chunks of lines on `--jobs` threads (one per CPU
+//! by default); results are still printed in line order, so the output
+//! is the same as with `--jobs 1`. Stdin is only parsed in chunks when
+//! `--jobs` is given, because a chunk waits for all its lines.
+//!
+//! Arguments can also be directories, whose files are parsed except for
+//! hidden ones (`--recursive` descends into subdirectories, `--ext` picks
+//! extensions), or glob patterns like `tokens/*.txt` for shells that
+//! don't expand them. When more than one file is read, every result says
+//! which file it came from: a path prefix in human output, a `file` field
+//! in JSON and a `file` column first in csv/tsv. Human output then also
+//! gets a count after each file, and the JSON summary `files` and
+//! `unreadable` counts.
+//!
+//! `--only-failures` leaves the inputs that parsed out of any format,
+//! and `--quiet` everything but the summary (csv and tsv, which have no
+//! summary, keep only their header); `-qq` prints nothing at all. The
+//! counts always cover every input.
+//!
+//! `--strict` and `--lenient` (see [`ModeArgs`]) choose how forgiving
+//! the parser is, as in the other commands.
+//!
+//! Exit status is 0 when everything parsed, 1 when any input failed
+//! (including runs cut short by `--fail-fast` / `--max-failures`), and 2
+//! when a file couldn't be read or the arguments were wrong. Unreadable
+//! files are reported on stderr and the rest are still parsed.
+//!
+//! With `--watch` the inputs must all be files. They are parsed once,
+//! then again each time one of them changes, until Ctrl-C (which exits
+//! with 0). The human summary gets the time of the run. Each file's
+//! directory is watched rather than the file itself, so editors that
+//! save by replacing the file are picked up too.
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use colourss::{parse_color_bytes, parse_color_with, Color, ParseError, ParseOptions};
+use rayon::prelude::*;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::cell::OnceCell;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::html;
+use super::mode::ModeArgs;
+use super::preview::{self, When};
+use super::walk;
+
+#[derive(Args, Debug)]
+pub struct ParseArgs {
+    /// Files to parse, `-` for stdin, or literal colors
+    #[arg(value_name = "FILE_OR_COLOR", value_hint = clap::ValueHint::AnyPath)]
+    inputs: Vec<String>,
+    /// Treat every argument as a color, even if a file has that name
+    #[arg(long)]
+    inline: bool,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Human)]
+    format: Format,
+    /// Columns for csv/tsv output, comma-separated
+    #[arg(long, value_enum, value_delimiter = ',', default_value = "line,input,hex,r,g,b")]
+    columns: Vec<Column>,
+    /// Emit failed inputs in csv/tsv output, with an `error` column
+    #[arg(long)]
+    include_errors: bool,
+    /// Stop at the first input that doesn't parse
+    #[arg(long, conflicts_with = "max_failures")]
+    fail_fast: bool,
+    /// Stop once this many inputs have failed
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    max_failures: Option<u64>,
+    /// Only print the inputs that fail, and the summary
+    #[arg(long, conflicts_with = "quiet")]
+    only_failures: bool,
+    /// Only print the summary; given twice, print nothing and only set the exit status
+    #[arg(long, short, action = clap::ArgAction::Count)]
+    quiet: u8,
+    /// Write the report to this file instead of stdout
+    #[arg(long, short, value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    output: Option<PathBuf>,
+    /// Draw a swatch after each color in human output
+    #[arg(long, value_enum, value_name = "WHEN", num_args = 0..=1, require_equals = true, default_missing_value = "auto")]
+    preview: Option<When>,
+    /// Parse the files in subdirectories too
+    #[arg(long, short)]
+    recursive: bool,
+    /// Only parse files with these extensions in directories, comma-separated
+    #[arg(long, value_name = "EXT", value_delimiter = ',')]
+    ext: Vec<String>,
+    /// Parse again whenever one of the files changes, until Ctrl-C
+    #[arg(long, conflicts_with = "inline")]
+    watch: bool,
+    /// Parse this many lines at once [default: the number of CPUs, or 1 for stdin]
+    #[arg(long, short, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    jobs: Option<u64>,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Human,
+    Json,
+    Ndjson,
+    Csv,
+    Tsv,
+    Html,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum Column {
+    File,
+    Line,
+    Input,
+    Ok,
+    Hex,
+    R,
+    G,
+    B,
+    Error,
+}
+
+#[derive(Default)]
+struct Counts {
+    success: usize,
+    fail: usize,
+    /// CSS-wide keywords, which are neither.
+    skipped: usize,
+}
+
+impl Counts {
+    /// `, N skipped` when there were any, for the summaries.
+    fn skipped_note(&self) -> String {
+        if self.skipped > 0 { format!(", {} skipped", self.skipped) } else { String::new() }
+    }
+}
+
+/// Whether `result` is a CSS-wide keyword, which is skipped rather than
+/// failed.
+fn is_skipped(result: &Result<Color, ParseError>) -> bool {
+    matches!(result, Err(ParseError::CssWideKeyword(_)))
+}
+
+/// One thing to parse, after directories and globs are expanded.
+enum Input {
+    Stdin,
+    File(PathBuf),
+    /// A literal color and its argument position.
+    Color(usize, String),
+    /// A directory or pattern that couldn't be listed.
+    Unreadable(anyhow::Error),
+}
+
+/// How reading one source ended.
+enum Outcome {
+    Finished,
+    /// `--max-failures` was reached.
+    Stopped,
+    Unreadable(anyhow::Error),
+}
+
+/// Where an input came from: a line of a file or stdin, or an argument.
+#[derive(Clone, Copy)]
+enum Origin {
+    Line(usize),
+    Arg(usize),
+}
+
+struct Entry<'a> {
+    /// The file or `stdin`, when more than one was read.
+    source: Option<&'a str>,
+    origin: Origin,
+    input: &'a str,
+    result: &'a Result<Color, ParseError>,
+}
+
+trait Output {
+    /// Called before the lines of each file or of stdin.
+    fn source(&mut self, name: &str) -> io::Result<()>;
+    /// Called after them with that source's counts, when more than one
+    /// was read.
+    fn source_done(&mut self, _counts: &Counts) -> io::Result<()> {
+        Ok(())
+    }
+    fn entry(&mut self, entry: &Entry) -> io::Result<()>;
+    fn finish(&mut self, totals: &Totals) -> io::Result<()>;
+}
+
+/// Everything one run saw, for the closing summary.
+struct Totals {
+    counts: Counts,
+    /// How many files (or stdin) were read, when more than one.
+    files: Option<usize>,
+    unreadable: usize,
+}
+
+pub fn run(mut args: ParseArgs) -> Result<ExitCode> {
+    if args.include_errors && !args.columns.contains(&Column::Error) {
+        args.columns.push(Column::Error);
+    }
+    if args.watch {
+        return watch(&args);
+    }
+    if args.inputs.is_empty() {
+        if io::stdin().is_terminal() {
+            anyhow::bail!("nothing to parse: give a file, a color, or pipe lines into stdin");
+        }
+        args.inputs.push("-".to_string());
+    }
+    let inputs = expand(&args);
+    report(&args, &inputs, None, &Pool::new(args.jobs))
+}
+
+/// Turns the arguments into [`Input`]s: `-` is stdin, paths and glob
+/// patterns become the files they stand for (see [`walk`]), and anything
+/// else is a color.
+fn expand(args: &ParseArgs) -> Vec<Input> {
+    let mut inputs = Vec::new();
+    for (i, arg) in args.inputs.iter().enumerate() {
+        if args.inline {
+            inputs.push(Input::Color(i + 1, arg.clone()));
+        } else if arg == "-" {
+            inputs.push(Input::Stdin);
+        } else if let Some(files) = walk::files(arg, &args.ext, args.recursive) {
+            inputs.extend(files.into_iter().map(|file| match file {
+                Ok(file) => Input::File(file.path),
+                Err(e) => Input::Unreadable(e),
+            }));
+        } else {
+            inputs.push(Input::Color(i + 1, arg.clone()));
+        }
+    }
+    inputs
+}
+
+/// Parses every input once and prints the report. `stamp` is added to
+/// the human summary.
+fn report(args: &ParseArgs, inputs: &[Input], stamp: Option<String>, pool: &Pool) -> Result<ExitCode> {
+    let ParseArgs {
+        format, columns, include_errors, fail_fast, max_failures, only_failures, quiet, output, preview, jobs, mode, ..
+    } = args;
+    let sources = inputs.iter().filter(|i| matches!(i, Input::Stdin | Input::File(_))).count();
+    let many = sources > 1;
+    let mut columns = columns.clone();
+    if many && !columns.contains(&Column::File) {
+        columns.insert(0, Column::File);
+    }
+    let stdout: Box<dyn Write> = match output {
+        _ if *quiet > 1 => Box::new(io::sink()),
+        Some(path) => {
+            let file = File::create(path).with_context(|| format!("Could not write file `{:?}`", path))?;
+            Box::new(io::BufWriter::new(file))
+        }
+        None => Box::new(io::stdout().lock()),
+    };
+    // `--output` is never a terminal, even when stdout is
+    let preview = preview.is_some_and(|when| when.enabled_on(output.is_none() && io::stdout().is_terminal()));
+    let out: Box<dyn Output> = match format {
+        Format::Human => Box::new(Human { out: stdout, preview, stamp }),
+        Format::Json => Box::new(Json { out: stdout, ndjson: false, first: true }),
+        Format::Ndjson => Box::new(Json { out: stdout, ndjson: true, first: true }),
+        Format::Html => Box::new(Html { out: stdout, rows: String::new(), many }),
+        Format::Csv | Format::Tsv => Box::new(Table::new(
+            stdout,
+            if *format == Format::Csv { b',' } else { b'\t' },
+            columns,
+            *include_errors,
+        )?),
+    };
+    let out = if *only_failures || *quiet > 0 {
+        Box::new(Filter { out, only_failures: *only_failures, quiet: *quiet > 0 })
+    } else {
+        out
+    };
+    let mut session = Session {
+        out,
+        counts: Counts::default(),
+        max_failures: if *fail_fast { Some(1) } else { max_failures.map(|n| n as usize) },
+        source: String::new(),
+        many,
+        options: mode.options(),
+        pool,
+        notes: *quiet < 2,
+        jobs_given: jobs.is_some(),
+    };
+
+    let mut unreadable = 0;
+    for input in inputs {
+        let outcome = match input {
+            Input::Stdin => session.parse_stdin()?,
+            Input::File(path) => session.parse_file(path)?,
+            Input::Color(n, color) => match session.check(Origin::Arg(*n), color)? {
+                true => Outcome::Finished,
+                false => Outcome::Stopped,
+            },
+            Input::Unreadable(e) => {
+                eprintln!("Error: {:?}", e);
+                unreadable += 1;
+                Outcome::Finished
+            }
+        };
+        match outcome {
+            Outcome::Finished => {}
+            Outcome::Stopped => break,
+            Outcome::Unreadable(e) => {
+                eprintln!("Error: {:?}", e);
+                unreadable += 1;
+            }
+        }
+    }
+    let totals = Totals { counts: session.counts, files: many.then_some(sources), unreadable };
+    session.out.finish(&totals)?;
+
+    Ok(if unreadable > 0 {
+        ExitCode::from(crate::EXIT_ERROR)
+    } else if totals.counts.fail > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+enum WatchEvent {
+    Changed,
+    Interrupted,
+}
+
+/// How long a burst of file events has to settle before parsing again.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// How long a watched file may be missing (mid-save) before we give up
+/// on this change and wait for the next one.
+const MISSING_GRACE: Duration = Duration::from_secs(1);
+
+fn watch(args: &ParseArgs) -> Result<ExitCode> {
+    if args.inputs.is_empty() {
+        anyhow::bail!("--watch needs at least one file");
+    }
+    let mut files = Vec::new();
+    let mut inputs = Vec::new();
+    for input in &args.inputs {
+        let path = Path::new(input);
+        if input == "-" || !path.is_file() {
+            anyhow::bail!("--watch only works on files, and `{}` isn't one", input);
+        }
+        files.push(watch_path(path)?);
+        inputs.push(Input::File(path.to_path_buf()));
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let changes = tx.clone();
+    let watched = files.clone();
+    let mut debouncer = notify_debouncer_mini::new_debouncer(DEBOUNCE, move |events: notify_debouncer_mini::DebounceEventResult| {
+        match events {
+            Ok(events) if events.iter().any(|e| watched.contains(&e.path)) => {
+                let _ = changes.send(WatchEvent::Changed);
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("Watch error: {}", e),
+        }
+    })
+    .context("Could not start watching files")?;
+    let mut dirs: Vec<&Path> = files.iter().filter_map(|f| f.parent()).collect();
+    dirs.sort();
+    dirs.dedup();
+    for dir in dirs {
+        debouncer
+            .watcher()
+            .watch(dir, notify_debouncer_mini::notify::RecursiveMode::NonRecursive)
+            .with_context(|| format!("Could not watch `{}`", dir.display()))?;
+    }
+    ctrlc::set_handler(move || {
+        let _ = tx.send(WatchEvent::Interrupted);
+    })
+    .context("Could not set a Ctrl-C handler")?;
+
+    let clear = io::stdout().is_terminal();
+    let pool = Pool::new(args.jobs);
+    let mut last = None;
+    loop {
+        if !wait_for_files(&files) {
+            if last.take().is_some() {
+                eprintln!("Waiting for the files to come back...");
+            }
+        } else {
+            // reading the files makes events of its own, so only run
+            // again if one of them really changed
+            let now = Some(versions(&files));
+            if now != last {
+                last = now;
+                if clear {
+                    print!("\x1b[2J\x1b[H");
+                }
+                if let Err(e) = report(args, &inputs, Some(clock()), &pool) {
+                    eprintln!("Error: {:?}", e);
+                }
+            }
+        }
+        match rx.recv() {
+            Ok(WatchEvent::Changed) => continue,
+            Ok(WatchEvent::Interrupted) | Err(_) => break,
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `path` with its directory made absolute, as the watcher reports it.
+/// The file itself isn't resolved, since saving may replace it.
+fn watch_path(path: &Path) -> Result<PathBuf> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let dir = dir
+        .canonicalize()
+        .with_context(|| format!("Could not read directory `{}`", dir.display()))?;
+    Ok(dir.join(path.file_name().unwrap_or_default()))
+}
+
+/// Whether all `files` exist, giving them [`MISSING_GRACE`] to reappear
+/// if a save has just removed one.
+fn wait_for_files(files: &[PathBuf]) -> bool {
+    let step = Duration::from_millis(50);
+    let mut waited = Duration::ZERO;
+    while !files.iter().all(|f| f.is_file()) {
+        if waited >= MISSING_GRACE {
+            return false;
+        }
+        thread::sleep(step);
+        waited += step;
+    }
+    true
+}
+
+/// The threads for `--jobs`. They are only started once a chunk of
+/// [`CHUNK_LINES`] lines has to be parsed, and then kept for the rest of
+/// the run, `--watch` reruns included.
+struct Pool {
+    jobs: usize,
+    threads: OnceCell<rayon::ThreadPool>,
+}
+
+impl Pool {
+    fn new(jobs: Option<u64>) -> Pool {
+        let jobs = match jobs {
+            Some(n) => n as usize,
+            None => thread::available_parallelism().map_or(1, |n| n.get()),
+        };
+        Pool { jobs, threads: OnceCell::new() }
+    }
+
+    /// Whether there is more than one thread to parse with.
+    fn parallel(&self) -> bool {
+        self.jobs > 1
+    }
+
+    fn threads(&self) -> io::Result<&rayon::ThreadPool> {
+        if let Some(threads) = self.threads.get() {
+            return Ok(threads);
+        }
+        let threads = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.jobs)
+            .build()
+            .map_err(|e| io::Error::other(format!("Could not start the parsing threads: {}", e)))?;
+        Ok(self.threads.get_or_init(|| threads))
+    }
+}
+
+/// Modification time and size of each file, to tell real changes from
+/// events that only opened it.
+fn versions(files: &[PathBuf]) -> Vec<Option<(SystemTime, u64)>> {
+    files
+        .iter()
+        .map(|f| fs::metadata(f).ok().and_then(|m| Some((m.modified().ok()?, m.len()))))
+        .collect()
+}
+
+/// The current time of day as `HH:MM:SS UTC`.
+fn clock() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()) % 86_400;
+    format!("{:02}:{:02}:{:02} UTC", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+/// How many lines `--jobs` parses at once.
+const CHUNK_LINES: usize = 4096;
+
+/// `line` without its line ending, and what it parses to: `None` for a
+/// blank line, which isn't an entry.
+fn parse_line<'a>(line: &'a [u8], options: &ParseOptions) -> (Cow<'a, str>, Option<Result<Color, ParseError>>) {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    match std::str::from_utf8(line) {
+        Ok(text) if text.trim().is_empty() => (Cow::Borrowed(text), None),
+        Ok(text) => (Cow::Borrowed(text), Some(parse_color_with(text, options))),
+        Err(_) => (String::from_utf8_lossy(line), Some(parse_color_bytes(line))),
+    }
+}
+
+/// State for one `parse` run. The `parse_*` and `check` methods return
+/// `false` once `--max-failures` is reached.
+struct Session<'a> {
+    out: Box<dyn Output>,
+    counts: Counts,
+    max_failures: Option<usize>,
+    /// The file (or `stdin`) being read, for messages.
+    source: String,
+    /// Whether more than one file is read, so results name theirs.
+    many: bool,
+    options: ParseOptions,
+    /// Threads for `--jobs`.
+    pool: &'a Pool,
+    /// Whether `--jobs` was given; stdin only uses the pool then.
+    jobs_given: bool,
+    /// Whether to say on stderr where `--max-failures` stopped.
+    notes: bool,
+}
+
+impl Session<'_> {
+    fn start(&mut self, source: &str, heading: &str) -> io::Result<()> {
+        self.source = source.to_string();
+        self.out.source(heading)
+    }
+
+    fn parse_stdin(&mut self) -> io::Result<Outcome> {
+        self.start("stdin", "stdin")?;
+        // chunks would hold lines back until CHUNK_LINES have arrived
+        let parallel = self.jobs_given;
+        self.parse_lines(io::stdin().lock(), parallel)
+    }
+
+    // this function handles opening the file and parsing each line
+    fn parse_file(&mut self, file_path: &Path) -> io::Result<Outcome> {
+        let file = match File::open(file_path) {
+            Ok(file) => file,
+            Err(e) => {
+                let e = anyhow::Error::new(e).context(format!("Could not read file `{:?}`", file_path));
+                return Ok(Outcome::Unreadable(e));
+            }
+        };
+
+        self.start(&file_path.display().to_string(), &format!("file: {:?}", file_path))?;
+        Ok(match self.parse_lines(BufReader::new(file), true)? {
+            Outcome::Unreadable(e) => Outcome::Unreadable(e.context(format!("Could not read file `{:?}`", file_path))),
+            outcome => outcome,
+        })
+    }
+
+    // parses every non-empty line of `reader`, for both files and stdin.
+    // Lines are read in chunks into buffers that are reused, so memory use
+    // doesn't grow with the file; with `parallel` and more than one job a
+    // full chunk is parsed on all the threads, otherwise a chunk is
+    // parsed here, and without `parallel` it is one line. Results
+    // are recorded in line order either way, so the output, the counts and
+    // where `--max-failures` stops are the same. A line that isn't UTF-8
+    // is a failed entry. Errors writing the report are returned; errors
+    // reading are an `Outcome::Unreadable`, so the other files still get
+    // parsed.
+    fn parse_lines(&mut self, mut reader: impl BufRead, parallel: bool) -> io::Result<Outcome> {
+        let parallel = parallel && self.pool.parallel();
+        let chunk_len = if parallel { CHUNK_LINES } else { 1 };
+        let before = (self.counts.success, self.counts.fail, self.counts.skipped);
+        let mut outcome = Outcome::Finished;
+        let mut chunk: Vec<Vec<u8>> = Vec::new();
+        let mut number = 0;
+        while matches!(outcome, Outcome::Finished) {
+            let mut len = 0;
+            while len < chunk_len {
+                if len == chunk.len() {
+                    chunk.push(Vec::new());
+                }
+                let buf = &mut chunk[len];
+                buf.clear();
+                match reader.read_until(b'\n', buf) {
+                    Ok(0) => break,
+                    Ok(_) => len += 1,
+                    Err(e) => {
+                        outcome = Outcome::Unreadable(e.into());
+                        break;
+                    }
+                }
+            }
+            if len == 0 {
+                break;
+            }
+
+            let options = &self.options;
+            let lines = &chunk[..len];
+            // a shorter chunk is the last one, not worth the threads
+            let parsed: Vec<_> = if len == CHUNK_LINES {
+                self.pool.threads()?.install(|| lines.par_iter().map(|line| parse_line(line, options)).collect())
+            } else {
+                lines.iter().map(|line| parse_line(line, options)).collect()
+            };
+            for (input, result) in parsed {
+                number += 1;
+                let Some(result) = result else { continue }; // skip empty lines
+                if !self.record(Origin::Line(number), &input, result)? {
+                    outcome = Outcome::Stopped;
+                    break;
+                }
+            }
+            if len < chunk_len && matches!(outcome, Outcome::Finished) {
+                break;
+            }
+        }
+        if self.many && !matches!(outcome, Outcome::Unreadable(_)) {
+            let counts = Counts {
+                success: self.counts.success - before.0,
+                fail: self.counts.fail - before.1,
+                skipped: self.counts.skipped - before.2,
+            };
+            self.out.source_done(&counts)?;
+        }
+        Ok(outcome)
+    }
+
+    fn check(&mut self, origin: Origin, input: &str) -> io::Result<bool> {
+        let result = parse_color_with(input, &self.options);
+        self.record(origin, input, result)
+    }
+
+    fn record(&mut self, origin: Origin, input: &str, result: Result<Color, ParseError>) -> io::Result<bool> {
+        if result.is_ok() {
+            self.counts.success += 1;
+        } else if is_skipped(&result) {
+            self.counts.skipped += 1;
+        } else {
+            self.counts.fail += 1;
+        }
+        let source = match origin {
+            Origin::Line(_) if self.many => Some(self.source.as_str()),
+            _ => None,
+        };
+        self.out.entry(&Entry { source, origin, input, result: &result })?;
+
+        if result.is_err() && !is_skipped(&result) && self.max_failures.is_some_and(|max| self.counts.fail >= max) {
+            let at = match origin {
+                Origin::Line(n) => format!("{}:{}", self.source, n),
+                Origin::Arg(n) => format!("argument {}", n),
+            };
+            if self.notes {
+                eprintln!("Stopping after {} failure(s), last at {}: {}", self.counts.fail, at, input);
+            }
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+
+/// Leaves out part of another output for `--only-failures` and
+/// `--quiet`; the counts in the summary still cover everything. (`-qq`
+/// writes the output to nowhere instead.)
+struct Filter {
+    out: Box<dyn Output>,
+    only_failures: bool,
+    /// Keep only the summary.
+    quiet: bool,
+}
+
+impl Output for Filter {
+    fn source(&mut self, name: &str) -> io::Result<()> {
+        if self.quiet { Ok(()) } else { self.out.source(name) }
+    }
+
+    fn source_done(&mut self, counts: &Counts) -> io::Result<()> {
+        if self.quiet { Ok(()) } else { self.out.source_done(counts) }
+    }
+
+    fn entry(&mut self, entry: &Entry) -> io::Result<()> {
+        if self.quiet || (self.only_failures && (entry.result.is_ok() || is_skipped(entry.result))) {
+            return Ok(());
+        }
+        self.out.entry(entry)
+    }
+
+    fn finish(&mut self, totals: &Totals) -> io::Result<()> {
+        self.out.finish(totals)
+    }
+}
+
+struct Human<W> {
+    out: W,
+    /// Append a swatch to OK lines.
+    preview: bool,
+    /// When the run happened, for `--watch`.
+    stamp: Option<String>,
+}
+
+impl<W: Write> Output for Human<W> {
+    fn source(&mut self, name: &str) -> io::Result<()> {
+        writeln!(self.out, "Parsing {}...", name)
+    }
+
+    fn entry(&mut self, entry: &Entry) -> io::Result<()> {
+        let location = match entry.origin {
+            Origin::Line(n) => format!("Line {}", n),
+            Origin::Arg(n) => format!("Arg {}", n),
+        };
+        match entry.source {
+            Some(source) => write!(self.out, "  {}: ", source)?,
+            None => write!(self.out, "  ")?,
+        }
+        match entry.result {
+            Ok(color) => {
+                write!(
+                    self.out,
+                    "[{}] OK: '{}' -> Color(r: {}, g: {}, b: {})",
+                    location, entry.input, color.r, color.g, color.b
+                )?;
+                if self.preview {
+                    write!(self.out, " {}", preview::swatch(color))?;
+                }
+                writeln!(self.out)
+            }
+            Err(e) if is_skipped(entry.result) => writeln!(self.out, "[{}] SKIP: '{}' -> {}", location, entry.input, e),
+            Err(e) => writeln!(self.out, "[{}] FAIL: '{}' -> Error: {}", location, entry.input, e),
+        }
+    }
+
+    fn source_done(&mut self, counts: &Counts) -> io::Result<()> {
+        writeln!(self.out, "  {} successful, {} failed{}.", counts.success, counts.fail, counts.skipped_note())
+    }
+
+    fn finish(&mut self, totals: &Totals) -> io::Result<()> {
+        let counts = &totals.counts;
+        write!(
+            self.out,
+            "\nParsing complete. {} successful, {} failed{}",
+            counts.success,
+            counts.fail,
+            counts.skipped_note()
+        )?;
+        if let Some(files) = totals.files {
+            write!(self.out, " in {} files", files)?;
+        }
+        if totals.unreadable > 0 {
+            write!(self.out, ", {} unreadable", totals.unreadable)?;
+        }
+        write!(self.out, ".")?;
+        match &self.stamp {
+            Some(stamp) => writeln!(self.out, " (last run {})", stamp)?,
+            None => writeln!(self.out)?,
+        }
+        self.out.flush()
+    }
+}
+
+/// Collects a table row per entry, and writes the page when the summary
+/// for the top of it is known, so unlike the other formats it holds the
+/// whole report in memory.
+struct Html<W> {
+    out: W,
+    rows: String,
+    /// Add a file column.
+    many: bool,
+}
+
+impl<W: Write> Output for Html<W> {
+    fn source(&mut self, _name: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn entry(&mut self, entry: &Entry) -> io::Result<()> {
+        let location = match entry.origin {
+            Origin::Line(n) => format!("Line {}", n),
+            Origin::Arg(n) => format!("Arg {}", n),
+        };
+        self.rows.push_str(match entry.result {
+            Ok(_) => "<tr>",
+            Err(_) if is_skipped(entry.result) => "<tr class=\"skip\">",
+            Err(_) => "<tr class=\"fail\">",
+        });
+        if self.many {
+            self.rows.push_str(&format!("<td>{}</td>", html::escape(entry.source.unwrap_or(""))));
+        }
+        self.rows.push_str(&format!("<td>{}</td><td><code>{}</code></td>", location, html::escape(entry.input)));
+        match entry.result {
+            Ok(color) => self.rows.push_str(&format!("{}<td><code>{}</code></td><td></td>", html::swatch(color), color.to_hex())),
+            Err(e) => self.rows.push_str(&format!("<td></td><td></td><td>{}</td>", html::escape(&e.to_string()))),
+        }
+        self.rows.push_str("</tr>\n");
+        Ok(())
+    }
+
+    fn finish(&mut self, totals: &Totals) -> io::Result<()> {
+        let counts = &totals.counts;
+        let mut summary = format!("{} successful, {} failed{}", counts.success, counts.fail, counts.skipped_note());
+        if let Some(files) = totals.files {
+            summary.push_str(&format!(" in {} files", files));
+        }
+        if totals.unreadable > 0 {
+            summary.push_str(&format!(", {} unreadable", totals.unreadable));
+        }
+        let file = if self.many { "<th>File</th>" } else { "" };
+        let body = format!(
+            "<p class=\"summary\">{}.</p>\n<table>\n<tr>{}<th>Line</th><th>Input</th><th>Color</th><th>Hex</th><th>Error</th></tr>\n{}</table>\n",
+            summary, file, self.rows
+        );
+        write!(self.out, "{}", html::page("ColourSS parse report", &body))?;
+        self.out.flush()
+    }
+}
+
+/// JSON array (`ndjson: false`) or newline-delimited JSON.
+struct Json<W> {
+    out: W,
+    ndjson: bool,
+    first: bool,
+}
+
+impl<W: Write> Json<W> {
+    fn write(&mut self, value: &Value) -> io::Result<()> {
+        if self.ndjson {
+            writeln!(self.out, "{}", value)
+        } else {
+            let sep = if self.first { "[\n" } else { ",\n" };
+            self.first = false;
+            write!(self.out, "{}  {}", sep, value)
+        }
+    }
+}
+
+impl<W: Write> Output for Json<W> {
+    fn source(&mut self, _name: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn entry(&mut self, entry: &Entry) -> io::Result<()> {
+        let line = match entry.origin {
+            Origin::Line(n) | Origin::Arg(n) => n,
+        };
+        let mut value = match entry.result {
+            Ok(c) => json!({
+                "line": line, "input": entry.input, "ok": true,
+                "r": c.r, "g": c.g, "b": c.b, "hex": c.to_hex(), "error": null,
+            }),
+            Err(e) => json!({
+                "line": line, "input": entry.input, "ok": false,
+                "r": null, "g": null, "b": null, "hex": null, "error": e.to_string(),
+            }),
+        };
+        if is_skipped(entry.result) {
+            value["skipped"] = true.into();
+        }
+        if let Some(source) = entry.source {
+            value["file"] = source.into();
+        }
+        self.write(&value)
+    }
+
+    fn finish(&mut self, totals: &Totals) -> io::Result<()> {
+        let counts = &totals.counts;
+        let mut summary = json!({ "summary": {
+            "total": counts.success + counts.fail + counts.skipped,
+            "ok": counts.success,
+            "failed": counts.fail,
+        }});
+        if counts.skipped > 0 {
+            summary["summary"]["skipped"] = counts.skipped.into();
+        }
+        if let Some(files) = totals.files {
+            summary["summary"]["files"] = files.into();
+            summary["summary"]["unreadable"] = totals.unreadable.into();
+        }
+        self.write(&summary)?;
+        if !self.ndjson {
+            writeln!(self.out, "\n]")?;
+        }
+        self.out.flush()
+    }
+}
+
+/// CSV (`sep: b','`) or TSV (`sep: b'\t'`).
+struct Table<W> {
+    out: W,
+    sep: u8,
+    columns: Vec<Column>,
+    include_errors: bool,
+}
+
+impl<W: Write> Table<W> {
+    fn new(out: W, sep: u8, columns: Vec<Column>, include_errors: bool) -> io::Result<Self> {
+        let mut table = Table { out, sep, columns, include_errors };
+        let header: Vec<String> = table
+            .columns
+            .iter()
+            .map(|c| c.to_possible_value().unwrap().get_name().to_string())
+            .collect();
+        table.row(&header)?;
+        Ok(table)
+    }
+
+    fn row(&mut self, fields: &[String]) -> io::Result<()> {
+        let escaped: Vec<String> = fields.iter().map(|f| self.escape(f)).collect();
+        writeln!(self.out, "{}", escaped.join(&(self.sep as char).to_string()))
+    }
+
+    fn escape(&self, field: &str) -> String {
+        if self.sep == b'\t' {
+            field.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+        } else if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+}
+
+impl<W: Write> Output for Table<W> {
+    fn source(&mut self, _name: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn entry(&mut self, entry: &Entry) -> io::Result<()> {
+        if entry.result.is_err() && !self.include_errors {
+            return Ok(());
+        }
+        let line = match entry.origin {
+            Origin::Line(n) | Origin::Arg(n) => n,
+        };
+        let color = entry.result.as_ref().ok();
+        let channel = |f: fn(&Color) -> u8| color.map(|c| f(c).to_string()).unwrap_or_default();
+        let fields: Vec<String> = self
+            .columns
+            .iter()
+            .map(|column| match column {
+                Column::File => entry.source.unwrap_or_default().to_string(),
+                Column::Line => line.to_string(),
+                Column::Input => entry.input.to_string(),
+                Column::Ok => color.is_some().to_string(),
+                Column::Hex => color.map(Color::to_hex).unwrap_or_default(),
+                Column::R => channel(|c| c.r),
+                Column::G => channel(|c| c.g),
+                Column::B => channel(|c| c.b),
+                Column::Error => entry.result.as_ref().err().map(|e| e.to_string()).unwrap_or_default(),
+            })
+            .collect();
+        self.row(&fields)
+    }
+
+    fn finish(&mut self, _totals: &Totals) -> io::Result<()> {
+        self.out.flush()
+    }
+}
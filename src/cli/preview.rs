@@ -0,0 +1,54 @@
+//! Truecolor swatches for terminal output.
+//!
+//! Swatches are only drawn when asked for: with `--preview=auto` (what a
+//! bare `--preview` means), stdout has to be a terminal and `COLORTERM`
+//! has to say `truecolor` or `24bit`, since that's how terminals
+//! advertise 24-bit color. `--preview=always` skips both checks.
+
+use clap::ValueEnum;
+use colourss::Color;
+use std::env;
+use std::io::{self, IsTerminal};
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum When {
+    Auto,
+    Always,
+    Never,
+}
+
+impl When {
+    /// Whether swatches should actually be drawn on stdout.
+    pub fn enabled(self) -> bool {
+        self.enabled_on(io::stdout().is_terminal())
+    }
+
+    /// Whether swatches should be drawn on an output that is a terminal
+    /// or not, like a file given with `--output`.
+    pub fn enabled_on(self, terminal: bool) -> bool {
+        match self {
+            When::Always => true,
+            When::Never => false,
+            When::Auto => terminal && supports_truecolor(),
+        }
+    }
+}
+
+fn supports_truecolor() -> bool {
+    env::var("COLORTERM").is_ok_and(|v| v == "truecolor" || v == "24bit")
+}
+
+/// A two-cell block of `color`.
+pub fn swatch(color: &Color) -> String {
+    format!("{}  \x1b[0m", color.ansi_bg())
+}
+
+/// `text` on a `color` background, in black or white, whichever reads better.
+pub fn label(color: &Color, text: &str) -> String {
+    let ink = if color.is_dark() {
+        Color { r: 255, g: 255, b: 255 }
+    } else {
+        Color { r: 0, g: 0, b: 0 }
+    };
+    format!("{}{} {} \x1b[0m", color.ansi_bg(), ink.ansi_fg(), text)
+}
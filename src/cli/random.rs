@@ -0,0 +1,86 @@
+//! `colourss random`: print random colors.
+//!
+//! Without constraints every RGB color is equally likely. `--pastel`,
+//! `--dark` and `--hue` pick from a region of HSL space instead:
+//!
+//! * `--pastel`: saturation 40–80%, lightness 75–90%
+//! * `--dark`: saturation 30–90%, lightness 10–30%
+//! * `--hue` alone: saturation 50–100%, lightness 35–65%, so the hue
+//!   is actually visible
+//!
+//! The generator is ChaCha8, so a given `--seed` prints the same colors
+//! on every platform and every release.
+
+use anyhow::Result;
+use clap::Args;
+use colourss::Color;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+use std::process::ExitCode;
+
+use super::convert::{serialize, Notation};
+
+#[derive(Args, Debug)]
+pub struct RandomArgs {
+    /// How many colors to print
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    count: usize,
+    /// Seed for reproducible output
+    #[arg(long, value_name = "S")]
+    seed: Option<u64>,
+    /// Only light, soft colors
+    #[arg(long, conflicts_with = "dark")]
+    pastel: bool,
+    /// Only dark colors
+    #[arg(long)]
+    dark: bool,
+    /// Only hues in this range of degrees, like `200-260` (or `330-30`
+    /// to wrap around red)
+    #[arg(long, value_name = "RANGE", value_parser = hue_range)]
+    hue: Option<RangeInclusive<f32>>,
+    /// Notation to print
+    #[arg(long, value_enum, default_value_t = Notation::Hex)]
+    format: Notation,
+}
+
+fn hue_range(s: &str) -> Result<RangeInclusive<f32>, String> {
+    let degrees = |v: &str| match v.trim().parse::<f32>() {
+        Ok(d) if (0.0..=360.0).contains(&d) => Ok(d),
+        _ => Err(format!("`{}` is not a hue between 0 and 360", v)),
+    };
+    match s.split_once('-') {
+        Some((start, end)) => Ok(degrees(start)?..=degrees(end)?),
+        None => degrees(s).map(|d| d..=d),
+    }
+}
+
+pub fn run(args: RandomArgs) -> Result<ExitCode> {
+    let mut rng = match args.seed {
+        Some(seed) => ChaCha8Rng::seed_from_u64(seed),
+        None => ChaCha8Rng::from_os_rng(),
+    };
+    let region = if args.pastel {
+        Some((0.4..=0.8, 0.75..=0.9))
+    } else if args.dark {
+        Some((0.3..=0.9, 0.1..=0.3))
+    } else if args.hue.is_some() {
+        Some((0.5..=1.0, 0.35..=0.65))
+    } else {
+        None
+    };
+
+    let mut out = io::stdout().lock();
+    for _ in 0..args.count {
+        let color = match &region {
+            Some((saturation, lightness)) => {
+                let hue = args.hue.clone().unwrap_or(0.0..=360.0);
+                Color::random_hsl(&mut rng, hue, saturation.clone(), lightness.clone())
+            }
+            None => rng.random(),
+        };
+        writeln!(out, "{}", serialize(&color, None, args.format, false))?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
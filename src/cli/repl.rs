@@ -0,0 +1,183 @@
+//! `colourss repl`: parse colors as they are typed.
+//!
+//! Each line is parsed and shown as hex, `rgb()` and `hsl()`, with the
+//! nearest color keyword and a swatch (see [`When`]). A line that
+//! doesn't parse prints the error, and for an unknown name the closest
+//! keyword by spelling, then the prompt comes back. Lines starting with
+//! `:` are commands, listed by `:help`. Line editing and history (for
+//! the session only) come from rustyline; Ctrl-D or `:quit` ends it.
+
+use anyhow::Result;
+use clap::Args;
+use colourss::{named_colors, Color, ParseError, ParseOptions, Rgba};
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use serde_json::json;
+use std::process::ExitCode;
+
+use super::convert::{serialize, Notation};
+use super::mode::ModeArgs;
+use super::preview::{self, When};
+
+#[derive(Args, Debug)]
+pub struct ReplArgs {
+    /// When to draw the swatch
+    #[arg(long, value_enum, value_name = "WHEN", default_value_t = When::Auto)]
+    preview: When,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+const HELP: &str = "\
+:format text|json   how results are printed
+:strict on|off      only accept what the CSS spec allows
+:lenient on|off     also clamp out-of-range values, accept bare hex and more
+:help               this list
+:quit               leave (or Ctrl-D)";
+
+/// What the prompt is set to; changed by the `:` commands.
+struct Session {
+    json: bool,
+    options: ParseOptions,
+    preview: bool,
+}
+
+pub fn run(args: ReplArgs) -> Result<ExitCode> {
+    let mut session = Session { json: false, options: args.mode.options(), preview: args.preview.enabled() };
+    let mut editor = DefaultEditor::new()?;
+    loop {
+        let line = match editor.readline("colourss> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(e.into()),
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line)?;
+        match line.strip_prefix(':') {
+            Some(command) => match session.command(command) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(message) => println!("{}", message),
+            },
+            None => session.show(line),
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+impl Session {
+    /// Runs a `:` command; `Ok(false)` means quit.
+    fn command(&mut self, command: &str) -> Result<bool, String> {
+        let mut words = command.split_whitespace();
+        let name = words.next().unwrap_or("");
+        let value = words.next();
+        let switch = |value: Option<&str>| match value {
+            Some("on") => Ok(true),
+            Some("off") => Ok(false),
+            _ => Err(format!(":{} takes on or off", name)),
+        };
+        match name {
+            "q" | "quit" | "exit" => return Ok(false),
+            "h" | "help" => println!("{}", HELP),
+            "format" => {
+                self.json = match value {
+                    Some("text") => false,
+                    Some("json") => true,
+                    _ => return Err(":format takes text or json".to_string()),
+                }
+            }
+            // `off` only undoes what `on` sets, so `:strict off` keeps
+            // `--lenient` and `:lenient off` keeps `--strict`
+            "strict" if switch(value)? => self.options = ParseOptions::strict(),
+            "strict" => {
+                let default = ParseOptions::default();
+                self.options.mixed_separators = default.mixed_separators;
+                self.options.require_hsl_percent = default.require_hsl_percent;
+                self.options.clamp_alpha = default.clamp_alpha;
+            }
+            "lenient" if switch(value)? => self.options = ParseOptions::lenient(),
+            "lenient" => {
+                let default = ParseOptions::default();
+                self.options.clamp_components = default.clamp_components;
+                self.options.allow_bare_hex = default.allow_bare_hex;
+                self.options.allow_0x_hex = default.allow_0x_hex;
+                self.options.extra_separators = default.extra_separators;
+                self.options.allow_trailing_comma = default.allow_trailing_comma;
+                self.options.allow_gray_function = default.allow_gray_function;
+            }
+            _ => return Err(format!("unknown command :{} (try :help)", name)),
+        }
+        Ok(true)
+    }
+
+    fn show(&self, input: &str) {
+        let rgba = match Rgba::parse_with(input, &self.options) {
+            Ok(rgba) => rgba,
+            Err(e) => {
+                let suggestion = did_you_mean(&e);
+                if self.json {
+                    println!("{}", json!({ "input": input, "error": e.to_string(), "suggestion": suggestion }));
+                } else {
+                    println!("Error: {}", e);
+                    if let Some(name) = suggestion {
+                        println!("Did you mean '{}'?", name);
+                    }
+                }
+                return;
+            }
+        };
+        let color = Color { r: rgba.r, g: rgba.g, b: rgba.b };
+        let alpha = Some(rgba.a).filter(|&a| a < 1.0);
+        let (name, distance) = color.nearest_named();
+        let hex = serialize(&color, alpha, Notation::Hex, false);
+        let rgb = serialize(&color, alpha, Notation::Rgb, false);
+        let hsl = serialize(&color, alpha, Notation::Hsl, false);
+        if self.json {
+            let distance = (distance as f64 * 100.0).round() / 100.0;
+            println!("{}", json!({ "input": input, "hex": hex, "rgb": rgb, "hsl": hsl, "name": name, "distance": distance }));
+            return;
+        }
+        if self.preview {
+            println!("  {}", preview::label(&color, &hex));
+        } else {
+            println!("  {}", hex);
+        }
+        println!("  {}", rgb);
+        println!("  {}", hsl);
+        if distance == 0.0 {
+            println!("  {}", name);
+        } else {
+            println!("  nearest: {} (ΔE {:.2})", name, distance);
+        }
+    }
+}
+
+/// The keyword spelled most like an unknown color name, if one is close.
+fn did_you_mean(error: &ParseError) -> Option<&'static str> {
+    let ParseError::UnknownColorName(input) = error else { return None };
+    let input = input.to_ascii_lowercase();
+    let (name, distance) = named_colors()
+        .map(|(name, _)| (name, edit_distance(&input, name)))
+        .min_by_key(|&(_, distance)| distance)?;
+    (distance <= (name.len() / 3).max(1)).then_some(name)
+}
+
+/// Levenshtein distance, counting characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let next = (row[j + 1] + 1).min(row[j] + 1).min(diagonal + usize::from(ca != cb));
+            diagonal = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    row[b.len()]
+}
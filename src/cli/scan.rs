@@ -0,0 +1,262 @@
+//! `colourss scan`: find the colors written anywhere in source files.
+//!
+//! Unlike `parse`, which wants one color per line, this runs
+//! [`colourss::find_colors`] over whole files and prints every token it
+//! finds as `path:line:column: token -> #hex`, the format editors read
+//! as a quickfix or error list. Lines and columns count from 1, columns
+//! in bytes. `--unique` prints each distinct color once instead, most
+//! frequent first, with its count and where it first appears.
+//!
+//! Directories are searched recursively, skipping hidden entries and
+//! files that aren't UTF-8 text (images and the like); `--ext` narrows
+//! them down. Patterns like `src/**/*.css` are expanded for shells that
+//! don't. Bare keywords like `red` are matched too unless `--no-named`,
+//! since in prose they are usually just words.
+//!
+//! `--format json` prints an array of `{"file", "line", "column",
+//! "token", "hex"}`, or with `--unique` of `{"hex", "count", "tokens",
+//! "first": {"file", "line", "column"}}`.
+//!
+//! Like grep, it exits with 0 when some color was found, 1 when none
+//! was, and 2 when a file couldn't be read.
+
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use colourss::find_colors;
+use serde_json::{json, Value};
+use std::fs;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use super::convert::{serialize, Notation};
+use super::walk::{self, Source};
+
+#[derive(Args, Debug)]
+pub struct ScanArgs {
+    /// Files, directories or glob patterns to search
+    #[arg(value_name = "PATH", required = true, value_hint = clap::ValueHint::AnyPath)]
+    paths: Vec<String>,
+    /// Print each distinct color once, with how often it appears
+    #[arg(long)]
+    unique: bool,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Match bare color keywords like `red` (the default)
+    #[arg(long, overrides_with = "no_named")]
+    include_named: bool,
+    /// Only match hex codes and color functions, not keywords
+    #[arg(long)]
+    no_named: bool,
+    /// Only search files with these extensions in directories, comma-separated
+    #[arg(long, value_name = "EXT", value_delimiter = ',')]
+    ext: Vec<String>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// One color token found in a file.
+struct Found {
+    file: String,
+    line: usize,
+    column: usize,
+    token: String,
+    hex: String,
+}
+
+pub fn run(args: ScanArgs) -> Result<ExitCode> {
+    let mut found = Vec::new();
+    let unreadable = read_all(&args.paths, &args.ext, |file, text| scan(file, text, !args.no_named, &mut found));
+
+    report(&found, args.format, args.unique, unreadable)
+}
+
+impl Finding for Found {
+    fn place(&self) -> String {
+        format!("{}:{}:{}", self.file, self.line, self.column)
+    }
+
+    fn text(&self) -> String {
+        self.token.clone()
+    }
+
+    fn token(&self) -> &str {
+        &self.token
+    }
+
+    fn hex(&self) -> Result<&str, &str> {
+        Ok(&self.hex)
+    }
+
+    fn json(&self) -> Value {
+        json!({ "file": self.file, "line": self.line, "column": self.column, "token": self.token })
+    }
+
+    fn first(&self) -> Value {
+        json!({ "file": self.file, "line": self.line, "column": self.column })
+    }
+}
+
+/// A color found by `scan` or one of the `scan-*` commands, which
+/// [`report`] prints.
+pub trait Finding {
+    /// Where it is, like `path:line:column`.
+    fn place(&self) -> String;
+    /// What is printed after the place in text output, before the hex.
+    fn text(&self) -> String;
+    /// The color as written.
+    fn token(&self) -> &str;
+    /// The color as hex, or why it didn't parse.
+    fn hex(&self) -> Result<&str, &str>;
+    /// Its JSON object, which gets a `hex` or `error` added.
+    fn json(&self) -> Value;
+    /// The `first` object of its color with `--unique`.
+    fn first(&self) -> Value;
+}
+
+/// Prints `found` in `format`, each distinct color once with `unique`
+/// (colors that didn't parse are left out then), and returns the exit
+/// status: 2 when `unreadable` files couldn't be read, 1 when nothing was
+/// found or something didn't parse, else 0.
+pub fn report<T: Finding>(found: &[T], format: Format, unique: bool, unreadable: usize) -> Result<ExitCode> {
+    let mut out = io::stdout().lock();
+    match (format, unique) {
+        (Format::Text, false) => {
+            for f in found {
+                match f.hex() {
+                    Ok(hex) => writeln!(out, "{}: {} -> {}", f.place(), f.text(), hex)?,
+                    Err(e) => writeln!(out, "{}: {} error: {}", f.place(), f.text(), e)?,
+                }
+            }
+        }
+        (Format::Text, true) => {
+            for (first, hex, count, _) in colors(found) {
+                writeln!(out, "{:>5}  {}  {}", count, hex, first.place())?;
+            }
+        }
+        (Format::Json, false) => {
+            let items: Vec<Value> = found
+                .iter()
+                .map(|f| {
+                    let mut item = f.json();
+                    match f.hex() {
+                        Ok(hex) => item["hex"] = json!(hex),
+                        Err(e) => item["error"] = json!(e),
+                    }
+                    item
+                })
+                .collect();
+            writeln!(out, "{}", serde_json::to_string_pretty(&items)?)?;
+        }
+        (Format::Json, true) => {
+            let items: Vec<Value> = colors(found)
+                .into_iter()
+                .map(|(first, hex, count, tokens)| {
+                    json!({ "hex": hex, "count": count, "tokens": tokens, "first": first.first() })
+                })
+                .collect();
+            writeln!(out, "{}", serde_json::to_string_pretty(&items)?)?;
+        }
+    }
+
+    Ok(if unreadable > 0 {
+        ExitCode::from(crate::EXIT_ERROR)
+    } else if found.is_empty() || found.iter().any(|f| f.hex().is_err()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Calls `each` with the name and text of every file `paths` stand for,
+/// as [`walk::files`] finds them searching directories recursively, and
+/// returns how many couldn't be read; those are reported on stderr.
+pub fn read_all(paths: &[String], ext: &[String], mut each: impl FnMut(&str, &str)) -> usize {
+    let mut files = Vec::new();
+    let mut unreadable = 0;
+    for arg in paths {
+        let found = walk::files(arg, ext, true).unwrap_or_else(|| vec![Ok(Source { path: arg.into(), listed: false })]);
+        for file in found {
+            match file {
+                Ok(file) => files.push(file),
+                Err(e) => {
+                    eprintln!("Error: {:?}", e);
+                    unreadable += 1;
+                }
+            }
+        }
+    }
+
+    for file in &files {
+        let text = match fs::read(&file.path).map(String::from_utf8) {
+            Ok(Ok(text)) => text,
+            Ok(Err(_)) if file.listed => continue,
+            Ok(Err(_)) => {
+                eprintln!("Error: `{:?}` is not UTF-8 text", file.path);
+                unreadable += 1;
+                continue;
+            }
+            Err(e) => {
+                eprintln!("Error: {:?}", anyhow::Error::new(e).context(format!("Could not read file `{:?}`", file.path)));
+                unreadable += 1;
+                continue;
+            }
+        };
+        each(&file.path.display().to_string(), &text);
+    }
+    unreadable
+}
+
+/// Adds the colors in `text` to `found`, with their line and column.
+fn scan(file: &str, text: &str, named: bool, found: &mut Vec<Found>) {
+    let mut line = 1;
+    let mut line_start = 0;
+    let mut counted = 0;
+    for m in find_colors(text) {
+        let token = &text[m.start..m.end];
+        if !named && !token.starts_with('#') && !token.contains('(') {
+            continue;
+        }
+        for (i, b) in text.as_bytes()[counted..m.start].iter().enumerate() {
+            if *b == b'\n' {
+                line += 1;
+                line_start = counted + i + 1;
+            }
+        }
+        counted = m.start;
+        let alpha = Some(m.alpha).filter(|&a| a < 1.0);
+        found.push(Found {
+            file: file.to_string(),
+            line,
+            column: m.start - line_start + 1,
+            token: token.to_string(),
+            hex: serialize(&m.color, alpha, Notation::Hex, false),
+        });
+    }
+}
+
+/// Each distinct color with the first item that has it, its hex, how
+/// many times it was found and how it was spelled, most frequent first;
+/// ties keep the order of first appearance.
+fn colors<T: Finding>(found: &[T]) -> Vec<(&T, &str, usize, Vec<&str>)> {
+    let mut colors: Vec<(&T, &str, usize, Vec<&str>)> = Vec::new();
+    for f in found {
+        let Ok(hex) = f.hex() else { continue };
+        let token = f.token();
+        match colors.iter_mut().find(|(_, first, _, _)| *first == hex) {
+            Some((_, _, count, tokens)) => {
+                *count += 1;
+                if !tokens.contains(&token) {
+                    tokens.push(token);
+                }
+            }
+            None => colors.push((f, hex, 1, vec![token])),
+        }
+    }
+    colors.sort_by_key(|(_, _, count, _)| std::cmp::Reverse(*count));
+    colors
+}
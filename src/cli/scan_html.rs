@@ -0,0 +1,173 @@
+//! `colourss scan-html`: find the colors in old HTML templates.
+//!
+//! Three kinds of places are read: the legacy color attributes (`<font
+//! color>`, `bgcolor`, `text`, `link`, `vlink` and `alink` on `<body>`,
+//! and `bgcolor` on tables and their cells), read with
+//! [`colourss::parse_color_legacy_html`] the way browsers do, so
+//! `chucknorris` is a color; `style` attributes on any element; and
+//! `<style>` blocks. The last two are CSS, searched with
+//! [`colourss::find_colors`] like `scan` does. `<script>` contents are
+//! skipped, and so are comments.
+//!
+//! Each color is printed as `path:line:column: font color: red ->
+//! #ff0000`, naming the element and attribute it was found in, or just
+//! `style` in a `<style>` block. Legacy attributes that are empty or
+//! `transparent` are ignored, as browsers ignore them.
+//!
+//! `--unique` and `--format json` work as for `scan`; the JSON objects
+//! are `{"file", "line", "column", "element", "attribute", "token",
+//! "hex"}`, with a `null` attribute in `<style>` blocks. Exit codes are
+//! those of `scan` too. Directories are searched for `.html` and `.htm`
+//! files unless `--ext` says otherwise.
+
+use anyhow::Result;
+use clap::Args;
+use colourss::{find_colors, parse_color_legacy_html};
+use serde_json::{json, Value};
+use std::process::ExitCode;
+
+use super::convert::{serialize, Notation};
+use super::markup::{items, Item, Lines};
+use super::scan::{read_all, report, Finding, Format};
+
+#[derive(Args, Debug)]
+pub struct ScanHtmlArgs {
+    /// HTML files, directories or glob patterns to search
+    #[arg(value_name = "PATH", required = true, value_hint = clap::ValueHint::AnyPath)]
+    paths: Vec<String>,
+    /// Print each distinct color once, with how often it appears
+    #[arg(long)]
+    unique: bool,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Only search files with these extensions in directories, comma-separated
+    #[arg(long, value_name = "EXT", value_delimiter = ',', default_value = "html,htm")]
+    ext: Vec<String>,
+}
+
+/// The elements with legacy color attributes, and those attributes.
+const LEGACY_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("body", &["bgcolor", "text", "link", "vlink", "alink"]),
+    ("font", &["color"]),
+    ("table", &["bgcolor"]),
+    ("td", &["bgcolor"]),
+    ("th", &["bgcolor"]),
+    ("tr", &["bgcolor"]),
+];
+
+/// One color found in a file.
+struct Found {
+    file: String,
+    line: usize,
+    column: usize,
+    element: String,
+    /// `None` in a `<style>` block.
+    attribute: Option<String>,
+    token: String,
+    hex: String,
+}
+
+pub fn run(args: ScanHtmlArgs) -> Result<ExitCode> {
+    let mut found = Vec::new();
+    let unreadable = read_all(&args.paths, &args.ext, |file, text| scan(file, text, &mut found));
+
+    report(&found, args.format, args.unique, unreadable)
+}
+
+impl Finding for Found {
+    fn place(&self) -> String {
+        format!("{}:{}:{}", self.file, self.line, self.column)
+    }
+
+    fn text(&self) -> String {
+        match &self.attribute {
+            Some(attribute) => format!("{} {}: {}", self.element, attribute, self.token),
+            None => format!("{}: {}", self.element, self.token),
+        }
+    }
+
+    fn token(&self) -> &str {
+        &self.token
+    }
+
+    fn hex(&self) -> Result<&str, &str> {
+        Ok(&self.hex)
+    }
+
+    fn json(&self) -> Value {
+        json!({
+            "file": self.file,
+            "line": self.line,
+            "column": self.column,
+            "element": self.element,
+            "attribute": self.attribute,
+            "token": self.token,
+        })
+    }
+
+    fn first(&self) -> Value {
+        json!({ "file": self.file, "line": self.line, "column": self.column })
+    }
+}
+
+/// Adds the colors in the HTML `text` to `found`.
+fn scan(file: &str, text: &str, found: &mut Vec<Found>) {
+    let lines = Lines::new(text);
+    let mut add = |at: usize, element: &str, attribute: Option<&str>, token: &str, hex: String| {
+        let (line, column) = lines.position(at);
+        found.push(Found {
+            file: file.to_string(),
+            line,
+            column,
+            element: element.to_string(),
+            attribute: attribute.map(str::to_string),
+            token: token.to_string(),
+            hex,
+        });
+    };
+
+    let mut in_style = false;
+    for item in items(text, &["script", "style"]) {
+        match item {
+            Item::Start(tag) => {
+                let element = tag.name.to_ascii_lowercase();
+                in_style = element == "style";
+                let legacy = LEGACY_ATTRIBUTES.iter().find(|(name, _)| *name == element).map_or(&[][..], |(_, names)| names);
+                for attribute in &tag.attributes {
+                    let name = attribute.name.to_ascii_lowercase();
+                    if name == "style" {
+                        for (at, token, hex) in css(attribute.value, attribute.value_at) {
+                            add(at, &element, Some("style"), token, hex);
+                        }
+                    } else if legacy.contains(&name.as_str()) {
+                        // empty and `transparent` are the only errors, and
+                        // browsers ignore the attribute for those
+                        if let Ok(color) = parse_color_legacy_html(attribute.value) {
+                            let hex = serialize(&color, None, Notation::Hex, false);
+                            add(attribute.name_at, &element, Some(&name), attribute.value.trim(), hex);
+                        }
+                    }
+                }
+            }
+            Item::Raw { start, end } if in_style => {
+                for (at, token, hex) in css(&text[start..end], start) {
+                    add(at, "style", None, token, hex);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The colors in the CSS `text`, which starts at byte `at` of the file:
+/// where each starts, as written, and as hex.
+fn css(text: &str, at: usize) -> Vec<(usize, &str, String)> {
+    find_colors(text)
+        .into_iter()
+        .map(|m| {
+            let alpha = Some(m.alpha).filter(|&a| a < 1.0);
+            (at + m.start, &text[m.start..m.end], serialize(&m.color, alpha, Notation::Hex, false))
+        })
+        .collect()
+}
@@ -0,0 +1,227 @@
+//! `colourss scan-svg`: list the colors an SVG paints with.
+//!
+//! Rather than matching color-shaped text anywhere like `scan`, this
+//! walks the markup and reads the presentation attributes that take a
+//! color (`fill`, `stroke`, `stop-color`, `flood-color`,
+//! `lighting-color` and `color`) and the same properties in `style`
+//! attributes. Each is printed as
+//! `path:line:column: /svg/g/path fill="#f60" -> #ff6600`, with the
+//! element's path from the root; `[n]` marks the nth element of that
+//! name under its parent when it isn't the first. A value that doesn't
+//! parse is printed with its error instead of a hex code. Values that
+//! aren't colors but are fine in SVG, like `none`, `currentColor`,
+//! `inherit` or a `url(#gradient)` without fallback, are left out.
+//!
+//! The walk is lightweight, not a full XML parser (see
+//! [`markup`](super::markup)); entities aren't expanded, and `<style>`
+//! elements aren't read.
+//!
+//! `--unique` and `--format json` work as for `scan`; the JSON objects
+//! are `{"file", "line", "column", "element", "attribute", "style",
+//! "value"}` plus `"hex"` or `"error"`, where `style` says whether the
+//! value came from a `style` attribute.
+//!
+//! It exits with 0 when all colors parsed, 1 when some didn't or none
+//! were found, and 2 when a file couldn't be read. Directories are
+//! searched for `.svg` files.
+
+use anyhow::Result;
+use clap::Args;
+use colourss::parse_rgba;
+use serde_json::{json, Value};
+use std::process::ExitCode;
+
+use super::convert::{serialize, Notation};
+use super::markup::{items, Attribute, Item, Lines};
+use super::scan::{read_all, report, Finding, Format};
+
+#[derive(Args, Debug)]
+pub struct ScanSvgArgs {
+    /// SVG files, directories or glob patterns to search
+    #[arg(value_name = "PATH", required = true, value_hint = clap::ValueHint::AnyPath)]
+    paths: Vec<String>,
+    /// Print each distinct color once, with how often it appears
+    #[arg(long)]
+    unique: bool,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+/// Attributes and style properties that take a color.
+const COLOR_ATTRIBUTES: &[&str] = &["color", "fill", "flood-color", "lighting-color", "stop-color", "stroke"];
+
+/// Values of those attributes that aren't colors but aren't mistakes
+/// either, compared ignoring case.
+const NOT_COLORS: &[&str] =
+    &["none", "currentcolor", "inherit", "initial", "unset", "revert", "transparent", "context-fill", "context-stroke"];
+
+/// One color attribute or style property found in a file.
+struct Found {
+    file: String,
+    line: usize,
+    column: usize,
+    element: String,
+    attribute: String,
+    style: bool,
+    value: String,
+    /// The color as hex, or why it didn't parse.
+    hex: Result<String, String>,
+}
+
+pub fn run(args: ScanSvgArgs) -> Result<ExitCode> {
+    let mut found = Vec::new();
+    let unreadable = read_all(&args.paths, &["svg".to_string()], |file, text| scan(file, text, &mut found));
+
+    report(&found, args.format, args.unique, unreadable)
+}
+
+impl Finding for Found {
+    fn place(&self) -> String {
+        format!("{}:{}:{}", self.file, self.line, self.column)
+    }
+
+    fn text(&self) -> String {
+        if self.style {
+            format!("{} style=\"{}: {}\"", self.element, self.attribute, self.value)
+        } else {
+            format!("{} {}=\"{}\"", self.element, self.attribute, self.value)
+        }
+    }
+
+    fn token(&self) -> &str {
+        &self.value
+    }
+
+    fn hex(&self) -> Result<&str, &str> {
+        self.hex.as_deref().map_err(String::as_str)
+    }
+
+    fn json(&self) -> Value {
+        json!({
+            "file": self.file,
+            "line": self.line,
+            "column": self.column,
+            "element": self.element,
+            "attribute": self.attribute,
+            "style": self.style,
+            "value": self.value,
+        })
+    }
+
+    fn first(&self) -> Value {
+        json!({ "file": self.file, "line": self.line, "column": self.column, "element": self.element })
+    }
+}
+
+/// An element whose end tag hasn't been seen yet.
+struct Open {
+    name: String,
+    /// `/svg/g[2]`
+    path: String,
+    /// How many children of each name it has had so far.
+    children: Vec<(String, usize)>,
+}
+
+/// Adds the color attributes of the elements in `text` to `found`.
+fn scan(file: &str, text: &str, found: &mut Vec<Found>) {
+    let lines = Lines::new(text);
+    // the bottom entry stands for the document, to count the root elements
+    let mut stack = vec![Open { name: String::new(), path: String::new(), children: Vec::new() }];
+    for item in items(text, &[]) {
+        let tag = match item {
+            Item::Start(tag) => tag,
+            Item::End(name) => {
+                // a stray end tag closes nothing; a missing one is forgiven
+                if let Some(depth) = stack.iter().rposition(|open| open.name == name)
+                    && depth > 0
+                {
+                    stack.truncate(depth);
+                }
+                continue;
+            }
+            Item::Raw { .. } => continue,
+        };
+        let parent = stack.last_mut().unwrap();
+        let n = match parent.children.iter_mut().find(|(child, _)| *child == tag.name) {
+            Some((_, n)) => {
+                *n += 1;
+                *n
+            }
+            None => {
+                parent.children.push((tag.name.to_string(), 1));
+                1
+            }
+        };
+        let path =
+            if n == 1 { format!("{}/{}", parent.path, tag.name) } else { format!("{}/{}[{}]", parent.path, tag.name, n) };
+        for Attribute { name: attribute, value, name_at, value_at } in tag.attributes {
+            let mut add = |attribute: &str, value: &str, at: usize, style: bool| {
+                if let Some(hex) = parse(value) {
+                    let (line, column) = lines.position(at);
+                    found.push(Found {
+                        file: file.to_string(),
+                        line,
+                        column,
+                        element: path.clone(),
+                        attribute: attribute.to_string(),
+                        style,
+                        value: value.to_string(),
+                        hex,
+                    });
+                }
+            };
+            if attribute == "style" {
+                for (property, value, offset) in declarations(value) {
+                    add(&property, value, value_at + offset, true);
+                }
+            } else if COLOR_ATTRIBUTES.contains(&attribute) {
+                add(attribute, value.trim(), name_at, false);
+            }
+        }
+        if !tag.closed {
+            stack.push(Open { name: tag.name.to_string(), path, children: Vec::new() });
+        }
+    }
+}
+
+/// The color declarations in a `style` attribute: the lowercased
+/// property, the value without `!important`, and where the property
+/// starts in `style`.
+fn declarations(style: &str) -> Vec<(String, &str, usize)> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+    for declaration in style.split(';') {
+        if let Some((property, value)) = declaration.split_once(':') {
+            let property_at = offset + declaration.len() - declaration.trim_start().len();
+            let property = property.trim().to_ascii_lowercase();
+            let value = value.trim();
+            let value = value.strip_suffix("!important").map_or(value, str::trim_end);
+            if COLOR_ATTRIBUTES.contains(&property.as_str()) {
+                found.push((property, value, property_at));
+            }
+        }
+        offset += declaration.len() + 1;
+    }
+    found
+}
+
+/// `Some` with the color of `value` as hex or why it isn't one, or
+/// `None` when `value` is a non-color SVG accepts.
+fn parse(value: &str) -> Option<Result<String, String>> {
+    let mut value = value.trim();
+    if let Some(rest) = value.strip_prefix("url(") {
+        // a paint server, with the color to use when it's missing
+        value = rest.split_once(')').map_or("", |(_, fallback)| fallback.trim());
+        if value.is_empty() {
+            return None;
+        }
+    }
+    if NOT_COLORS.iter().any(|keyword| value.eq_ignore_ascii_case(keyword)) {
+        return None;
+    }
+    Some(match parse_rgba(value) {
+        Ok(rgba) => Ok(serialize(&rgba.to_color(), Some(rgba.a).filter(|&a| a < 1.0), Notation::Hex, false)),
+        Err(e) => Err(e.to_string()),
+    })
+}
@@ -0,0 +1,94 @@
+//! `colourss scan-theme`: check the colors in VS Code theme files.
+//!
+//! Runs [`colourss::themes::extract`] over each file and prints every
+//! color as `path#pointer: value -> #hex`, where the pointer is the JSON
+//! pointer of the value, like `theme.json#/colors/editor.background`. A
+//! value that doesn't parse is printed with its error instead of a hex
+//! code. Fields that don't hold colors are ignored.
+//!
+//! `--unique` and `--format json` work as for `scan`; the JSON objects
+//! are `{"file", "pointer", "value"}` plus `"hex"` or `"error"`, or with
+//! `--unique` `{"hex", "count", "tokens", "first": {"file", "pointer"}}`.
+//!
+//! It exits with 0 when all colors parsed, 1 when some didn't or none
+//! were found, and 2 when a file couldn't be read or isn't JSON.
+//! Directories are searched for `.json` files.
+
+use anyhow::Result;
+use clap::Args;
+use colourss::themes::extract;
+use serde_json::{json, Value};
+use std::process::ExitCode;
+
+use super::convert::{serialize, Notation};
+use super::scan::{read_all, report, Finding, Format};
+
+#[derive(Args, Debug)]
+pub struct ScanThemeArgs {
+    /// Theme files, directories or glob patterns to search
+    #[arg(value_name = "PATH", required = true, value_hint = clap::ValueHint::AnyPath)]
+    paths: Vec<String>,
+    /// Print each distinct color once, with how often it appears
+    #[arg(long)]
+    unique: bool,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+/// One color value found in a theme.
+struct Found {
+    file: String,
+    pointer: String,
+    value: String,
+    /// The color as hex, or why it didn't parse.
+    hex: Result<String, String>,
+}
+
+pub fn run(args: ScanThemeArgs) -> Result<ExitCode> {
+    let mut found = Vec::new();
+    let mut invalid = 0;
+    let unreadable = read_all(&args.paths, &["json".to_string()], |file, text| match extract(text) {
+        Ok(colors) => found.extend(colors.into_iter().map(|c| Found {
+            file: file.to_string(),
+            pointer: c.pointer,
+            value: c.value,
+            hex: c
+                .color
+                .map(|rgba| serialize(&rgba.to_color(), Some(rgba.a).filter(|&a| a < 1.0), Notation::Hex, false))
+                .map_err(|e| e.to_string()),
+        })),
+        Err(e) => {
+            eprintln!("Error: {}: {}", file, e);
+            invalid += 1;
+        }
+    });
+
+    report(&found, args.format, args.unique, unreadable + invalid)
+}
+
+impl Finding for Found {
+    fn place(&self) -> String {
+        format!("{}#{}", self.file, self.pointer)
+    }
+
+    fn text(&self) -> String {
+        self.value.clone()
+    }
+
+    fn token(&self) -> &str {
+        &self.value
+    }
+
+    fn hex(&self) -> Result<&str, &str> {
+        self.hex.as_deref().map_err(String::as_str)
+    }
+
+    fn json(&self) -> Value {
+        json!({ "file": self.file, "pointer": self.pointer, "value": self.value })
+    }
+
+    fn first(&self) -> Value {
+        json!({ "file": self.file, "pointer": self.pointer })
+    }
+}
@@ -0,0 +1,92 @@
+//! `colourss scheme`: palettes built from one base color.
+//!
+//! Harmonies rotate the hue and keep saturation and lightness; ramps mix
+//! towards black, white or gray. Every kind starts with the base color.
+//! `--json` prints an array of `{"hex", "hsl"}` objects.
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use colourss::{parse_color_with, Color};
+use serde_json::json;
+use std::process::ExitCode;
+
+use super::convert::{serialize, Notation};
+use super::mode::ModeArgs;
+use super::preview::{self, When};
+
+#[derive(Args, Debug)]
+pub struct SchemeArgs {
+    /// Base color
+    color: String,
+    /// What to build
+    #[arg(long, value_enum, default_value_t = Kind::Complementary)]
+    kind: Kind,
+    /// Number of colors for analogous, shades, tints and tones
+    #[arg(long, value_name = "N", default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+    count: u64,
+    /// Notation to print
+    #[arg(long, value_enum, default_value_t = Notation::Hex)]
+    format: Notation,
+    /// Print a JSON array with each color's hex and hsl
+    #[arg(long, conflicts_with_all = ["format", "preview"])]
+    json: bool,
+    /// Draw a swatch after each color
+    #[arg(long, value_enum, value_name = "WHEN", num_args = 0..=1, require_equals = true, default_missing_value = "auto")]
+    preview: Option<When>,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    /// The base and the hue opposite it
+    Complementary,
+    /// The base and the two hues next to its complement
+    SplitComplementary,
+    /// Three hues 120° apart
+    Triadic,
+    /// Four hues 90° apart
+    Tetradic,
+    /// `--count` hues 30° apart
+    Analogous,
+    /// `--count` steps towards black
+    Shades,
+    /// `--count` steps towards white
+    Tints,
+    /// `--count` steps towards gray
+    Tones,
+}
+
+pub fn run(args: SchemeArgs) -> Result<ExitCode> {
+    let base = parse_color_with(&args.color, &args.mode.options()).context("Invalid color")?;
+    let n = args.count as usize;
+    let colors: Vec<Color> = match args.kind {
+        Kind::Complementary => vec![base, base.complementary()],
+        Kind::SplitComplementary => base.split_complementary().to_vec(),
+        Kind::Triadic => base.triadic().to_vec(),
+        Kind::Tetradic => base.tetradic().to_vec(),
+        Kind::Analogous => base.analogous(n, 30.0),
+        Kind::Shades => base.shades(n),
+        Kind::Tints => base.tints(n),
+        Kind::Tones => base.tones(n),
+    };
+
+    if args.json {
+        let list: Vec<_> = colors
+            .iter()
+            .map(|c| json!({ "hex": c.to_hex(), "hsl": serialize(c, None, Notation::Hsl, false) }))
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&list)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+    let preview = args.preview.is_some_and(When::enabled);
+    for color in &colors {
+        let text = serialize(color, None, args.format, false);
+        if preview {
+            println!("{} {}", text, preview::swatch(color));
+        } else {
+            println!("{}", text);
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
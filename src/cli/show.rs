@@ -0,0 +1,54 @@
+//! `colourss show`: print colors as labelled swatches.
+//!
+//! Each color gets one line: its hex on a block of the color itself,
+//! then the `rgb()` form and the keyword if it has one. Without a
+//! truecolor terminal (see [`When`]) the swatch is left out and only the
+//! text is printed.
+
+use anyhow::Result;
+use clap::Args;
+use colourss::parse_color_with;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use super::mode::ModeArgs;
+use super::preview::{self, When};
+
+#[derive(Args, Debug)]
+pub struct ShowArgs {
+    /// Colors to show
+    #[arg(value_name = "COLOR", required = true)]
+    colors: Vec<String>,
+    /// When to draw the swatch
+    #[arg(long, value_enum, value_name = "WHEN", default_value_t = When::Auto)]
+    preview: When,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+pub fn run(args: ShowArgs) -> Result<ExitCode> {
+    let preview = args.preview.enabled();
+    let options = args.mode.options();
+    let mut out = io::stdout().lock();
+    let mut failed = false;
+
+    for input in &args.colors {
+        let color = match parse_color_with(input, &options) {
+            Ok(color) => color,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                failed = true;
+                continue;
+            }
+        };
+        let hex = color.to_hex();
+        let mut line = if preview { preview::label(&color, &hex) } else { hex };
+        line.push_str(&format!("  rgb({} {} {})", color.r, color.g, color.b));
+        if let Some(name) = color.name() {
+            line.push_str(&format!("  {}", name));
+        }
+        writeln!(out, "{}", line)?;
+    }
+
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
@@ -0,0 +1,85 @@
+//! `colourss sort`: reorder a list of colors.
+//!
+//! Lines are printed as they were written unless `--normalize` asks for
+//! one notation. The sort is stable, so equal colors keep their order.
+//! A line that doesn't parse stops the command with exit status 1 before
+//! anything is printed, unless `--keep-invalid` sends such lines to the
+//! end of the output instead.
+
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use colourss::{parse_color_with, Palette};
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use super::convert::{serialize, Notation};
+use super::input::read_lines;
+use super::mode::ModeArgs;
+
+#[derive(Args, Debug)]
+pub struct SortArgs {
+    /// File with one color per line; stdin if missing or `-`
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    file: Option<String>,
+    /// What to sort by
+    #[arg(long, value_enum, default_value_t = By::Hue)]
+    by: By,
+    /// Print every color in this notation instead of as written
+    #[arg(long, value_enum, value_name = "NOTATION")]
+    normalize: Option<Notation>,
+    /// Print lines that don't parse at the end instead of failing
+    #[arg(long)]
+    keep_invalid: bool,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum By {
+    /// Hue starting at red, grays first (dark to light)
+    Hue,
+    /// Darkest first
+    Luminance,
+    /// Grays first
+    Saturation,
+    /// Alphabetically by nearest color keyword
+    Name,
+}
+
+pub fn run(args: SortArgs) -> Result<ExitCode> {
+    let mut palette = Palette::new();
+    let mut invalid = Vec::new();
+    let options = args.mode.options();
+    for (n, line) in read_lines(args.file.as_deref())? {
+        match parse_color_with(&line, &options) {
+            Ok(color) => palette.push_labeled(color, line),
+            Err(e) if args.keep_invalid => {
+                eprintln!("Line {}: {}", n, e);
+                invalid.push(line);
+            }
+            Err(e) => {
+                eprintln!("Error: line {}: {}", n, e);
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    }
+
+    match args.by {
+        By::Hue => palette.sort_by_hue(),
+        By::Luminance => palette.sort_by_luminance(),
+        By::Saturation => palette.sort_by_saturation(),
+        By::Name => palette.sort_by_cached_key(|color| color.nearest_named().0),
+    }
+
+    let mut out = io::stdout().lock();
+    for (i, color) in palette.iter().enumerate() {
+        match args.normalize {
+            Some(to) => writeln!(out, "{}", serialize(color, None, to, false))?,
+            None => writeln!(out, "{}", palette.label(i).unwrap_or_default())?,
+        }
+    }
+    for line in invalid {
+        writeln!(out, "{}", line)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
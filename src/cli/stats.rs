@@ -0,0 +1,265 @@
+//! `colourss stats`: a summary of a color file.
+//!
+//! Counts lines, parse results and notations, lists the most frequent
+//! colors, draws a histogram of hues in twelve 30° buckets (grays have no
+//! hue and are counted on their own) and names the lightest and darkest
+//! colors. `--json` (or `--format json`) prints the same numbers as one
+//! object:
+//!
+//! `{"lines", "ok", "failed", "skipped", "formats": {...}, "most_frequent":
+//! [{"hex", "count"}], "hues": [12 counts], "grays", "lightest", "darkest"}`
+//!
+//! CSS-wide keywords like `inherit` are counted as skipped, not failed.
+//!
+//! `--format html` makes it a standalone page, with swatches next to the
+//! colors. `--output` writes any of them to a file.
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use colourss::{parse_color_with, Color, ParseError};
+use serde_json::{json, Map, Value};
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use super::html;
+use super::input::read_lines;
+use super::mode::ModeArgs;
+
+#[derive(Args, Debug)]
+pub struct StatsArgs {
+    /// File with one color per line; stdin if missing or `-`
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    file: Option<String>,
+    /// How many of the most frequent colors to list
+    #[arg(long, value_name = "N", default_value_t = 5)]
+    top: usize,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Print a JSON object instead of text, like `--format json`
+    #[arg(long, conflicts_with = "format")]
+    json: bool,
+    /// Write the summary to this file instead of stdout
+    #[arg(long, short, value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    output: Option<PathBuf>,
+    #[command(flatten)]
+    mode: ModeArgs,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Text,
+    Json,
+    Html,
+}
+
+/// Notations counted separately, in output order.
+const FORMATS: [&str; 6] = ["hex-short", "hex-long", "rgb-legacy", "rgb-modern", "hsl", "named"];
+
+/// Which of [`FORMATS`] a successfully parsed line is written in.
+fn format_of(input: &str) -> &'static str {
+    let input = input.trim();
+    let head = input.get(..3).unwrap_or("").to_ascii_lowercase();
+    if let Some(digits) = input.strip_prefix('#') {
+        if digits.len() <= 4 { "hex-short" } else { "hex-long" }
+    } else if head == "rgb" {
+        if input.contains(',') { "rgb-legacy" } else { "rgb-modern" }
+    } else if head == "hsl" {
+        "hsl"
+    } else {
+        "named"
+    }
+}
+
+#[derive(Default)]
+struct Stats {
+    lines: usize,
+    failed: usize,
+    /// CSS-wide keywords, which aren't colors but aren't mistakes.
+    skipped: usize,
+    formats: [usize; FORMATS.len()],
+    /// Distinct colors in order of first appearance, with counts.
+    counts: Vec<(Color, usize)>,
+    hues: [usize; 12],
+    grays: usize,
+    lightest: Option<Color>,
+    darkest: Option<Color>,
+}
+
+impl Stats {
+    fn add(&mut self, input: &str, color: Color) {
+        let format = format_of(input);
+        self.formats[FORMATS.iter().position(|f| *f == format).unwrap()] += 1;
+
+        match self.counts.iter_mut().find(|(c, _)| *c == color) {
+            Some((_, n)) => *n += 1,
+            None => self.counts.push((color, 1)),
+        }
+
+        let (h, s, _) = color.to_hsl();
+        if s == 0.0 {
+            self.grays += 1;
+        } else {
+            self.hues[(h / 30.0) as usize % 12] += 1;
+        }
+
+        let lum = color.relative_luminance();
+        if self.lightest.is_none_or(|c| lum > c.relative_luminance()) {
+            self.lightest = Some(color);
+        }
+        if self.darkest.is_none_or(|c| lum < c.relative_luminance()) {
+            self.darkest = Some(color);
+        }
+    }
+
+    fn ok(&self) -> usize {
+        self.lines - self.failed - self.skipped
+    }
+
+    /// `, N skipped` when there were any.
+    fn skipped_note(&self) -> String {
+        if self.skipped > 0 { format!(", {} skipped", self.skipped) } else { String::new() }
+    }
+
+    /// The `n` most frequent colors; ties keep the order of first appearance.
+    fn most_frequent(&self, n: usize) -> Vec<(Color, usize)> {
+        let mut counts = self.counts.clone();
+        counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        counts.truncate(n);
+        counts
+    }
+}
+
+pub fn run(args: StatsArgs) -> Result<ExitCode> {
+    let mut stats = Stats::default();
+    let options = args.mode.options();
+    for (_, line) in read_lines(args.file.as_deref())? {
+        stats.lines += 1;
+        match parse_color_with(&line, &options) {
+            Ok(color) => stats.add(&line, color),
+            Err(ParseError::CssWideKeyword(_)) => stats.skipped += 1,
+            Err(_) => stats.failed += 1,
+        }
+    }
+
+    let mut out: Box<dyn Write> = match &args.output {
+        Some(path) => {
+            let file = File::create(path).with_context(|| format!("Could not write file `{:?}`", path))?;
+            Box::new(io::BufWriter::new(file))
+        }
+        None => Box::new(io::stdout().lock()),
+    };
+    match if args.json { Format::Json } else { args.format } {
+        Format::Text => write_text(&mut out, &stats, args.top)?,
+        Format::Json => writeln!(out, "{}", serde_json::to_string_pretty(&to_json(&stats, args.top))?)?,
+        Format::Html => write!(out, "{}", html::page("ColourSS stats", &to_html(&stats, args.top)))?,
+    }
+    out.flush()?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn write_text(out: &mut impl Write, stats: &Stats, top: usize) -> io::Result<()> {
+    writeln!(out, "Lines: {} ({} parsed, {} failed{})", stats.lines, stats.ok(), stats.failed, stats.skipped_note())?;
+
+    writeln!(out, "\nFormats:")?;
+    for (name, count) in FORMATS.iter().zip(stats.formats) {
+        writeln!(out, "  {:<12} {}", name, count)?;
+    }
+
+    writeln!(out, "\nMost frequent:")?;
+    for (color, count) in stats.most_frequent(top) {
+        writeln!(out, "  {:>5}  {}", count, describe(&color))?;
+    }
+
+    writeln!(out, "\nHues:")?;
+    let max = stats.hues.iter().copied().max().unwrap_or(0).max(1);
+    for (i, count) in stats.hues.iter().enumerate() {
+        let bar = "#".repeat((count * 30).div_ceil(max));
+        let row = format!("  {:>3}-{:<3} {:>5} {}", i * 30, i * 30 + 30, count, bar);
+        writeln!(out, "{}", row.trim_end())?;
+    }
+    writeln!(out, "  gray    {:>5}", stats.grays)?;
+
+    if let (Some(lightest), Some(darkest)) = (stats.lightest, stats.darkest) {
+        writeln!(out, "\nLightest: {}", describe(&lightest))?;
+        writeln!(out, "Darkest:  {}", describe(&darkest))?;
+    }
+    Ok(())
+}
+
+/// `#rrggbb`, with the keyword in parentheses if there is one.
+fn describe(color: &Color) -> String {
+    match color.name() {
+        Some(name) => format!("{} ({})", color.to_hex(), name),
+        None => color.to_hex(),
+    }
+}
+
+/// The body of the `--format html` page: the counts at the top, then a
+/// table for each part of the text output.
+fn to_html(stats: &Stats, top: usize) -> String {
+    let row = |color: &Color, label: &str| {
+        let name = color.name().map_or(String::new(), |n| n.to_string());
+        format!("<tr><td>{}</td>{}<td><code>{}</code></td><td>{}</td></tr>\n", label, html::swatch(color), color.to_hex(), name)
+    };
+    let mut body = format!(
+        "<p class=\"summary\">{} lines: {} parsed, {} failed{}.</p>\n",
+        stats.lines,
+        stats.ok(),
+        stats.failed,
+        stats.skipped_note()
+    );
+
+    body.push_str("<h2>Formats</h2>\n<table>\n<tr><th>Format</th><th>Lines</th></tr>\n");
+    for (name, count) in FORMATS.iter().zip(stats.formats) {
+        body.push_str(&format!("<tr><td>{}</td><td>{}</td></tr>\n", name, count));
+    }
+    body.push_str("</table>\n");
+
+    body.push_str("<h2>Most frequent</h2>\n<table>\n<tr><th>Count</th><th>Color</th><th>Hex</th><th>Name</th></tr>\n");
+    for (color, count) in stats.most_frequent(top) {
+        body.push_str(&row(&color, &count.to_string()));
+    }
+    body.push_str("</table>\n");
+
+    body.push_str("<h2>Hues</h2>\n<table>\n<tr><th>Hue</th><th>Lines</th></tr>\n");
+    for (i, count) in stats.hues.iter().enumerate() {
+        body.push_str(&format!("<tr><td>{}-{}°</td><td>{}</td></tr>\n", i * 30, i * 30 + 30, count));
+    }
+    body.push_str(&format!("<tr><td>gray</td><td>{}</td></tr>\n</table>\n", stats.grays));
+
+    if let (Some(lightest), Some(darkest)) = (stats.lightest, stats.darkest) {
+        body.push_str("<h2>Extremes</h2>\n<table>\n");
+        body.push_str(&row(&lightest, "Lightest"));
+        body.push_str(&row(&darkest, "Darkest"));
+        body.push_str("</table>\n");
+    }
+    body
+}
+
+fn to_json(stats: &Stats, top: usize) -> Value {
+    let formats: Map<String, Value> = FORMATS
+        .iter()
+        .zip(stats.formats)
+        .map(|(name, count)| (name.to_string(), json!(count)))
+        .collect();
+    let most_frequent: Vec<Value> = stats
+        .most_frequent(top)
+        .iter()
+        .map(|(color, count)| json!({ "hex": color.to_hex(), "count": count }))
+        .collect();
+    json!({
+        "lines": stats.lines,
+        "ok": stats.ok(),
+        "failed": stats.failed,
+        "skipped": stats.skipped,
+        "formats": formats,
+        "most_frequent": most_frequent,
+        "hues": stats.hues,
+        "grays": stats.grays,
+        "lightest": stats.lightest.map(|c| c.to_hex()),
+        "darkest": stats.darkest.map(|c| c.to_hex()),
+    })
+}
@@ -0,0 +1,79 @@
+//! The files a path argument stands for, for `parse` and the `scan`
+//! commands.
+//!
+//! An argument is a file, a directory, whose files are read sorted by
+//! name, or a glob pattern like `src/**/*.css` for shells that don't
+//! expand them. Hidden entries in directories are skipped, and like a
+//! shell, `*` doesn't match the leading dot of hidden names.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A file found by [`files`].
+pub struct Source {
+    pub path: PathBuf,
+    /// Found in a directory rather than named.
+    pub listed: bool,
+}
+
+/// The files `arg` stands for, or `None` when it is neither an existing
+/// path nor a glob pattern.
+///
+/// Files in directories are kept when their extension is in `ext`, or
+/// all of them when it is empty, and subdirectories are searched with
+/// `recursive`. A directory that can't be read, or a pattern nothing
+/// matches, is an `Err` among the files; the others are still found.
+pub fn files(arg: &str, ext: &[String], recursive: bool) -> Option<Vec<Result<Source>>> {
+    let path = Path::new(arg);
+    let mut files = Vec::new();
+    if path.is_dir() {
+        list_dir(path, ext, recursive, &mut files);
+    } else if path.exists() {
+        files.push(Ok(Source { path: path.to_path_buf(), listed: false }));
+    } else if arg.contains(['*', '?', '[']) {
+        let options = glob::MatchOptions { require_literal_leading_dot: true, ..Default::default() };
+        for path in glob::glob_with(arg, options).ok()? {
+            match path {
+                Ok(path) if path.is_dir() => list_dir(&path, ext, recursive, &mut files),
+                Ok(path) => files.push(Ok(Source { path, listed: false })),
+                Err(e) => files.push(Err(e.into())),
+            }
+        }
+        if files.is_empty() {
+            files.push(Err(anyhow::anyhow!("No files match `{}`", arg)));
+        }
+    } else {
+        return None;
+    }
+    Some(files)
+}
+
+fn list_dir(dir: &Path, ext: &[String], recursive: bool, files: &mut Vec<Result<Source>>) {
+    let entries = fs::read_dir(dir)
+        .and_then(|entries| entries.map(|e| e.map(|e| e.path())).collect::<io::Result<Vec<_>>>())
+        .with_context(|| format!("Could not read directory `{:?}`", dir));
+    let mut paths = match entries {
+        Ok(paths) => paths,
+        Err(e) => {
+            files.push(Err(e));
+            return;
+        }
+    };
+    paths.sort();
+    for path in paths {
+        if path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.')) {
+            continue;
+        }
+        if path.is_dir() {
+            if recursive {
+                list_dir(&path, ext, recursive, files);
+            }
+        } else if ext.is_empty() || path.extension().is_some_and(|e| {
+            ext.iter().any(|want| want.trim_start_matches('.').eq_ignore_ascii_case(&e.to_string_lossy()))
+        }) {
+            files.push(Ok(Source { path, listed: true }));
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! 16-bit per channel colors, as in 16-bit PNGs.
+
+use crate::{Channels, Color};
+
+/// An sRGB color with 16 bits per channel.
+///
+/// Widening a [`Color`] replicates each byte (`0xab` becomes `0xabab`),
+/// so black and white stay black and white and narrowing gives the
+/// original back. [`parse_color16`](crate::parse_color16) keeps the
+/// extra precision of percentages and `hsl()`, which [`Color`] rounds
+/// away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color16 {
+    pub r: u16,
+    pub g: u16,
+    pub b: u16,
+}
+
+impl Color16 {
+    pub const fn new(r: u16, g: u16, b: u16) -> Color16 {
+        Color16 { r, g, b }
+    }
+
+    /// Rounds each channel to the nearest 8-bit value.
+    pub fn to_color(&self) -> Color {
+        let narrow = |c: u16| ((c as u32 * 255 + 32767) / 65535) as u8;
+        Color {
+            r: narrow(self.r),
+            g: narrow(self.g),
+            b: narrow(self.b),
+        }
+    }
+
+    /// The 12-digit hex code X11 and ImageMagick use, `#rrrrggggbbbb`.
+    pub fn to_hex(&self) -> String {
+        format!("#{:04x}{:04x}{:04x}", self.r, self.g, self.b)
+    }
+
+    pub(crate) fn from_channels(channels: Channels) -> Color16 {
+        let widen = |c: f32, scale: f32| (c / scale * 65535.0).round().clamp(0.0, 65535.0) as u16;
+        match channels {
+            Channels::Bytes(color) => color.into(),
+            Channels::Rgb([r, g, b]) => Color16::new(widen(r, 255.0), widen(g, 255.0), widen(b, 255.0)),
+            Channels::Hsl([r, g, b]) => Color16::new(widen(r, 1.0), widen(g, 1.0), widen(b, 1.0)),
+        }
+    }
+}
+
+impl From<Color> for Color16 {
+    fn from(color: Color) -> Color16 {
+        let widen = |c: u8| c as u16 * 0x101;
+        Color16 {
+            r: widen(color.r),
+            g: widen(color.g),
+            b: widen(color.b),
+        }
+    }
+}
+
+impl From<Color16> for Color {
+    fn from(color: Color16) -> Color {
+        color.to_color()
+    }
+}
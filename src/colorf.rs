@@ -0,0 +1,103 @@
+//! A color with `f32` channels, for chaining adjustments without rounding.
+
+use crate::hsl::{hsl_to_rgb_f32, rgb_f32_to_hsl};
+use crate::{BlendMode, Color};
+
+/// A gamma-encoded sRGB color with `f32` channels in `0.0..=1.0`.
+///
+/// Every [`Color`] operation rounds its result back to bytes, so a chain
+/// of small steps (lighten a little, mix a little, lighten again) loses up
+/// to half a step each time and the errors add up. `ColorF` keeps full
+/// precision in between: convert once with [`ColorF::from`], do the work,
+/// and round once at the end with [`ColorF::to_color`].
+///
+/// Methods keep channels in `0.0..=1.0`; values set by hand may stray
+/// outside it and are clamped when converting back.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorF {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl ColorF {
+    pub const fn new(r: f32, g: f32, b: f32) -> ColorF {
+        ColorF { r, g, b }
+    }
+
+    /// Rounds to the nearest 8-bit color. Channels are clamped to
+    /// `0.0..=1.0` first, and NaN becomes `0`.
+    pub fn to_color(&self) -> Color {
+        let byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Color {
+            r: byte(self.r),
+            g: byte(self.g),
+            b: byte(self.b),
+        }
+    }
+
+    /// `(hue, saturation, lightness)`, like [`Color::to_hsl`].
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        rgb_f32_to_hsl(self.r, self.g, self.b)
+    }
+
+    /// Like [`Color::from_hsl`], without rounding.
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> ColorF {
+        let (r, g, b) = hsl_to_rgb_f32(h, s, l);
+        ColorF { r, g, b }
+    }
+
+    /// Like [`Color::mix`]: `t` is clamped to `0.0..=1.0` (NaN counts as
+    /// `0.0`) and the endpoints are exact.
+    pub fn mix(&self, other: &ColorF, t: f32) -> ColorF {
+        if t.is_nan() || t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *other;
+        }
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        ColorF {
+            r: lerp(self.r, other.r),
+            g: lerp(self.g, other.g),
+            b: lerp(self.b, other.b),
+        }
+    }
+
+    /// Like [`Color::lighten`]: adds `amount` to the HSL lightness.
+    pub fn lighten(&self, amount: f32) -> ColorF {
+        let (h, s, l) = self.to_hsl();
+        ColorF::from_hsl(h, s, l + amount)
+    }
+
+    /// Like [`Color::darken`].
+    pub fn darken(&self, amount: f32) -> ColorF {
+        self.lighten(-amount)
+    }
+
+    /// Like [`Color::blend`]: blends `self` (the source) onto `backdrop`.
+    pub fn blend(&self, backdrop: &ColorF, mode: BlendMode) -> ColorF {
+        let channel = |cs: f32, cb: f32| mode.apply(cb.clamp(0.0, 1.0), cs.clamp(0.0, 1.0)).clamp(0.0, 1.0);
+        ColorF {
+            r: channel(self.r, backdrop.r),
+            g: channel(self.g, backdrop.g),
+            b: channel(self.b, backdrop.b),
+        }
+    }
+}
+
+impl From<Color> for ColorF {
+    fn from(color: Color) -> ColorF {
+        ColorF {
+            r: color.r as f32 / 255.0,
+            g: color.g as f32 / 255.0,
+            b: color.b as f32 / 255.0,
+        }
+    }
+}
+
+impl From<ColorF> for Color {
+    fn from(color: ColorF) -> Color {
+        color.to_color()
+    }
+}
@@ -0,0 +1,66 @@
+use crate::linear::{linear_to_srgb, srgb_to_linear};
+use crate::Color;
+
+/// A type of color vision deficiency to simulate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cvd {
+    /// No working L (red) cones.
+    Protanopia,
+    /// No working M (green) cones.
+    Deuteranopia,
+    /// No working S (blue) cones.
+    Tritanopia,
+    /// No color vision at all.
+    Achromatopsia,
+}
+
+// Machado, Oliveira & Fernandes (2009), severity 1.0, for linear RGB.
+const PROTANOPIA: [[f32; 3]; 3] = [
+    [0.152286, 1.052583, -0.204868],
+    [0.114503, 0.786281, 0.099216],
+    [-0.003882, -0.048116, 1.051998],
+];
+const DEUTERANOPIA: [[f32; 3]; 3] = [
+    [0.367322, 0.860646, -0.227968],
+    [0.280085, 0.672501, 0.047413],
+    [-0.011820, 0.042940, 0.968881],
+];
+const TRITANOPIA: [[f32; 3]; 3] = [
+    [1.255528, -0.076749, -0.178779],
+    [-0.078411, 0.930809, 0.147602],
+    [0.004733, 0.691367, 0.303900],
+];
+
+impl Cvd {
+    /// Simulates this deficiency on every color of a slice.
+    pub fn simulate_all(self, colors: &[Color]) -> Vec<Color> {
+        colors.iter().map(|c| c.simulate(self)).collect()
+    }
+}
+
+impl Color {
+    /// Approximately how this color looks to someone with the given deficiency.
+    ///
+    /// The dichromacies use the Machado et al. (2009) matrices at full
+    /// severity, applied in linear RGB. Achromatopsia is the same as
+    /// [`Color::grayscale`].
+    pub fn simulate(&self, cvd: Cvd) -> Color {
+        let m = match cvd {
+            Cvd::Protanopia => &PROTANOPIA,
+            Cvd::Deuteranopia => &DEUTERANOPIA,
+            Cvd::Tritanopia => &TRITANOPIA,
+            Cvd::Achromatopsia => return self.grayscale(),
+        };
+        let rgb = [
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+        ];
+        let row = |i: usize| linear_to_srgb(m[i][0] * rgb[0] + m[i][1] * rgb[1] + m[i][2] * rgb[2]);
+        Color {
+            r: row(0),
+            g: row(1),
+            b: row(2),
+        }
+    }
+}
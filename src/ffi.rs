@@ -0,0 +1,164 @@
+//! C bindings, enabled with the `ffi` feature.
+//!
+//! The matching header lives in `include/colourss.h` and is generated with
+//! `cbindgen --config cbindgen.toml --output include/colourss.h src/ffi.rs`.
+//!
+//! Every function returns `0` (or a byte count) on success and one of the
+//! negative `COLOURSS_ERR_*` codes on failure; nothing here unwinds across
+//! the C boundary.
+
+use std::ffi::{c_char, c_int, CStr};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::{parse_color_alpha, Color, ParseError};
+
+/// Input was not a valid hex code.
+pub const COLOURSS_ERR_INVALID_HEX: c_int = -1;
+/// Input was not a valid `rgb()`/`rgba()` call.
+pub const COLOURSS_ERR_INVALID_RGB: c_int = -2;
+/// Input was not a valid `hsl()`/`hsla()` call.
+pub const COLOURSS_ERR_INVALID_HSL: c_int = -3;
+/// A component was out of range.
+pub const COLOURSS_ERR_INVALID_COMPONENT: c_int = -4;
+/// Input was not a known color name.
+pub const COLOURSS_ERR_UNKNOWN_NAME: c_int = -5;
+/// A number inside the input could not be parsed.
+pub const COLOURSS_ERR_PARSE_FAILURE: c_int = -6;
+/// A required pointer argument was null.
+pub const COLOURSS_ERR_NULL_POINTER: c_int = -7;
+/// The input string was not valid UTF-8.
+pub const COLOURSS_ERR_INVALID_UTF8: c_int = -8;
+/// The output buffer was too small.
+pub const COLOURSS_ERR_BUFFER_TOO_SMALL: c_int = -9;
+/// The library panicked; this is always a bug.
+pub const COLOURSS_ERR_PANIC: c_int = -10;
+/// The input was a CSS-wide keyword like `inherit`, not a color.
+pub const COLOURSS_ERR_CSS_WIDE_KEYWORD: c_int = -11;
+
+/// A parsed color as seen from C.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColourssColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    /// Alpha in `0.0..=1.0`; `1.0` for inputs without an alpha component.
+    pub a: f32,
+}
+
+fn error_code(e: &ParseError) -> c_int {
+    match e {
+        ParseError::InvalidHexFormat(_) => COLOURSS_ERR_INVALID_HEX,
+        ParseError::InvalidRgbFormat(_) => COLOURSS_ERR_INVALID_RGB,
+        ParseError::InvalidHslFormat(_) => COLOURSS_ERR_INVALID_HSL,
+        ParseError::InvalidComponentValue { .. } | ParseError::EmptyComponent { .. } => {
+            COLOURSS_ERR_INVALID_COMPONENT
+        }
+        ParseError::UnknownColorName(_) => COLOURSS_ERR_UNKNOWN_NAME,
+        ParseError::ParseFailure { .. } | ParseError::InvalidTemperature(_) => COLOURSS_ERR_PARSE_FAILURE,
+        ParseError::InvalidUtf8(_) => COLOURSS_ERR_INVALID_UTF8,
+        ParseError::ListItem { error, .. } => error_code(error),
+        ParseError::CssWideKeyword(_) => COLOURSS_ERR_CSS_WIDE_KEYWORD,
+    }
+}
+
+/// Runs `f`, turning a panic into [`COLOURSS_ERR_PANIC`].
+fn guard(f: impl FnOnce() -> c_int) -> c_int {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(COLOURSS_ERR_PANIC)
+}
+
+/// Parses the NUL-terminated string `input` into `*out`.
+///
+/// Returns `0` on success or a negative `COLOURSS_ERR_*` code; `*out` is
+/// left untouched on failure.
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string, and `out`
+/// must be null or valid for writing a `ColourssColor`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn colourss_parse(input: *const c_char, out: *mut ColourssColor) -> c_int {
+    guard(|| {
+        if input.is_null() || out.is_null() {
+            return COLOURSS_ERR_NULL_POINTER;
+        }
+        // SAFETY: the caller guarantees `input` is NUL-terminated
+        let Ok(input) = unsafe { CStr::from_ptr(input) }.to_str() else {
+            return COLOURSS_ERR_INVALID_UTF8;
+        };
+        match parse_color_alpha(input) {
+            Ok((color, a)) => {
+                // SAFETY: the caller guarantees `out` is writable
+                unsafe {
+                    out.write(ColourssColor {
+                        r: color.r,
+                        g: color.g,
+                        b: color.b,
+                        a,
+                    })
+                };
+                0
+            }
+            Err(e) => error_code(&e),
+        }
+    })
+}
+
+/// A static, NUL-terminated description of `code`.
+///
+/// Never returns null; unknown codes get a generic message. The string
+/// must not be freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn colourss_error_message(code: c_int) -> *const c_char {
+    let message: &'static CStr = match code {
+        0 => c"Success",
+        COLOURSS_ERR_INVALID_HEX => c"Invalid hex code format",
+        COLOURSS_ERR_INVALID_RGB => c"Invalid RGB/RGBA format",
+        COLOURSS_ERR_INVALID_HSL => c"Invalid HSL/HSLA format",
+        COLOURSS_ERR_INVALID_COMPONENT => c"Invalid component value",
+        COLOURSS_ERR_UNKNOWN_NAME => c"Unknown color name",
+        COLOURSS_ERR_PARSE_FAILURE => c"Failed to parse number",
+        COLOURSS_ERR_NULL_POINTER => c"Null pointer argument",
+        COLOURSS_ERR_INVALID_UTF8 => c"Input is not valid UTF-8",
+        COLOURSS_ERR_BUFFER_TOO_SMALL => c"Output buffer too small",
+        COLOURSS_ERR_PANIC => c"Internal error",
+        COLOURSS_ERR_CSS_WIDE_KEYWORD => c"CSS-wide keyword",
+        _ => c"Unknown error code",
+    };
+    message.as_ptr()
+}
+
+/// Writes `color` as a NUL-terminated lowercase `#rrggbb` into `buf`.
+///
+/// `len` is the size of `buf` in bytes and must be at least 8. Returns the
+/// number of characters written, not counting the NUL, or a negative
+/// `COLOURSS_ERR_*` code. Alpha is ignored.
+///
+/// # Safety
+///
+/// `color` must be null or point to a valid `ColourssColor`, and `buf`
+/// must be null or valid for writing `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn colourss_to_hex(
+    color: *const ColourssColor,
+    buf: *mut c_char,
+    len: usize,
+) -> c_int {
+    guard(|| {
+        if color.is_null() || buf.is_null() {
+            return COLOURSS_ERR_NULL_POINTER;
+        }
+        // SAFETY: the caller guarantees `color` is valid for reads
+        let c = unsafe { color.read() };
+        let hex = Color { r: c.r, g: c.g, b: c.b }.to_hex();
+        if len < hex.len() + 1 {
+            return COLOURSS_ERR_BUFFER_TOO_SMALL;
+        }
+        // SAFETY: `buf` holds at least `hex.len() + 1` bytes, checked above
+        unsafe {
+            std::ptr::copy_nonoverlapping(hex.as_ptr(), buf.cast::<u8>(), hex.len());
+            buf.add(hex.len()).write(0);
+        }
+        hex.len() as c_int
+    })
+}
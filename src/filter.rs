@@ -0,0 +1,44 @@
+use crate::Color;
+
+/// CSS-filter-style adjustments.
+///
+/// These follow the `invert()`, `grayscale()` and `sepia()` filter
+/// functions from the CSS Filter Effects spec at 100% strength, applied
+/// directly to the sRGB channel values like browsers do.
+impl Color {
+    /// Inverts every channel (`255 - c`).
+    pub fn invert(&self) -> Color {
+        Color {
+            r: 255 - self.r,
+            g: 255 - self.g,
+            b: 255 - self.b,
+        }
+    }
+
+    /// Converts to gray using the BT.709 luma weights
+    /// (`0.2126 R + 0.7152 G + 0.0722 B`), not the plain channel mean.
+    pub fn grayscale(&self) -> Color {
+        let y = 0.2126 * self.r as f32 + 0.7152 * self.g as f32 + 0.0722 * self.b as f32;
+        let y = y.round().clamp(0.0, 255.0) as u8;
+        Color { r: y, g: y, b: y }
+    }
+
+    /// Applies the standard sepia tone matrix.
+    ///
+    /// ```text
+    /// R' = 0.393 R + 0.769 G + 0.189 B
+    /// G' = 0.349 R + 0.686 G + 0.168 B
+    /// B' = 0.272 R + 0.534 G + 0.131 B
+    /// ```
+    ///
+    /// Results above 255 are clamped, so white becomes `#ffffef`.
+    pub fn sepia(&self) -> Color {
+        let (r, g, b) = (self.r as f32, self.g as f32, self.b as f32);
+        let channel = |v: f32| v.round().clamp(0.0, 255.0) as u8;
+        Color {
+            r: channel(0.393 * r + 0.769 * g + 0.189 * b),
+            g: channel(0.349 * r + 0.686 * g + 0.168 * b),
+            b: channel(0.272 * r + 0.534 * g + 0.131 * b),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Writing a [`Color`] in any of the notations the parser reads.
+
+use crate::{parse_color, starts_with_function, Color, ParseError, Rgba};
+
+/// A CSS notation, for [`Color::format`] and [`parse_color_detailed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorFormat {
+    /// `#ff6600`
+    HexLower,
+    /// `#FF6600`
+    HexUpper,
+    /// `#f60` when every channel is a doubled digit, otherwise `#ff6600`.
+    HexShort,
+    /// `rgb(255, 102, 0)`
+    RgbLegacy,
+    /// `rgb(255 102 0)`
+    RgbModern,
+    /// `hsl(24, 100%, 50%)`
+    HslLegacy,
+    /// `hsl(24 100% 50%)`
+    HslModern,
+    /// The color keyword, like `orangered`, or `fallback` for colors
+    /// without one (and for every color without the `named-colors`
+    /// feature). A `Named` fallback is written as [`ColorFormat::HexLower`].
+    Named { fallback: &'static ColorFormat },
+}
+
+impl Color {
+    /// `self` written as `format`; the parser reads it back as the same
+    /// color.
+    ///
+    /// `hsl()` hue, saturation and lightness get at most two decimals,
+    /// with trailing zeros dropped (`hsl(14.45 100% 51.96%)`), which is
+    /// enough for every 8-bit color to survive the round trip.
+    ///
+    /// ```
+    /// use colourss::{Color, ColorFormat};
+    ///
+    /// let orange = Color::new(255, 102, 0);
+    /// assert_eq!(orange.format(ColorFormat::RgbModern), "rgb(255 102 0)");
+    /// assert_eq!(orange.format(ColorFormat::HslLegacy), "hsl(24, 100%, 50%)");
+    /// let named = ColorFormat::Named { fallback: &ColorFormat::HexShort };
+    /// # #[cfg(feature = "named-colors")]
+    /// assert_eq!(Color::new(255, 0, 0).format(named), "red");
+    /// assert_eq!(orange.format(named), "#f60");
+    /// ```
+    pub fn format(&self, format: ColorFormat) -> String {
+        match format {
+            ColorFormat::HexLower => self.to_hex(),
+            ColorFormat::HexUpper => self.to_hex().to_ascii_uppercase(),
+            ColorFormat::HexShort => {
+                let short = [self.r, self.g, self.b].iter().all(|c| c >> 4 == c & 0xf);
+                if short {
+                    format!("#{:x}{:x}{:x}", self.r & 0xf, self.g & 0xf, self.b & 0xf)
+                } else {
+                    self.to_hex()
+                }
+            }
+            ColorFormat::RgbLegacy => format!("rgb({}, {}, {})", self.r, self.g, self.b),
+            ColorFormat::RgbModern => format!("rgb({} {} {})", self.r, self.g, self.b),
+            ColorFormat::HslLegacy => {
+                let [h, s, l] = self.hsl_components();
+                format!("hsl({}, {}%, {}%)", h, s, l)
+            }
+            ColorFormat::HslModern => {
+                let [h, s, l] = self.hsl_components();
+                format!("hsl({} {}% {}%)", h, s, l)
+            }
+            ColorFormat::Named { fallback } => match self.keyword() {
+                Some(name) => name.to_string(),
+                None if matches!(fallback, ColorFormat::Named { .. }) => self.to_hex(),
+                None => self.format(*fallback),
+            },
+        }
+    }
+
+    /// The shortest CSS for `self`, for minifiers: a color keyword when
+    /// one is strictly shorter than any hex code (`red`, `tan`, `navy`),
+    /// else `#rgb` when every channel is a doubled digit, else `#rrggbb`.
+    ///
+    /// ```
+    /// use colourss::Color;
+    ///
+    /// assert_eq!(Color::new(255, 255, 255).to_css_shortest(), "#fff");
+    /// # #[cfg(feature = "named-colors")]
+    /// assert_eq!(Color::new(255, 0, 0).to_css_shortest(), "red");
+    /// assert_eq!(Color::new(0x12, 0x34, 0x56).to_css_shortest(), "#123456");
+    /// ```
+    pub fn to_css_shortest(&self) -> String {
+        let hex = self.format(ColorFormat::HexShort);
+        match self.shortest_keyword() {
+            Some(name) if name.len() < hex.len() => name.to_string(),
+            _ => hex,
+        }
+    }
+
+    /// The shortest CSS keyword for `self`; nonstandard extras don't
+    /// count, since the output is for browsers.
+    #[cfg(feature = "named-colors")]
+    fn shortest_keyword(&self) -> Option<&'static str> {
+        crate::named_colors()
+            .filter(|(name, color)| color == self && crate::is_standard_name(name))
+            .map(|(name, _)| name)
+            .min_by_key(|name| name.len())
+    }
+
+    #[cfg(not(feature = "named-colors"))]
+    fn shortest_keyword(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Hue in degrees and saturation and lightness in percent, each with
+    /// at most two decimals.
+    fn hsl_components(&self) -> [String; 3] {
+        let (h, s, l) = self.to_hsl();
+        [number(h), number(s * 100.0), number(l * 100.0)]
+    }
+
+    #[cfg(feature = "named-colors")]
+    fn keyword(&self) -> Option<&'static str> {
+        self.name()
+    }
+
+    #[cfg(not(feature = "named-colors"))]
+    fn keyword(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+impl Rgba {
+    /// Like [`Color::to_css_shortest`], with alpha rounded to a byte as in
+    /// [`Rgba::as_array`]: colors that come out opaque are written like a
+    /// [`Color`], the rest as `#rgba` when every channel and the alpha are
+    /// doubled digits, else `#rrggbbaa`.
+    ///
+    /// ```
+    /// use colourss::Rgba;
+    ///
+    /// assert_eq!(Rgba { r: 255, g: 255, b: 255, a: 0.6 }.to_css_shortest(), "#fff9");
+    /// assert_eq!(Rgba { r: 255, g: 0, b: 0, a: 0.5 }.to_css_shortest(), "#ff000080");
+    /// # #[cfg(feature = "named-colors")]
+    /// assert_eq!(Rgba { r: 255, g: 0, b: 0, a: 1.0 }.to_css_shortest(), "red");
+    /// ```
+    pub fn to_css_shortest(&self) -> String {
+        let channels = self.as_array();
+        if channels[3] == 255 {
+            return self.to_color().to_css_shortest();
+        }
+        if channels.iter().all(|c| c >> 4 == c & 0xf) {
+            channels.iter().fold("#".to_string(), |hex, c| format!("{}{:x}", hex, c & 0xf))
+        } else {
+            channels.iter().fold("#".to_string(), |hex, c| format!("{}{:02x}", hex, c))
+        }
+    }
+}
+
+/// `v` with at most two decimals and no trailing zeros.
+fn number(v: f32) -> String {
+    let s = format!("{:.2}", v);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" { "0".to_string() } else { s.to_string() }
+}
+
+/// Like [`parse_color`], but also says which notation `input` was written
+/// in, so it can be written back the same way with [`Color::format`].
+///
+/// Hex codes with 3 or 4 digits are [`ColorFormat::HexShort`], longer
+/// ones [`ColorFormat::HexUpper`] if their letters are all uppercase and
+/// [`ColorFormat::HexLower`] otherwise. `rgb()` and `hsl()` (and `rgba()`
+/// and `hsla()`) are legacy with commas and modern without. Names are
+/// [`ColorFormat::Named`] with a [`ColorFormat::HexLower`] fallback.
+/// Alpha is dropped, as in [`parse_color`].
+///
+/// ```
+/// use colourss::{parse_color_detailed, ColorFormat};
+///
+/// let (color, format) = parse_color_detailed("rgb(255, 102, 0)").unwrap();
+/// assert_eq!(format, ColorFormat::RgbLegacy);
+/// assert_eq!(color.format(format), "rgb(255, 102, 0)");
+/// ```
+pub fn parse_color_detailed(input: &str) -> Result<(Color, ColorFormat), ParseError> {
+    let color = parse_color(input)?;
+    let input = input.trim();
+    let function = |names: [&str; 2]| names.iter().any(|name| starts_with_function(input, name));
+    let format = if let Some(digits) = input.strip_prefix('#') {
+        if digits.len() <= 4 {
+            ColorFormat::HexShort
+        } else if digits.bytes().any(|b| b.is_ascii_uppercase()) && !digits.bytes().any(|b| b.is_ascii_lowercase()) {
+            ColorFormat::HexUpper
+        } else {
+            ColorFormat::HexLower
+        }
+    } else if function(["rgb", "rgba"]) {
+        if input.contains(',') { ColorFormat::RgbLegacy } else { ColorFormat::RgbModern }
+    } else if function(["hsl", "hsla"]) {
+        if input.contains(',') { ColorFormat::HslLegacy } else { ColorFormat::HslModern }
+    } else {
+        ColorFormat::Named { fallback: &ColorFormat::HexLower }
+    };
+    Ok((color, format))
+}
@@ -0,0 +1,139 @@
+//! GIMP palette (`.gpl`) support.
+
+use std::io;
+
+use thiserror::Error;
+
+use crate::{Color, Palette};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GplError {
+    #[error("Missing 'GIMP Palette' header")]
+    MissingHeader,
+    #[error("Line {line}: invalid color row: {content}")]
+    InvalidRow { line: usize, content: String },
+}
+
+impl Palette {
+    /// Serializes the palette in GIMP's `.gpl` format.
+    ///
+    /// Each color becomes one `R G B<TAB>name` row, with channels
+    /// right-aligned to three characters like GIMP writes them. The name
+    /// is the entry's label if it has one, then the color keyword when the
+    /// value matches one exactly ([`Color::name`]), and otherwise the hex
+    /// code. The output only depends on the palette and `name`, so it's
+    /// safe to snapshot.
+    pub fn to_gpl(&self, name: &str) -> String {
+        let mut out = String::new();
+        out.push_str("GIMP Palette\n");
+        out.push_str(&format!("Name: {}\n", name));
+        out.push_str("Columns: 16\n");
+        out.push_str("#\n");
+        for (i, color) in self.iter().enumerate() {
+            #[cfg(feature = "named-colors")]
+            let keyword = color.name();
+            #[cfg(not(feature = "named-colors"))]
+            let keyword: Option<&str> = None;
+            let label = match (self.label(i), keyword) {
+                (Some(label), _) => label.to_string(),
+                (None, Some(keyword)) => keyword.to_string(),
+                (None, None) => color.to_hex(),
+            };
+            out.push_str(&format!(
+                "{:>3} {:>3} {:>3}\t{}\n",
+                color.r, color.g, color.b, label
+            ));
+        }
+        out
+    }
+
+    /// Writes [`Palette::to_gpl`] output to `writer`.
+    pub fn write_gpl<W: io::Write>(&self, mut writer: W, name: &str) -> io::Result<()> {
+        writer.write_all(self.to_gpl(name).as_bytes())
+    }
+
+    /// Parses a GIMP `.gpl` palette.
+    ///
+    /// The `Name:` header becomes the palette name and the text after
+    /// each row's channels becomes that entry's label. Blank lines,
+    /// `#` comments and other `Key: value` headers are skipped. Columns
+    /// may be separated by any amount of spaces or tabs. The first
+    /// malformed row is returned as an error with its (1-based) line number.
+    pub fn from_gpl(input: &str) -> Result<Palette, GplError> {
+        let (palette, mut warnings) = parse_gpl(input)?;
+        if warnings.is_empty() {
+            Ok(palette)
+        } else {
+            Err(warnings.remove(0))
+        }
+    }
+
+    /// Like [`Palette::from_gpl`], but skips malformed rows.
+    ///
+    /// Returns the palette along with an error for every skipped row.
+    /// A missing header is still a hard error.
+    pub fn from_gpl_lenient(input: &str) -> Result<(Palette, Vec<GplError>), GplError> {
+        parse_gpl(input)
+    }
+}
+
+fn parse_gpl(input: &str) -> Result<(Palette, Vec<GplError>), GplError> {
+    let mut lines = input.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == "GIMP Palette" => {}
+        _ => return Err(GplError::MissingHeader),
+    }
+
+    let mut palette = Palette::new();
+    let mut warnings = Vec::new();
+    let mut in_header = true;
+
+    for (i, line) in lines {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if in_header {
+            if let Some(name) = trimmed.strip_prefix("Name:") {
+                palette.set_name(name.trim());
+                continue;
+            }
+            if trimmed.starts_with("Columns:") {
+                continue;
+            }
+        }
+        in_header = false;
+
+        match parse_row(trimmed) {
+            Some((color, label)) => match label {
+                Some(label) => palette.push_labeled(color, label),
+                None => palette.push(color),
+            },
+            None => warnings.push(GplError::InvalidRow {
+                line: i + 1,
+                content: trimmed.to_string(),
+            }),
+        }
+    }
+
+    Ok((palette, warnings))
+}
+
+/// Parses `R G B [label...]`.
+fn parse_row(row: &str) -> Option<(Color, Option<&str>)> {
+    let mut rest = row;
+    let mut channels = [0u8; 3];
+    for channel in channels.iter_mut() {
+        rest = rest.trim_start();
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        *channel = rest[..end].parse().ok()?;
+        rest = &rest[end..];
+    }
+    let label = rest.trim();
+    let color = Color {
+        r: channels[0],
+        g: channels[1],
+        b: channels[2],
+    };
+    Some((color, (!label.is_empty()).then_some(label)))
+}
@@ -0,0 +1,160 @@
+use crate::{Color, InterpSpace};
+
+/// A multi-stop color gradient.
+///
+/// Stops are `(position, color)` pairs where position `0.0` is the start
+/// and `1.0` the end. Positions are fixed up the way CSS does it: a stop
+/// positioned before an earlier stop is moved forward to that stop's
+/// position (giving a hard edge), and any stops without a position are
+/// spread evenly between their positioned neighbours.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    stops: Vec<(f32, Color)>,
+    space: InterpSpace,
+}
+
+impl Gradient {
+    /// Creates a gradient from positioned stops.
+    ///
+    /// Positions that are NaN or infinite count as missing, as in
+    /// [`Gradient::with_positions`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stops` is empty.
+    pub fn new(stops: Vec<(f32, Color)>) -> Gradient {
+        Gradient::with_positions(stops.into_iter().map(|(p, c)| (Some(p), c)).collect())
+    }
+
+    /// Creates a gradient with the colors spread evenly from `0.0` to `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    pub fn from_colors(colors: Vec<Color>) -> Gradient {
+        Gradient::with_positions(colors.into_iter().map(|c| (None, c)).collect())
+    }
+
+    /// Creates a gradient where some stops may be missing a position.
+    ///
+    /// A missing first position defaults to `0.0` and a missing last
+    /// position to `1.0`; the rest are distributed evenly. A position
+    /// that is NaN or infinite is treated as missing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stops` is empty.
+    pub fn with_positions(stops: Vec<(Option<f32>, Color)>) -> Gradient {
+        assert!(!stops.is_empty(), "a gradient needs at least one stop");
+
+        let mut positions: Vec<Option<f32>> = stops.iter().map(|(p, _)| p.filter(|p| p.is_finite())).collect();
+        let last = positions.len() - 1;
+        if positions[0].is_none() {
+            positions[0] = Some(0.0);
+        }
+        if positions[last].is_none() {
+            positions[last] = Some(if last == 0 { 0.0 } else { 1.0 });
+        }
+
+        // a position smaller than any before it is clamped up to the max so far
+        let mut max = f32::NEG_INFINITY;
+        for pos in positions.iter_mut().flatten() {
+            if *pos < max {
+                *pos = max;
+            }
+            max = *pos;
+        }
+
+        // spread runs of unpositioned stops evenly between their neighbours
+        let mut i = 0;
+        while i < positions.len() {
+            if positions[i].is_some() {
+                i += 1;
+                continue;
+            }
+            let start = i - 1;
+            let mut end = i;
+            while positions[end].is_none() {
+                end += 1;
+            }
+            let (from, to) = (positions[start].unwrap(), positions[end].unwrap());
+            let span = (end - start) as f32;
+            for (k, pos) in positions[start + 1..end].iter_mut().enumerate() {
+                *pos = Some(from + (to - from) * (k + 1) as f32 / span);
+            }
+            i = end;
+        }
+
+        Gradient {
+            stops: positions
+                .into_iter()
+                .zip(stops)
+                .map(|(p, (_, c))| (p.unwrap(), c))
+                .collect(),
+            space: InterpSpace::Srgb,
+        }
+    }
+
+    /// Makes the gradient interpolate in linear light instead of sRGB.
+    ///
+    /// See [`Color::mix_linear`].
+    pub fn in_linear_light(self) -> Gradient {
+        self.in_space(InterpSpace::LinearRgb)
+    }
+
+    /// Makes the gradient interpolate in `space` instead of sRGB, as
+    /// [`Color::mix_in`] does; for CSS `linear-gradient(in oklch, ...)`.
+    pub fn in_space(mut self, space: InterpSpace) -> Gradient {
+        self.space = space;
+        self
+    }
+
+    /// The stops after position fix-up, in order.
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// The color at position `t`.
+    ///
+    /// `t` is clamped to `0.0..=1.0`. Before the first stop the first
+    /// color is used, after the last stop the last color. When two stops
+    /// share a position the later one wins from that point on.
+    pub fn sample(&self, t: f32) -> Color {
+        self.sample_with(t, |a, b, t| a.mix_in(self.space, b, t))
+    }
+
+    /// Like [`Gradient::sample`], but mixes the two stops around `t` with
+    /// `mix` instead, e.g. [`Color::mix_oklab`]. `mix` gets the position
+    /// within the segment, from `0.0` to `1.0`.
+    pub fn sample_with(&self, t: f32, mix: impl Fn(&Color, &Color, f32) -> Color) -> Color {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+
+        let (first_pos, first) = self.stops[0];
+        if t <= first_pos {
+            return first;
+        }
+        let (last_pos, last) = self.stops[self.stops.len() - 1];
+        if t >= last_pos {
+            return last;
+        }
+
+        // the first stop strictly after t; there is one since t < last_pos,
+        // but fall back to the last rather than panic
+        let next = self.stops.iter().position(|(p, _)| *p > t).unwrap_or(self.stops.len() - 1);
+        let (p0, c0) = self.stops[next - 1];
+        let (p1, c1) = self.stops[next];
+        let local = (t - p0) / (p1 - p0);
+        mix(&c0, &c1, local)
+    }
+
+    /// `n` evenly spaced samples from `0.0` to `1.0` inclusive.
+    pub fn steps(&self, n: usize) -> Vec<Color> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self.sample(0.0)],
+            _ => (0..n)
+                .map(|i| self.sample(i as f32 / (n - 1) as f32))
+                .collect(),
+        }
+    }
+}
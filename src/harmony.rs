@@ -0,0 +1,50 @@
+use crate::Color;
+
+/// Color harmonies for quick palette suggestions.
+///
+/// All of these are hue rotations in HSL that keep saturation and
+/// lightness, built on [`Color::rotate_hue`]. Multi-color results always
+/// start with the original color. Grays have no hue, so every entry is a
+/// copy of the gray.
+impl Color {
+    /// The color on the opposite side of the hue wheel (+180°).
+    pub fn complementary(&self) -> Color {
+        self.rotate_hue(180.0)
+    }
+
+    /// The original color plus the two colors 120° and 240° away.
+    pub fn triadic(&self) -> [Color; 3] {
+        [*self, self.rotate_hue(120.0), self.rotate_hue(240.0)]
+    }
+
+    /// The original color plus the two neighbours of its complement (+150° and +210°).
+    pub fn split_complementary(&self) -> [Color; 3] {
+        [*self, self.rotate_hue(150.0), self.rotate_hue(210.0)]
+    }
+
+    /// Four colors evenly spaced around the wheel (0°, 90°, 180°, 270°).
+    pub fn tetradic(&self) -> [Color; 4] {
+        [
+            *self,
+            self.rotate_hue(90.0),
+            self.rotate_hue(180.0),
+            self.rotate_hue(270.0),
+        ]
+    }
+
+    /// `count` colors, each `step_degrees` further around the wheel than the last.
+    ///
+    /// The first entry is the original color. Use a negative step to walk
+    /// the wheel the other way. `count == 0` gives an empty `Vec`.
+    pub fn analogous(&self, count: usize, step_degrees: f32) -> Vec<Color> {
+        (0..count)
+            .map(|i| {
+                if i == 0 {
+                    *self
+                } else {
+                    self.rotate_hue(step_degrees * i as f32)
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,86 @@
+//! Float RGB <-> HSL conversion used by the manipulation methods.
+
+use crate::{hue_to_rgb, Color};
+
+/// Converts a color to `(hue, saturation, lightness)`.
+///
+/// Hue is in degrees `0.0..360.0`, saturation and lightness in `0.0..=1.0`.
+/// Grays get a hue of `0.0` and a saturation of `0.0`.
+pub(crate) fn rgb_to_hsl(color: &Color) -> (f32, f32, f32) {
+    rgb_f32_to_hsl(color.r as f32 / 255.0, color.g as f32 / 255.0, color.b as f32 / 255.0)
+}
+
+/// Like [`rgb_to_hsl`], for channels already normalized to `0.0..=1.0`.
+pub(crate) fn rgb_f32_to_hsl(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+
+    if max == min {
+        // it's grayscale
+        return (0.0, 0.0, l);
+    }
+
+    let d = max - min;
+    let s = if l > 0.5 {
+        d / (2.0 - max - min)
+    } else {
+        d / (max + min)
+    };
+    let h = if max == r {
+        (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+
+    (h * 60.0, s, l)
+}
+
+/// Converts `(hue, saturation, lightness)` back to a color, rounding each channel.
+///
+/// Hue is in degrees and wraps around; saturation and lightness are clamped
+/// to `0.0..=1.0`.
+pub(crate) fn hsl_to_rgb(h: f32, s: f32, l: f32) -> Color {
+    let (r, g, b) = hsl_to_rgb_f32(h, s, l);
+    let byte = |c: f32| (c * 255.0).round() as u8;
+    Color {
+        r: byte(r),
+        g: byte(g),
+        b: byte(b),
+    }
+}
+
+/// Like [`hsl_to_rgb`], without rounding: channels come back in `0.0..=1.0`.
+pub(crate) fn hsl_to_rgb_f32(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    let h = h.rem_euclid(360.0) / 360.0;
+    let s = s.clamp(0.0, 1.0);
+    let l = l.clamp(0.0, 1.0);
+
+    if s == 0.0 {
+        return (l, l, l);
+    }
+
+    let q = if l < 0.5 { l * (1.0 + s) } else { l + s - l * s };
+    let p = 2.0 * l - q;
+    (
+        hue_to_rgb(p, q, h + 1.0 / 3.0),
+        hue_to_rgb(p, q, h),
+        hue_to_rgb(p, q, h - 1.0 / 3.0),
+    )
+}
+
+impl Color {
+    /// `(hue, saturation, lightness)`: hue in degrees `0.0..360.0`,
+    /// saturation and lightness in `0.0..=1.0`.
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        rgb_to_hsl(self)
+    }
+
+    /// Builds a color from HSL, rounding each channel. Hue wraps around;
+    /// saturation and lightness are clamped to `0.0..=1.0`.
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Color {
+        hsl_to_rgb(h, s, l)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::linear::decode;
+use crate::xyz::linear_to_xyz;
+use crate::{css_number, snippet, Color, ParseError};
+
+/// Lowest temperature `from_kelvin` models; lower values are clamped.
+const MIN_KELVIN: f32 = 1000.0;
+/// Highest temperature `from_kelvin` models; higher values are clamped.
+const MAX_KELVIN: f32 = 40000.0;
+
+/// Tanner Helland's fit of blackbody color, as unrounded `0.0..=255.0` channels.
+fn kelvin_channels(kelvin: f32) -> (f32, f32, f32) {
+    let t = kelvin.clamp(MIN_KELVIN, MAX_KELVIN) / 100.0;
+
+    let r = if t <= 66.0 {
+        255.0
+    } else {
+        329.69873 * (t - 60.0).powf(-0.13320476)
+    };
+    let g = if t <= 66.0 {
+        99.4708 * t.ln() - 161.11957
+    } else {
+        288.12216 * (t - 60.0).powf(-0.075514846)
+    };
+    let b = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.51773 * (t - 10.0).ln() - 305.0448
+    };
+
+    (r.clamp(0.0, 255.0), g.clamp(0.0, 255.0), b.clamp(0.0, 255.0))
+}
+
+/// CIE xy chromaticity of `0.0..=255.0` sRGB channels, `None` for black.
+fn chromaticity(r: f32, g: f32, b: f32) -> Option<(f32, f32)> {
+    let (x, y, z) = linear_to_xyz(decode(r / 255.0), decode(g / 255.0), decode(b / 255.0));
+    let sum = x + y + z;
+    if sum <= 0.0 {
+        return None;
+    }
+    Some((x / sum, y / sum))
+}
+
+/// Color temperature.
+impl Color {
+    /// Approximate color of a blackbody radiator at `kelvin`.
+    ///
+    /// Uses Tanner Helland's curve fit, which is good to within a few
+    /// units per channel between 1000K and 40000K. Temperatures outside
+    /// that range are clamped to it. 6600K comes out as pure white.
+    pub fn from_kelvin(kelvin: f32) -> Color {
+        let (r, g, b) = kelvin_channels(kelvin);
+        Color {
+            r: r.round() as u8,
+            g: g.round() as u8,
+            b: b.round() as u8,
+        }
+    }
+
+    /// Best-effort correlated color temperature of this color.
+    ///
+    /// McCamy's cubic approximation gives a first guess from the CIE xy
+    /// chromaticity. That guess is then refined against the
+    /// [`Color::from_kelvin`] curve, since McCamy on its own drifts badly
+    /// outside roughly 2000K–10000K. Returns `None` for black and for
+    /// colors too far from the blackbody curve to have a meaningful
+    /// temperature (greens, purples, ...).
+    pub fn estimate_kelvin(&self) -> Option<f32> {
+        let (x, y) = chromaticity(self.r as f32, self.g as f32, self.b as f32)?;
+
+        // McCamy (1992)
+        let n = (x - 0.3320) / (0.1858 - y);
+        let guess = 449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33;
+        let guess = if guess.is_finite() {
+            guess.clamp(MIN_KELVIN, MAX_KELVIN)
+        } else {
+            6500.0
+        };
+
+        let distance = |ln_k: f32| {
+            let (r, g, b) = kelvin_channels(ln_k.exp());
+            match chromaticity(r, g, b) {
+                Some((cx, cy)) => ((cx - x).powi(2) + (cy - y).powi(2)).sqrt(),
+                None => f32::INFINITY,
+            }
+        };
+
+        // golden-section search in log space around the guess
+        let mut lo = (guess / 2.5).max(MIN_KELVIN).ln();
+        let mut hi = (guess * 2.5).min(MAX_KELVIN).ln();
+        let ratio = (5f32.sqrt() - 1.0) / 2.0;
+        for _ in 0..40 {
+            let c1 = hi - ratio * (hi - lo);
+            let c2 = lo + ratio * (hi - lo);
+            if distance(c1) < distance(c2) {
+                hi = c2;
+            } else {
+                lo = c1;
+            }
+        }
+        let best = (lo + hi) / 2.0;
+
+        if distance(best) > 0.02 {
+            return None;
+        }
+        Some(best.exp())
+    }
+}
+
+/// Parses a color temperature like `2700K`, `4000 K` or `6500k` into
+/// [`Color::from_kelvin`]'s approximation of it.
+///
+/// The number is a CSS number (`6.5e3K` works too) and may be followed
+/// by whitespace before the `K`. Temperatures below 1000K or above
+/// 40000K are clamped to that range, like `from_kelvin` does, so `500K`
+/// is the same color as `1000K`; negative ones, which aren't
+/// temperatures, are an [`InvalidTemperature`](ParseError::InvalidTemperature)
+/// error, as is anything without both a number and a `K`.
+///
+/// ```
+/// use colourss::{parse_temperature, Color};
+///
+/// assert_eq!(parse_temperature("6600K").unwrap(), Color::new(255, 255, 255));
+/// assert_eq!(parse_temperature("2700 k").unwrap(), Color::from_kelvin(2700.0));
+/// assert!(parse_temperature("K").is_err());
+/// ```
+pub fn parse_temperature(input: &str) -> Result<Color, ParseError> {
+    let input = input.trim();
+    let invalid = || ParseError::InvalidTemperature(snippet(input));
+    let number = input.strip_suffix(['K', 'k']).ok_or_else(invalid)?.trim_end();
+    match css_number(number) {
+        Some(kelvin) if kelvin >= 0.0 => Ok(Color::from_kelvin(kelvin)),
+        _ => Err(invalid()),
+    }
+}
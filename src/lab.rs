@@ -0,0 +1,134 @@
+use crate::xyz::WhitePoint;
+use crate::Color;
+
+/// A color in CIE L\*a\*b\* (D65 white point).
+///
+/// `l` is lightness in `0.0..=100.0`; `a` (green–red) and `b`
+/// (blue–yellow) are unbounded but stay within roughly ±128 for sRGB colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Lab {
+    /// CIE76 color difference: plain Euclidean distance in Lab.
+    pub fn delta_e76(&self, other: &Lab) -> f32 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        (dl * dl + da * da + db * db).sqrt()
+    }
+
+    /// CIEDE2000 color difference.
+    ///
+    /// Follows Sharma, Wu & Dalal, "The CIEDE2000 Color-Difference
+    /// Formula: Implementation Notes, Supplementary Test Data, and
+    /// Mathematical Observations" (2005), with `kL = kC = kH = 1`.
+    /// Computed in `f64` internally.
+    pub fn delta_e2000(&self, other: &Lab) -> f32 {
+        let (l1, a1, b1) = (self.l as f64, self.a as f64, self.b as f64);
+        let (l2, a2, b2) = (other.l as f64, other.a as f64, other.b as f64);
+        let pow25_7 = 25f64.powi(7);
+
+        // step 1: a' with the G factor, C' and h'
+        let c_bar = ((a1 * a1 + b1 * b1).sqrt() + (a2 * a2 + b2 * b2).sqrt()) / 2.0;
+        let c_bar7 = c_bar.powi(7);
+        let g = 0.5 * (1.0 - (c_bar7 / (c_bar7 + pow25_7)).sqrt());
+        let a1p = (1.0 + g) * a1;
+        let a2p = (1.0 + g) * a2;
+        let c1p = (a1p * a1p + b1 * b1).sqrt();
+        let c2p = (a2p * a2p + b2 * b2).sqrt();
+        let hue = |b: f64, ap: f64| {
+            if b == 0.0 && ap == 0.0 {
+                0.0
+            } else {
+                b.atan2(ap).to_degrees().rem_euclid(360.0)
+            }
+        };
+        let h1p = hue(b1, a1p);
+        let h2p = hue(b2, a2p);
+
+        // step 2: differences
+        let dlp = l2 - l1;
+        let dcp = c2p - c1p;
+        let chroma_zero = c1p * c2p == 0.0;
+        let dhp = if chroma_zero {
+            0.0
+        } else if (h2p - h1p).abs() <= 180.0 {
+            h2p - h1p
+        } else if h2p - h1p > 180.0 {
+            h2p - h1p - 360.0
+        } else {
+            h2p - h1p + 360.0
+        };
+        let d_hp = 2.0 * (c1p * c2p).sqrt() * (dhp / 2.0).to_radians().sin();
+
+        // step 3: weighting functions
+        let l_bar = (l1 + l2) / 2.0;
+        let c_bar_p = (c1p + c2p) / 2.0;
+        let h_bar_p = if chroma_zero {
+            h1p + h2p
+        } else if (h1p - h2p).abs() <= 180.0 {
+            (h1p + h2p) / 2.0
+        } else if h1p + h2p < 360.0 {
+            (h1p + h2p + 360.0) / 2.0
+        } else {
+            (h1p + h2p - 360.0) / 2.0
+        };
+        let t = 1.0 - 0.17 * (h_bar_p - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_bar_p).to_radians().cos()
+            + 0.32 * (3.0 * h_bar_p + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_bar_p - 63.0).to_radians().cos();
+        let d_theta = 30.0 * (-((h_bar_p - 275.0) / 25.0).powi(2)).exp();
+        let c_bar_p7 = c_bar_p.powi(7);
+        let r_c = 2.0 * (c_bar_p7 / (c_bar_p7 + pow25_7)).sqrt();
+        let l50 = (l_bar - 50.0).powi(2);
+        let s_l = 1.0 + 0.015 * l50 / (20.0 + l50).sqrt();
+        let s_c = 1.0 + 0.045 * c_bar_p;
+        let s_h = 1.0 + 0.015 * c_bar_p * t;
+        let r_t = -(2.0 * d_theta).to_radians().sin() * r_c;
+
+        let dl = dlp / s_l;
+        let dc = dcp / s_c;
+        let dh = d_hp / s_h;
+        (dl * dl + dc * dc + dh * dh + r_t * dc * dh).sqrt() as f32
+    }
+}
+
+impl Color {
+    /// Converts to CIE L\*a\*b\* via linear sRGB and XYZ (D65).
+    pub fn to_lab(&self) -> Lab {
+        let xyz = self.to_xyz(WhitePoint::D65);
+        let white = WhitePoint::D65.xyz();
+
+        let f = |t: f32| {
+            const DELTA: f32 = 6.0 / 29.0;
+            if t > DELTA * DELTA * DELTA {
+                t.cbrt()
+            } else {
+                t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+            }
+        };
+        let fx = f(xyz.x / white.x);
+        let fy = f(xyz.y / white.y);
+        let fz = f(xyz.z / white.z);
+
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// CIE76 difference in Lab. Around 2.3 is a just-noticeable difference.
+    pub fn delta_e76(&self, other: &Color) -> f32 {
+        self.to_lab().delta_e76(&other.to_lab())
+    }
+
+    /// CIEDE2000 difference in Lab. Below 1.0 is imperceptible.
+    pub fn delta_e2000(&self, other: &Color) -> f32 {
+        self.to_lab().delta_e2000(&other.to_lab())
+    }
+}
@@ -0,0 +1,80 @@
+//! The HTML "rules for parsing a legacy colour value", for attributes
+//! like `<font color>` and `<body bgcolor>`.
+
+use crate::{parse_hex, snippet, Color, ParseError};
+
+/// Parses `input` the way browsers read legacy HTML color attributes,
+/// following the WHATWG algorithm: anything that isn't empty or
+/// `transparent` becomes some color.
+///
+/// CSS color keywords and `#rgb` come out as usual. Everything else is
+/// read as hex: a leading `#` is dropped, characters outside the Basic
+/// Multilingual Plane count as `00`, input is cut to 128 characters,
+/// anything that isn't a hex digit is `0`, and the digits are padded with
+/// `0` to a multiple of three and split into thirds for red, green and
+/// blue. Each third keeps its last 8 digits, then loses leading zeros
+/// shared by all three while longer than 2, and is cut to 2 digits.
+///
+/// The empty string is an [`InvalidHexFormat`](ParseError::InvalidHexFormat)
+/// error and `transparent` an [`UnknownColorName`](ParseError::UnknownColorName).
+///
+/// ```
+/// use colourss::parse_color_legacy_html;
+///
+/// assert_eq!(parse_color_legacy_html("chucknorris").unwrap().to_hex(), "#c00000");
+/// assert_eq!(parse_color_legacy_html("12345z").unwrap().to_hex(), "#123450");
+/// assert!(parse_color_legacy_html("transparent").is_err());
+/// ```
+pub fn parse_color_legacy_html(input: &str) -> Result<Color, ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::InvalidHexFormat(String::new()));
+    }
+    let input = input.trim_matches(|c: char| c.is_ascii_whitespace());
+    if input.eq_ignore_ascii_case("transparent") {
+        return Err(ParseError::UnknownColorName(snippet(input)));
+    }
+    #[cfg(feature = "named-colors")]
+    if input.is_ascii() && crate::is_standard_name(input) {
+        return crate::parse_named(input);
+    }
+    if input.len() == 4
+        && input.starts_with('#')
+        && input[1..].bytes().all(|b| b.is_ascii_hexdigit())
+    {
+        return parse_hex(input).map(|(color, _)| color);
+    }
+
+    let mut chars: Vec<char> = Vec::new();
+    for c in input.chars() {
+        if c as u32 > 0xffff {
+            chars.extend(['0', '0']);
+        } else {
+            chars.push(c);
+        }
+    }
+    chars.truncate(128);
+    if chars.first() == Some(&'#') {
+        chars.remove(0);
+    }
+    // only ASCII hex digits survive, so a byte each is enough
+    let mut digits: Vec<u8> = chars.iter().map(|&c| if c.is_ascii_hexdigit() { c as u8 } else { b'0' }).collect();
+    while digits.is_empty() || !digits.len().is_multiple_of(3) {
+        digits.push(b'0');
+    }
+
+    let mut length = digits.len() / 3;
+    let mut parts: Vec<&[u8]> = digits.chunks(length).collect();
+    if length > 8 {
+        parts = parts.iter().map(|part| &part[length - 8..]).collect();
+        length = 8;
+    }
+    while length > 2 && parts.iter().all(|part| part[0] == b'0') {
+        parts = parts.iter().map(|part| &part[1..]).collect();
+        length -= 1;
+    }
+    let channel = |part: &[u8]| {
+        let hex = std::str::from_utf8(&part[..length.min(2)]).unwrap_or("0");
+        u8::from_str_radix(hex, 16).unwrap_or(0)
+    };
+    Ok(Color::new(channel(parts[0]), channel(parts[1]), channel(parts[2])))
+}
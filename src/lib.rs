@@ -1,28 +1,444 @@
+use std::fmt;
+use std::str::FromStr;
 use thiserror::Error;
 
+mod adjust;
+mod ansi;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+mod blend;
+mod color16;
+mod colorf;
+mod cvd;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod filter;
+mod format;
+mod gpl;
+mod gradient;
+mod harmony;
+mod hsl;
+mod kelvin;
+mod lab;
+mod legacy_html;
+mod linear;
+mod list;
+mod luminance;
+#[cfg(feature = "material-names")]
+mod material;
+mod mix;
+#[cfg(feature = "named-colors")]
+pub mod named;
+mod oklab;
+mod options;
+mod order;
+mod palette;
+#[cfg(feature = "python")]
+mod python;
+mod quantize;
+mod ramp;
+#[cfg(feature = "rand")]
+mod random;
+mod rgba;
+mod scan;
+#[cfg(feature = "serde")]
+pub mod serde_hex;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "tailwind-names")]
+mod tailwind;
+#[cfg(feature = "themes")]
+pub mod themes;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "x11-names")]
+mod x11;
+mod xyz;
+
+pub use blend::BlendMode;
+pub use color16::Color16;
+pub use colorf::ColorF;
+pub use cvd::Cvd;
+pub use format::{parse_color_detailed, ColorFormat};
+pub use gpl::GplError;
+pub use gradient::Gradient;
+pub use kelvin::parse_temperature;
+pub use lab::Lab;
+pub use legacy_html::parse_color_legacy_html;
+pub use linear::LinearColor;
+pub use list::{parse_color_list, parse_color_list_iter};
+#[cfg(feature = "material-names")]
+pub use material::material_colors;
+pub use mix::{average, average_srgb, HuePath, InterpSpace};
+#[cfg(feature = "named-colors")]
+pub use named::{is_named_color, is_standard_name, named_colors, NAMED_COLORS};
+#[cfg(feature = "named-colors")]
+pub(crate) use named::parse_named;
+pub use oklab::Oklab;
+pub use options::{NameSet, ParseOptions};
+pub use palette::Palette;
+pub use quantize::{dominant_colors, quantize};
+pub use rgba::Rgba;
+pub use scan::{find_colors, ColorMatch};
+#[cfg(feature = "tailwind-names")]
+pub use tailwind::tailwind_colors;
+#[cfg(feature = "x11-names")]
+pub use x11::x11_colors;
+#[cfg(feature = "x11-names")]
+use x11::parse_x11;
+pub use xyz::{WhitePoint, Xyz};
+
+/// Why an input isn't a color. Every variant carries the input (cut to
+/// 64 characters), which `Display` quotes and escapes like `{:?}` does,
+/// so messages stay readable and one line long whatever was passed in.
 #[derive(Error, Debug)]
 pub enum ParseError {
-    #[error("Invalid hex code format")]
-    InvalidHexFormat,
-    #[error("Invalid RGB/RGBA format")]
-    InvalidRgbFormat,
-    #[error("Invalid HSL/HSLA format")]
-    InvalidHslFormat,
-    #[error("Invalid component value: {0}")]
-    InvalidComponentValue(String),
-    #[error("Unknown color name: {0}")]
+    #[error("Invalid hex code format in {0:?}")]
+    InvalidHexFormat(String),
+    #[error("Invalid RGB/RGBA format in {0:?}")]
+    InvalidRgbFormat(String),
+    #[error("Invalid HSL/HSLA format in {0:?}")]
+    InvalidHslFormat(String),
+    /// `component` is the part that's wrong, like `S: 120`.
+    #[error("Invalid component value: {} in {input:?}", .component.escape_debug())]
+    InvalidComponentValue { component: String, input: String },
+    #[error("Unknown color name: {0:?}")]
     UnknownColorName(String),
-    #[error("Failed to parse number")]
-    ParseFailure,
+    /// `what` says what was being parsed, like `"alpha"`.
+    #[error("Failed to parse {what} in {input:?}")]
+    ParseFailure { what: &'static str, input: String },
+    /// The input as far as it could be decoded, with `�` for bad bytes.
+    #[error("Input is not valid UTF-8: {0:?}")]
+    InvalidUtf8(String),
+    /// Nothing between two commas, or after the last one, as in
+    /// `rgb(255,,0)`; `position` counts components from 0.
+    #[error("Empty component at position {position} in {input:?}")]
+    EmptyComponent { position: usize, input: String },
+    /// From [`parse_color_list`]: item `index` (from 0) didn't parse.
+    #[error("Invalid list item {index}: {error}")]
+    ListItem {
+        index: usize,
+        #[source]
+        error: Box<ParseError>,
+    },
+    /// `inherit`, `initial`, `unset`, `revert` or `revert-layer`, which
+    /// are valid CSS wherever a color is but aren't one. Carries the
+    /// keyword in lowercase rather than the input.
+    #[error("{0:?} is a CSS-wide keyword, not a color")]
+    CssWideKeyword(&'static str),
+    /// From [`parse_temperature`]: not a number followed by `K`.
+    #[error("Invalid color temperature in {0:?}")]
+    InvalidTemperature(String),
+}
+
+/// The keywords every CSS property takes, in place of a value.
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["inherit", "initial", "unset", "revert", "revert-layer"];
+
+/// The CSS-wide keyword `input` is, ignoring ASCII case.
+fn css_wide_keyword(input: &str) -> Option<&'static str> {
+    CSS_WIDE_KEYWORDS.into_iter().find(|keyword| input.eq_ignore_ascii_case(keyword))
+}
+
+/// What sort of problem a [`ParseError`] is, for callers that treat
+/// them differently (say, unknown names as warnings and everything else
+/// as failures) without matching on variants, which grow with the
+/// grammar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Not the shape of any color: bad hex digits, the wrong number of
+    /// components, mismatched separators.
+    Syntax,
+    /// The right shape, but a component that isn't an allowed value,
+    /// like `rgb(300 0 0)` or a strict-mode `hsl()` without `%`.
+    OutOfRange,
+    /// A word that isn't a color keyword.
+    UnknownName,
+    /// Input the parser can't read at all, like bytes that aren't UTF-8,
+    /// or that is valid CSS but not a color, like `inherit`.
+    Unsupported,
+    /// A bug in the parser rather than in the input.
+    Internal,
+}
+
+impl ParseError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ParseError::InvalidHexFormat(_)
+            | ParseError::InvalidRgbFormat(_)
+            | ParseError::InvalidHslFormat(_)
+            | ParseError::EmptyComponent { .. }
+            | ParseError::InvalidTemperature(_) => ErrorKind::Syntax,
+            ParseError::InvalidComponentValue { .. } => ErrorKind::OutOfRange,
+            ParseError::UnknownColorName(_) => ErrorKind::UnknownName,
+            ParseError::InvalidUtf8(_) | ParseError::CssWideKeyword(_) => ErrorKind::Unsupported,
+            ParseError::ParseFailure { .. } => ErrorKind::Internal,
+            ParseError::ListItem { error, .. } => error.kind(),
+        }
+    }
+
+    pub fn is_syntax(&self) -> bool {
+        self.kind() == ErrorKind::Syntax
+    }
+
+    pub fn is_out_of_range(&self) -> bool {
+        self.kind() == ErrorKind::OutOfRange
+    }
+
+    pub fn is_unknown_name(&self) -> bool {
+        self.kind() == ErrorKind::UnknownName
+    }
+}
+
+/// `input` cut to its first 64 characters, for an error.
+pub(crate) fn snippet(input: &str) -> String {
+    match input.char_indices().nth(64) {
+        Some((end, _)) => format!("{}...", &input[..end]),
+        None => input.to_string(),
+    }
 }
 
-#[derive(Debug, PartialEq)]
+fn invalid_component(component: &str, input: &str) -> ParseError {
+    ParseError::InvalidComponentValue { component: snippet(component), input: snippet(input) }
+}
+
+/// An sRGB color with 8 bits per channel.
+///
+/// Colors compare (and sort, as in a `BTreeSet`) by `r`, then `g`, then
+/// `b`; for other orders see [`Color::sort_key_hue`] and
+/// [`Color::sort_key_luminance`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
     pub b: u8,
 }
 
+impl Color {
+    /// A color from its channels, also in `const` items:
+    ///
+    /// ```
+    /// use colourss::{parse_color, Color};
+    ///
+    /// const BRAND: Color = Color::new(0x12, 0x34, 0x56);
+    /// assert_eq!(BRAND, parse_color("#123456").unwrap());
+    /// ```
+    pub const fn new(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    /// Same as [`Color::new`].
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color::new(r, g, b)
+    }
+
+    /// An [`Rgba`] from its channels and alpha.
+    pub const fn rgba(r: u8, g: u8, b: u8, a: f32) -> Rgba {
+        Rgba { r, g, b, a }
+    }
+
+    /// Formats the color as lowercase `#rrggbb`.
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// The channels as `[r, g, b]`, the layout of an RGB8 pixel buffer.
+    pub fn as_array(&self) -> [u8; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    /// The channels as `(r, g, b)`.
+    pub fn to_tuple(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+
+    /// The channels in `r`, `g`, `b` order.
+    pub fn channels(&self) -> impl Iterator<Item = u8> {
+        self.as_array().into_iter()
+    }
+
+    /// Applies `f` to each channel.
+    ///
+    /// ```
+    /// use colourss::Color;
+    ///
+    /// let gray = Color::new(100, 100, 100);
+    /// assert_eq!(gray.map_channels(|c| c.saturating_add(50)), Color::new(150, 150, 150));
+    /// ```
+    pub fn map_channels(&self, f: impl Fn(u8) -> u8) -> Color {
+        Color { r: f(self.r), g: f(self.g), b: f(self.b) }
+    }
+
+    /// The same color with red set to `r`.
+    pub fn with_r(&self, r: u8) -> Color {
+        Color { r, ..*self }
+    }
+
+    /// The same color with green set to `g`.
+    pub fn with_g(&self, g: u8) -> Color {
+        Color { g, ..*self }
+    }
+
+    /// The same color with blue set to `b`.
+    pub fn with_b(&self, b: u8) -> Color {
+        Color { b, ..*self }
+    }
+
+    /// This color with alpha `a`.
+    pub fn with_a(&self, a: f32) -> Rgba {
+        Rgba { r: self.r, g: self.g, b: self.b, a }
+    }
+
+    /// A color with all three channels replaced; the same as
+    /// `Color { r, g, b }`, for chains of `with_*` calls.
+    pub fn with_channels(&self, r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    /// Adds `delta` to red, stopping at 0 and 255 instead of wrapping.
+    ///
+    /// ```
+    /// use colourss::Color;
+    ///
+    /// let c = Color::new(250, 10, 0);
+    /// assert_eq!(c.add_r(20).r, 255);
+    /// assert_eq!(c.add_g(-20).g, 0);
+    /// ```
+    pub fn add_r(&self, delta: i16) -> Color {
+        Color { r: add_channel(self.r, delta), ..*self }
+    }
+
+    /// Adds `delta` to green, clamped like [`Color::add_r`].
+    pub fn add_g(&self, delta: i16) -> Color {
+        Color { g: add_channel(self.g, delta), ..*self }
+    }
+
+    /// Adds `delta` to blue, clamped like [`Color::add_r`].
+    pub fn add_b(&self, delta: i16) -> Color {
+        Color { b: add_channel(self.b, delta), ..*self }
+    }
+}
+
+/// `rrggbb` in lowercase, or `#rrggbb` with `{:#x}`.
+///
+/// Width, fill and alignment pad the whole text, right-aligned unless
+/// asked otherwise: `format!("{:>9x}", color)` is `"   ff8000"`. The `0`
+/// flag pads with spaces too, since leading zeros would read as more
+/// digits, and precision is ignored.
+///
+/// ```
+/// use colourss::Color;
+///
+/// let orange = Color::new(255, 128, 0);
+/// assert_eq!(format!("{:x}", orange), "ff8000");
+/// assert_eq!(format!("{:#X}", orange), "#FF8000");
+/// assert_eq!(format!("{:<8x}|", orange), "ff8000  |");
+/// ```
+impl fmt::LowerHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hex = format!("{:02x}{:02x}{:02x}", self.r, self.g, self.b);
+        pad_hex(f, &hex)
+    }
+}
+
+/// `RRGGBB` in uppercase, or `#RRGGBB` with `{:#X}`. Flags work as for
+/// [`LowerHex`](fmt::LowerHex).
+impl fmt::UpperHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hex = format!("{:02X}{:02X}{:02X}", self.r, self.g, self.b);
+        pad_hex(f, &hex)
+    }
+}
+
+/// `Color(#4682b4)`, which is easier to recognize in a failing test than
+/// the three channels. `{:#?}` prints the same single line.
+///
+/// ```
+/// use colourss::Color;
+///
+/// assert_eq!(format!("{:?}", Color::new(70, 130, 180)), "Color(#4682b4)");
+/// ```
+impl fmt::Debug for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Color({})", self.to_hex())
+    }
+}
+
+fn add_channel(c: u8, delta: i16) -> u8 {
+    (c as i32 + delta as i32).clamp(0, 255) as u8
+}
+
+impl From<[u8; 3]> for Color {
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Color { r, g, b }
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Color { r, g, b }
+    }
+}
+
+/// Same as [`parse_color`], so `"navy".parse::<Color>()` works.
+impl FromStr for Color {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_color(input)
+    }
+}
+
+/// Same as [`parse_color`], for code that is generic over `TryFrom`.
+///
+/// ```
+/// use colourss::{Color, ParseError};
+///
+/// fn accent(input: &str) -> Result<String, ParseError> {
+///     Ok(Color::try_from(input)?.to_hex())
+/// }
+/// # #[cfg(feature = "named-colors")]
+/// assert_eq!(accent("teal").unwrap(), "#008080");
+/// assert!(accent("teel").is_err());
+/// ```
+impl TryFrom<&str> for Color {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse_color(input)
+    }
+}
+
+impl TryFrom<String> for Color {
+    type Error = ParseError;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        parse_color(&input)
+    }
+}
+
+/// Same as [`parse_color_bytes`]: bytes that aren't UTF-8 are
+/// [`ParseError::InvalidUtf8`].
+impl TryFrom<&[u8]> for Color {
+    type Error = ParseError;
+
+    fn try_from(input: &[u8]) -> Result<Self, Self::Error> {
+        parse_color_bytes(input)
+    }
+}
+
+fn pad_hex(f: &mut fmt::Formatter, digits: &str) -> fmt::Result {
+    let hash = if f.alternate() { "#" } else { "" };
+    let pad = f.width().unwrap_or(0).saturating_sub(hash.len() + digits.len());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, pad),
+        Some(fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+        Some(fmt::Alignment::Right) | None => (pad, 0),
+    };
+    let fill = f.fill().to_string();
+    write!(f, "{}{}{}{}", fill.repeat(before), hash, digits, fill.repeat(after))
+}
+
 /// Parses any CSS color string into an RGB `Color` struct.
 ///
 /// This parser attempts to match the input string against a set of
@@ -51,6 +467,7 @@ pub struct Color {
 /// ### 2. RGB(A): `<rgb-color> ::= 'rgb(' <components> ')' | 'rgba(' <components> ')'`
 ///
 /// Supports both comma-separated and space-separated values, and percentages for R, G, B.
+/// Function names are case-insensitive (`RGB(...)` works too), as in CSS.
 ///
 /// * `rgb(255, 100, 0)`
 /// * `rgba(255, 100, 0, 0.5)` (alpha is ignored)
@@ -58,6 +475,10 @@ pub struct Color {
 /// * `rgba(255 100 0 / 0.5)` (space-separated with alpha)
 /// * `rgb(100%, 0%, 50%)` (percentages)
 ///
+/// Percentages may have decimals (`rgb(33.333% 66.667% 0%)`). They are
+/// scaled to `0..=255` and rounded to the nearest byte, halves up, the
+/// same in either syntax: `50%` is `128`.
+///
 /// ### 3. HSL(A): `<hsl-color> ::= 'hsl(' <components> ')' | 'hsla(' <components> ')'`
 ///
 /// Supports both comma-separated and space-separated values.
@@ -67,126 +488,430 @@ pub struct Color {
 /// * `hsl(120 100% 50%)` (space-separated)
 /// * `hsla(120 100% 50% / 1.0)` (space-separated with alpha)
 ///
+/// Saturation and lightness may have decimals too (`hsl(120, 62.5%, 47.3%)`);
+/// each channel is rounded to the nearest byte like [`Color::from_hsl`].
+///
 /// ### 4. Named: `<named-color> ::= 'red' | 'blue' | ...`
 ///
-/// * `red`, `green`, `blue`, `white`, `black`, `yellow`, `rebeccapurple`, etc.
+/// * All 148 CSS color keywords: `red`, `steelblue`, `rebeccapurple`, etc.
 /// * This is case-insensitive.
 ///
 /// *(Note: For `rgba` and `hsla` formats, the alpha component is parsed
 /// to ensure the format is valid, but it is discarded in the final `Color`
-/// struct.)*
+/// struct. An alpha that isn't a number, or a second one after the `/`,
+/// is an error here just as in [`parse_color_with_alpha`].)*
 pub fn parse_color(input: &str) -> Result<Color, ParseError> {
+    parse_color_alpha(input).map(|(color, _alpha)| color)
+}
+
+/// Like [`parse_color`], but for raw bytes, such as a line read from a
+/// file that may not be UTF-8. Bytes that aren't UTF-8 are
+/// [`ParseError::InvalidUtf8`].
+pub fn parse_color_bytes(input: &[u8]) -> Result<Color, ParseError> {
+    let input = std::str::from_utf8(input)
+        .map_err(|_| ParseError::InvalidUtf8(snippet(&String::from_utf8_lossy(input))))?;
+    parse_color(input)
+}
+
+/// Like [`parse_color`], but also returns the alpha, in `0.0..=1.0`:
+/// `1.0` for formats without one, clamped if it's out of range. An
+/// alpha that isn't a number is an error, as in `parse_color`.
+///
+/// ```
+/// use colourss::parse_color_with_alpha;
+///
+/// let (color, alpha) = parse_color_with_alpha("#00000080").unwrap();
+/// assert_eq!(color.to_hex(), "#000000");
+/// assert!((alpha - 0.502).abs() < 0.001);
+/// ```
+pub fn parse_color_with_alpha(input: &str) -> Result<(Color, f32), ParseError> {
+    parse_color_alpha(input)
+}
+
+/// Like [`parse_color`], but keeps the alpha: `1.0` for formats without
+/// one, otherwise what was given (from the last two digits of 8-digit
+/// hex, the fourth argument of legacy `rgba()`/`hsla()`, or after the
+/// `/`), clamped to `0.0..=1.0`.
+///
+/// ```
+/// use colourss::parse_rgba;
+///
+/// let color = parse_rgba("rgb(255 0 0 / 25%)").unwrap();
+/// assert_eq!((color.r, color.a), (255, 0.25));
+/// # #[cfg(feature = "named-colors")]
+/// assert_eq!(parse_rgba("navy").unwrap().a, 1.0);
+/// ```
+pub fn parse_rgba(input: &str) -> Result<Rgba, ParseError> {
+    input.parse()
+}
+
+/// Like [`parse_rgba`], with [`ParseOptions`].
+///
+/// ```
+/// use colourss::{parse_rgba_with, ParseOptions};
+///
+/// let color = parse_rgba_with("gray(50% / 0.5)", &ParseOptions::lenient()).unwrap();
+/// assert_eq!((color.r, color.g, color.b, color.a), (128, 128, 128, 0.5));
+/// ```
+pub fn parse_rgba_with(input: &str, options: &ParseOptions) -> Result<Rgba, ParseError> {
+    let (Color { r, g, b }, a) = parse_color_alpha_with(input, options)?;
+    Ok(Rgba { r, g, b, a })
+}
+
+/// Like [`parse_color`], but with 16 bits per channel. Percentages and
+/// `hsl()` keep their precision instead of being rounded to bytes first;
+/// hex codes and names are widened as in [`Color16::from`].
+///
+/// ```
+/// use colourss::parse_color16;
+///
+/// assert_eq!(parse_color16("rgb(50% 50% 50%)").unwrap().r, 0x8000);
+/// assert_eq!(parse_color16("#ab0000").unwrap().r, 0xabab);
+/// ```
+pub fn parse_color16(input: &str) -> Result<Color16, ParseError> {
+    parse_color16_with(input, &ParseOptions::default())
+}
+
+/// Like [`parse_color16`], but as strict or as forgiving as `options` say.
+pub fn parse_color16_with(input: &str, options: &ParseOptions) -> Result<Color16, ParseError> {
+    parse_channels(input, options).map(|(channels, _alpha)| Color16::from_channels(channels))
+}
+
+/// Like [`parse_color`], but as strict or as forgiving as `options` say.
+///
+/// ```
+/// use colourss::{parse_color, parse_color_with, ParseOptions};
+///
+/// assert!(parse_color("hsl(120, 100, 50)").is_ok());
+/// assert!(parse_color_with("hsl(120, 100, 50)", &ParseOptions::strict()).is_err());
+/// assert_eq!(parse_color_with("rgb(300, 0, 0)", &ParseOptions::lenient()).unwrap().r, 255);
+/// ```
+pub fn parse_color_with(input: &str, options: &ParseOptions) -> Result<Color, ParseError> {
+    parse_color_alpha_with(input, options).map(|(color, _alpha)| color)
+}
+
+/// Same as `parse_color`, but also returns the alpha (`1.0` if there was none).
+pub(crate) fn parse_color_alpha(input: &str) -> Result<(Color, f32), ParseError> {
+    parse_color_alpha_with(input, &ParseOptions::default())
+}
+
+pub(crate) fn parse_color_alpha_with(input: &str, options: &ParseOptions) -> Result<(Color, f32), ParseError> {
+    parse_channels(input, options).map(|(channels, alpha)| (channels.to_color(), alpha))
+}
+
+/// The channels of a parsed color, before `rgb()` and `hsl()` values are
+/// rounded to the nearest byte, halves up.
+#[derive(Clone, Copy)]
+pub(crate) enum Channels {
+    /// Hex codes and names, which are bytes to begin with.
+    Bytes(Color),
+    /// `rgb()` components on the `0.0..=255.0` scale.
+    Rgb([f32; 3]),
+    /// `hsl()` converted to RGB on the `0.0..=1.0` scale.
+    Hsl([f32; 3]),
+}
+
+impl Channels {
+    pub(crate) fn to_color(self) -> Color {
+        match self {
+            Channels::Bytes(color) => color,
+            Channels::Rgb([r, g, b]) => Color { r: r.round() as u8, g: g.round() as u8, b: b.round() as u8 },
+            Channels::Hsl([r, g, b]) => {
+                Color { r: (r * 255.0).round() as u8, g: (g * 255.0).round() as u8, b: (b * 255.0).round() as u8 }
+            }
+        }
+    }
+}
+
+pub(crate) fn parse_channels(input: &str, options: &ParseOptions) -> Result<(Channels, f32), ParseError> {
     let input = input.trim();
 
     if input.is_empty() {
-        return Err(ParseError::InvalidHexFormat);
+        return Err(ParseError::InvalidHexFormat(String::new()));
     }
     
     if input.starts_with('#') {
-        return parse_hex(input);
+        return parse_hex(input).map(|(color, a)| (Channels::Bytes(color), a));
+    }
+
+    if (starts_with_function(input, "rgb") || starts_with_function(input, "rgba")) && input.ends_with(')') {
+        return parse_rgb(input, options);
+    }
+
+    if (starts_with_function(input, "hsl") || starts_with_function(input, "hsla")) && input.ends_with(')') {
+        return parse_hsl(input, options);
+    }
+
+    if options.allow_gray_function && starts_with_function(input, "gray") && input.ends_with(')') {
+        return parse_gray(input, options);
+    }
+
+    if options.allow_0x_hex
+        && let Some(digits) = input.strip_prefix("0x").or_else(|| input.strip_prefix("0X"))
+    {
+        let invalid = || ParseError::InvalidHexFormat(snippet(input));
+        if !matches!(digits.len(), 6 | 8) {
+            return Err(invalid());
+        }
+        return parse_hex(&format!("#{}", digits))
+            .map(|(color, a)| (Channels::Bytes(color), a))
+            .map_err(|_| invalid());
+    }
+
+    // `ff6600` is hex only when asked for, so typos of names don't
+    // turn into colors
+    if options.allow_bare_hex
+        && matches!(input.len(), 3 | 4 | 6 | 8)
+        && input.bytes().all(|b| b.is_ascii_hexdigit())
+    {
+        return parse_hex(&format!("#{}", input)).map(|(color, a)| (Channels::Bytes(color), a));
+    }
+
+    if let Some(keyword) = css_wide_keyword(input) {
+        return Err(ParseError::CssWideKeyword(keyword));
+    }
+
+    // if nothing matches, try a name; CSS keywords come first, so the
+    // Material and Tailwind palettes can't shadow them, then Material,
+    // whose names Tailwind has to take with `tailwind:`
+    let named = match options.name_set {
+        NameSet::Css => parse_named(input),
+        NameSet::X11 => parse_x11(input),
+        NameSet::Both => parse_named(input).or_else(|error| parse_x11(input).map_err(|_| error)),
+    };
+    #[cfg(feature = "material-names")]
+    if named.is_err()
+        && let Some(color) = material::parse_material(input)
+    {
+        return Ok((Channels::Bytes(color), 1.0));
     }
+    #[cfg(feature = "tailwind-names")]
+    if named.is_err()
+        && let Some(color) = tailwind::parse_tailwind(input)
+    {
+        return Ok((Channels::Bytes(color), 1.0));
+    }
+    named.map(|color| (Channels::Bytes(color), 1.0))
+}
 
-    if (input.starts_with("rgb(") || input.starts_with("rgba(")) && input.ends_with(')') {
-        return parse_rgb(input);
+/// `content` with [`ParseOptions::extra_separators`] turned into commas,
+/// so they follow the same rules.
+fn commas_for_extra_separators<'a>(content: &'a str, options: &ParseOptions) -> std::borrow::Cow<'a, str> {
+    let extra = options.extra_separators;
+    if extra.is_empty() || !content.contains(extra) {
+        return std::borrow::Cow::Borrowed(content);
     }
+    std::borrow::Cow::Owned(content.replace(extra, ","))
+}
 
-    if (input.starts_with("hsl(") || input.starts_with("hsla(")) && input.ends_with(')') {
-        return parse_hsl(input);
+/// `color_str` after checking that no comma-separated component in it
+/// (or the alpha after the `/`) is empty. A trailing comma is dropped
+/// if [`ParseOptions::allow_trailing_comma`] says so.
+fn without_empty_components<'a>(
+    color_str: &'a str,
+    alpha_str: Option<&str>,
+    input: &str,
+    options: &ParseOptions,
+) -> Result<&'a str, ParseError> {
+    let empty = |position| ParseError::EmptyComponent { position, input: snippet(input) };
+    let mut color_str = color_str;
+    if color_str.contains(',') {
+        let count = color_str.split(',').count();
+        for (i, part) in color_str.split(',').enumerate() {
+            if !part.trim().is_empty() {
+                continue;
+            }
+            if i == count - 1 && i > 0 && options.allow_trailing_comma {
+                color_str = &color_str[..color_str.rfind(',').unwrap_or(color_str.len())];
+                break;
+            }
+            return Err(empty(i));
+        }
     }
+    if alpha_str.is_some_and(|a| a.trim().is_empty()) {
+        return Err(empty(color_str.split([',', ' ']).filter(|p| !p.trim().is_empty()).count()));
+    }
+    Ok(color_str)
+}
 
-    // if nothing matches, try a name
-    parse_named(input)
+/// Whether the components mix commas with spaces or a `/`, as in
+/// `rgb(1, 2 3)` or `rgb(1, 2, 3 / 0.5)`.
+fn mixes_separators(color_str: &str, has_alpha_slash: bool) -> bool {
+    color_str.contains(',')
+        && (has_alpha_slash || color_str.split(',').any(|part| part.split_whitespace().count() > 1))
+}
+
+/// Whether `input` starts with `name(`, ignoring ASCII case like CSS does.
+fn starts_with_function(input: &str, name: &str) -> bool {
+    let bytes = input.as_bytes();
+    bytes.len() > name.len()
+        && bytes[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+        && bytes[name.len()] == b'('
 }
 
 /// Rule 1: Parse `#RRGGBB` (long) or `#RGB` (short)
 ///
 /// Handles 3, 4, 6, and 8-digit hex codes.
-/// Alpha (4 and 8 digits) is ignored.
-fn parse_hex(input: &str) -> Result<Color, ParseError> {
+/// Alpha (4 and 8 digits) is returned separately as 0.0-1.0.
+fn parse_hex(input: &str) -> Result<(Color, f32), ParseError> {
+    let invalid = || ParseError::InvalidHexFormat(snippet(input));
     // remove the '#'
     let hex = &input[1..];
 
+    // checking up front keeps the byte slicing below on char boundaries
+    // and stops `from_str_radix` from accepting a `+` sign
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+
     match hex.len() {
         // short hex: #rgb
         3 => {
             let r = u8::from_str_radix(&hex[0..1].repeat(2), 16)
-                .map_err(|_| ParseError::InvalidHexFormat)?;
+                .map_err(|_| invalid())?;
             let g = u8::from_str_radix(&hex[1..2].repeat(2), 16)
-                .map_err(|_| ParseError::InvalidHexFormat)?;
+                .map_err(|_| invalid())?;
             let b = u8::from_str_radix(&hex[2..3].repeat(2), 16)
-                .map_err(|_| ParseError::InvalidHexFormat)?;
-            Ok(Color { r, g, b })
+                .map_err(|_| invalid())?;
+            Ok((Color { r, g, b }, 1.0))
         }
-        // short hex with alpha: #rgba (alpha ignored)
+        // short hex with alpha: #rgba
         4 => {
             let r = u8::from_str_radix(&hex[0..1].repeat(2), 16)
-                .map_err(|_| ParseError::InvalidHexFormat)?;
+                .map_err(|_| invalid())?;
             let g = u8::from_str_radix(&hex[1..2].repeat(2), 16)
-                .map_err(|_| ParseError::InvalidHexFormat)?;
+                .map_err(|_| invalid())?;
             let b = u8::from_str_radix(&hex[2..3].repeat(2), 16)
-                .map_err(|_| ParseError::InvalidHexFormat)?;
-            // hex[3..4] is alpha, we ignore it
-            Ok(Color { r, g, b })
+                .map_err(|_| invalid())?;
+            let a = u8::from_str_radix(&hex[3..4].repeat(2), 16)
+                .map_err(|_| invalid())?;
+            Ok((Color { r, g, b }, a as f32 / 255.0))
         }
         // long hex: #rrggbb
         6 => {
             let r =
-                u8::from_str_radix(&hex[0..2], 16).map_err(|_| ParseError::InvalidHexFormat)?;
+                u8::from_str_radix(&hex[0..2], 16).map_err(|_| invalid())?;
             let g =
-                u8::from_str_radix(&hex[2..4], 16).map_err(|_| ParseError::InvalidHexFormat)?;
+                u8::from_str_radix(&hex[2..4], 16).map_err(|_| invalid())?;
             let b =
-                u8::from_str_radix(&hex[4..6], 16).map_err(|_| ParseError::InvalidHexFormat)?;
-            Ok(Color { r, g, b })
+                u8::from_str_radix(&hex[4..6], 16).map_err(|_| invalid())?;
+            Ok((Color { r, g, b }, 1.0))
         }
-        // long hex with alpha: #rrggbbaa (alpha ignored)
+        // long hex with alpha: #rrggbbaa
         8 => {
             let r =
-                u8::from_str_radix(&hex[0..2], 16).map_err(|_| ParseError::InvalidHexFormat)?;
+                u8::from_str_radix(&hex[0..2], 16).map_err(|_| invalid())?;
             let g =
-                u8::from_str_radix(&hex[2..4], 16).map_err(|_| ParseError::InvalidHexFormat)?;
+                u8::from_str_radix(&hex[2..4], 16).map_err(|_| invalid())?;
             let b =
-                u8::from_str_radix(&hex[4..6], 16).map_err(|_| ParseError::InvalidHexFormat)?;
-            // hex[6..8] is alpha, we ignore it
-            Ok(Color { r, g, b })
+                u8::from_str_radix(&hex[4..6], 16).map_err(|_| invalid())?;
+            let a =
+                u8::from_str_radix(&hex[6..8], 16).map_err(|_| invalid())?;
+            Ok((Color { r, g, b }, a as f32 / 255.0))
         }
         // anything else is wrong
-        _ => Err(ParseError::InvalidHexFormat),
+        _ => Err(invalid()),
+    }
+}
+
+/// Parses a CSS `<number>`: an optional sign, then digits with an
+/// optional fraction or just a fraction (`.5`), then an optional exponent
+/// (`1e2`). Stricter than `f32::from_str`, which also takes `inf`, `NaN`
+/// and `5.`; numbers too big for an `f32` are rejected too.
+pub(crate) fn css_number(text: &str) -> Option<f32> {
+    let bytes = text.as_bytes();
+    let digits = |from: usize| bytes[from.min(bytes.len())..].iter().take_while(|b| b.is_ascii_digit()).count();
+    let mut i = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let whole = digits(i);
+    i += whole;
+    if bytes.get(i) == Some(&b'.') {
+        let fraction = digits(i + 1);
+        if fraction == 0 {
+            return None;
+        }
+        i += 1 + fraction;
+    } else if whole == 0 {
+        return None;
+    }
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(i + 1), Some(b'+' | b'-')));
+        let exponent = digits(i + 1 + sign);
+        if exponent == 0 {
+            return None;
+        }
+        i += 1 + sign + exponent;
+    }
+    if i != bytes.len() {
+        return None;
     }
+    text.parse::<f32>().ok().filter(|v| v.is_finite())
 }
 
-/// Helper to parse an RGB component (0-255 or 0%-100%)
-fn parse_rgb_component(comp: &str) -> Result<u8, ParseError> {
+/// Helper to parse an RGB component (0-255 or 0%-100%), on the 0-255
+/// scale but not rounded yet
+fn parse_rgb_component(comp: &str, input: &str, options: &ParseOptions) -> Result<f32, ParseError> {
     let comp = comp.trim();
     if let Some(val_str) = comp.strip_suffix('%') {
-        let val = val_str
-            .parse::<f32>()
-            .map_err(|_| ParseError::InvalidComponentValue(comp.to_string()))?;
+        let mut val = css_number(val_str).ok_or_else(|| invalid_component(comp, input))?;
         if !(0.0..=100.0).contains(&val) {
-            return Err(ParseError::InvalidComponentValue(comp.to_string()));
+            if !options.clamp_components {
+                return Err(invalid_component(comp, input));
+            }
+            val = val.clamp(0.0, 100.0);
         }
-        // Convert 0.0-100.0 to 0-255
-        Ok((val / 100.0 * 255.0).round() as u8)
+        // Convert 0.0-100.0 to 0-255, multiplying first so that halves
+        // like 70% (178.5) stay exact and round up
+        Ok(val * 255.0 / 100.0)
+    } else if options.clamp_components {
+        // any number, clamped
+        let val = css_number(comp).ok_or_else(|| invalid_component(comp, input))?;
+        Ok(val.clamp(0.0, 255.0))
     } else {
-        // Plain number 0-255
-        comp.parse::<u8>()
-            .map_err(|_| ParseError::InvalidComponentValue(comp.to_string()))
+        // a whole number 0-255, however it's written (`+12`, `1e2`)
+        css_number(comp)
+            .filter(|v| v.fract() == 0.0 && (0.0..=255.0).contains(v))
+            .map(|v| v.abs())
+            .ok_or_else(|| invalid_component(comp, input))
     }
 }
 
+/// Helper to parse an alpha component (0-1 or 0%-100%), clamped to 0.0-1.0
+/// unless `options` say otherwise. A missing alpha means fully opaque.
+fn parse_alpha(comp: Option<&str>, input: &str, options: &ParseOptions) -> Result<f32, ParseError> {
+    let Some(comp) = comp else {
+        return Ok(1.0);
+    };
+    let comp = comp.trim();
+    let (val_str, scale) = match comp.strip_suffix('%') {
+        Some(val_str) => (val_str, 100.0),
+        None => (comp, 1.0),
+    };
+    let val = css_number(val_str).ok_or_else(|| invalid_component(comp, input))?;
+    if !options.clamp_alpha && !(0.0..=1.0).contains(&(val / scale)) {
+        return Err(invalid_component(comp, input));
+    }
+    Ok((val / scale).clamp(0.0, 1.0))
+}
+
 /// Rule 2: Parse `rgb(R, G, B)` or `rgba(R, G, B, A)`
 /// Also supports modern space-separated syntax `rgb(R G B / A)`
 /// and percentages `rgb(100% 0% 0%)`.
-fn parse_rgb(input: &str) -> Result<Color, ParseError> {
-    let start = input.find('(').ok_or(ParseError::InvalidRgbFormat)?;
-    let end = input.rfind(')').ok_or(ParseError::InvalidRgbFormat)?;
-    let content = &input[start + 1..end];
+fn parse_rgb(input: &str, options: &ParseOptions) -> Result<(Channels, f32), ParseError> {
+    let invalid = || ParseError::InvalidRgbFormat(snippet(input));
+    let start = input.find('(').ok_or_else(invalid)?;
+    let end = input.rfind(')').ok_or_else(invalid)?;
+    let content = &*commas_for_extra_separators(&input[start + 1..end], options);
 
     // Determine the color part of the string (pre-alpha-slash)
-    let (color_str, has_alpha_slash) = if let Some((color_str, _alpha_str)) = content.split_once('/') {
-        (color_str, true)
-    } else {
-        (content, false)
+    let (color_str, alpha_str) = match content.split_once('/') {
+        Some((color_str, alpha_str)) => (color_str, Some(alpha_str)),
+        None => (content, None),
     };
+    let color_str = without_empty_components(color_str, alpha_str, input, options)?;
+    let has_alpha_slash = alpha_str.is_some();
+    if !options.mixed_separators && mixes_separators(color_str, has_alpha_slash) {
+        return Err(invalid());
+    }
 
     // Create a String that will own the data.
     // This string lives until the end of the function.
@@ -197,38 +922,47 @@ fn parse_rgb(input: &str) -> Result<Color, ParseError> {
 
     // We must have exactly 3 (rgb) or 4 (rgba legacy) parts.
     if !(color_parts.len() == 3 || color_parts.len() == 4) {
-        return Err(ParseError::InvalidRgbFormat);
+        return Err(invalid());
     }
     
     // If we have 4 parts, but NO slash was found, it must be legacy `rgba(R,G,B,A)`
     // and this requires commas.
     if color_parts.len() == 4 && !has_alpha_slash && !content.contains(',') {
          // This is `rgba(R G B A)` which is invalid
-         return Err(ParseError::InvalidRgbFormat);
+         return Err(invalid());
+    }
+    // `rgba(R G B A / A)` has two alphas
+    if color_parts.len() == 4 && has_alpha_slash {
+        return Err(invalid());
     }
 
     // parse R, G, B using the helper
-    let r = parse_rgb_component(color_parts[0])?;
-    let g = parse_rgb_component(color_parts[1])?;
-    let b = parse_rgb_component(color_parts[2])?;
-    // color_parts[3] (alpha) is ignored if it exists
+    let r = parse_rgb_component(color_parts[0], input, options)?;
+    let g = parse_rgb_component(color_parts[1], input, options)?;
+    let b = parse_rgb_component(color_parts[2], input, options)?;
+    let a = parse_alpha(alpha_str.or(color_parts.get(3).copied()), input, options)?;
 
-    Ok(Color { r, g, b })
+    Ok((Channels::Rgb([r, g, b]), a))
 }
 
 /// Rule 3: Parse `hsl(H, S, L)` or `hsla(H, S, L, A)`
 /// Also supports modern space-separated syntax `hsl(H S L / A)`.
-fn parse_hsl(input: &str) -> Result<Color, ParseError> {
-    let start = input.find('(').ok_or(ParseError::InvalidHslFormat)?;
-    let end = input.rfind(')').ok_or(ParseError::InvalidHslFormat)?;
-    let content = &input[start + 1..end];
+fn parse_hsl(input: &str, options: &ParseOptions) -> Result<(Channels, f32), ParseError> {
+    let invalid = || ParseError::InvalidHslFormat(snippet(input));
+    let start = input.find('(').ok_or_else(invalid)?;
+    let end = input.rfind(')').ok_or_else(invalid)?;
+    let content = &*commas_for_extra_separators(&input[start + 1..end], options);
     
     // Determine the color part of the string (pre-alpha-slash)
-    let (color_str, has_alpha_slash) = if let Some((color_str, _alpha_str)) = content.split_once('/') {
-        (color_str, true)
-    } else {
-        (content, false)
+    let (color_str, alpha_str) = match content.split_once('/') {
+        Some((color_str, alpha_str)) => (color_str, Some(alpha_str)),
+        None => (content, None),
     };
+    let color_str = without_empty_components(color_str, alpha_str, input, options)?;
+    let has_alpha_slash = alpha_str.is_some();
+    if !options.mixed_separators && mixes_separators(color_str, has_alpha_slash) {
+        return Err(invalid());
+    }
 
     // Create a String that will own the data.
     // This string lives until the end of the function.
@@ -239,43 +973,61 @@ fn parse_hsl(input: &str) -> Result<Color, ParseError> {
 
     // We must have exactly 3 (hsl) or 4 (hsla legacy) parts.
     if !(parts.len() == 3 || parts.len() == 4) {
-        return Err(ParseError::InvalidHslFormat);
+        return Err(invalid());
     }
 
     // If we have 4 parts, but NO slash was found, it must be legacy `hsla(H,S,L,A)`
     // and this requires commas.
     if parts.len() == 4 && !has_alpha_slash && !content.contains(',') {
          // This is `hsla(H S L A)` which is invalid
-         return Err(ParseError::InvalidHslFormat);
+         return Err(invalid());
     }
+    // `hsla(H S L A / A)` has two alphas
+    if parts.len() == 4 && has_alpha_slash {
+        return Err(invalid());
+    }
+    let a = parse_alpha(alpha_str.or(parts.get(3).copied()), input, options)?;
 
     // H: 0-360 (can have 'deg' unit, or be unitless)
-    let h_str = parts[0].trim().trim_end_matches("deg");
-    let h = h_str
-        .parse::<f32>()
-        .map_err(|_| ParseError::InvalidComponentValue(parts[0].to_string()))?;
-
-    // S: 0%-100% (or just 0-100, based on tests)
-    let s_str = parts[1].trim().trim_end_matches('%');
-    let s = s_str
-        .parse::<f32>()
-        .map_err(|_| ParseError::InvalidComponentValue(parts[1].to_string()))?;
-
-    // L: 0%-100% (or just 0-100)
-    let l_str = parts[2].trim().trim_end_matches('%');
-    let l = l_str
-        .parse::<f32>()
-        .map_err(|_| ParseError::InvalidComponentValue(parts[2].to_string()))?;
+    let h_str = parts[0].trim();
+    let h = css_number(h_str.strip_suffix("deg").unwrap_or(h_str))
+        .ok_or_else(|| invalid_component(parts[0], input))?;
+
+    if options.require_hsl_percent {
+        for (name, part) in [("S", parts[1]), ("L", parts[2])] {
+            if !part.ends_with('%') {
+                return Err(invalid_component(&format!("{}: {} (needs a %)", name, part), input));
+            }
+        }
+    }
+
+    // S: 0%-100% (or just 0-100, unless `require_hsl_percent`)
+    let s_str = parts[1].trim();
+    let s = css_number(s_str.strip_suffix('%').unwrap_or(s_str))
+        .ok_or_else(|| invalid_component(parts[1], input))?;
+
+    // L: 0%-100% (or just 0-100, unless `require_hsl_percent`)
+    let l_str = parts[2].trim();
+    let l = css_number(l_str.strip_suffix('%').unwrap_or(l_str))
+        .ok_or_else(|| invalid_component(parts[2], input))?;
+
+    // out of range is an error, unless asked to clamp (and wrap the hue)
+    let (h, s, l) = if options.clamp_components && h.is_finite() && s.is_finite() && l.is_finite() {
+        let h = if (0.0..=360.0).contains(&h) { h } else { h.rem_euclid(360.0) };
+        (h, s.clamp(0.0, 100.0), l.clamp(0.0, 100.0))
+    } else {
+        (h, s, l)
+    };
 
     // Validate ranges
     if !(0.0..=360.0).contains(&h) {
-        return Err(ParseError::InvalidComponentValue(format!("H: {}", h)));
+        return Err(invalid_component(&format!("H: {}", h), input));
     }
     if !(0.0..=100.0).contains(&s) {
-        return Err(ParseError::InvalidComponentValue(format!("S: {}", s)));
+        return Err(invalid_component(&format!("S: {}", s), input));
     }
     if !(0.0..=100.0).contains(&l) {
-        return Err(ParseError::InvalidComponentValue(format!("L: {}", l)));
+        return Err(invalid_component(&format!("L: {}", l), input));
     }
 
     // convert to 0..1 range
@@ -286,12 +1038,7 @@ fn parse_hsl(input: &str) -> Result<Color, ParseError> {
     // HSL to RGB conversion
     if s == 0.0 {
         // it's grayscale
-        let val = (l * 255.0) as u8;
-        Ok(Color {
-            r: val,
-            g: val,
-            b: val,
-        })
+        Ok((Channels::Hsl([l, l, l]), a))
     } else {
         let q = if l < 0.5 { l * (1.0 + s) } else { l + s - l * s };
         let p = 2.0 * l - q;
@@ -299,13 +1046,41 @@ fn parse_hsl(input: &str) -> Result<Color, ParseError> {
         let g = hue_to_rgb(p, q, h);
         let b = hue_to_rgb(p, q, h - 1.0 / 3.0);
 
-        Ok(Color {
-            r: (r * 255.0) as u8,
-            g: (g * 255.0) as u8,
-            b: (b * 255.0) as u8,
-        })
+        Ok((Channels::Hsl([r, g, b]), a))
     }
 }
+
+/// Parse `gray(V)`, `gray(V / A)` or `gray(V, A)`, which is `rgb(V V V)`
+/// with that alpha. Errors are reported as `rgb()` errors.
+fn parse_gray(input: &str, options: &ParseOptions) -> Result<(Channels, f32), ParseError> {
+    let invalid = || ParseError::InvalidRgbFormat(snippet(input));
+    let start = input.find('(').ok_or_else(invalid)?;
+    let end = input.rfind(')').ok_or_else(invalid)?;
+    let content = &*commas_for_extra_separators(&input[start + 1..end], options);
+
+    let (color_str, alpha_str) = match content.split_once('/') {
+        Some((color_str, alpha_str)) => (color_str, Some(alpha_str)),
+        None => (content, None),
+    };
+    let color_str = without_empty_components(color_str, alpha_str, input, options)?;
+    if !options.mixed_separators && mixes_separators(color_str, alpha_str.is_some()) {
+        return Err(invalid());
+    }
+
+    let component_string = color_str.replace(',', " ");
+    let parts: Vec<&str> = component_string.split_whitespace().collect();
+    // one value, and an alpha after a comma or a slash but not both
+    match parts.len() {
+        1 => {}
+        2 if alpha_str.is_none() && color_str.contains(',') => {}
+        _ => return Err(invalid()),
+    }
+
+    let v = parse_rgb_component(parts[0], input, options)?;
+    let a = parse_alpha(alpha_str.or(parts.get(1).copied()), input, options)?;
+    Ok((Channels::Rgb([v, v, v]), a))
+}
+
 // Helper for HSL
 fn hue_to_rgb(p: f32, q: f32, mut t: f32) -> f32 {
     if t < 0.0 {
@@ -326,33 +1101,14 @@ fn hue_to_rgb(p: f32, q: f32, mut t: f32) -> f32 {
     p
 }
 
-/// Rule 4: Parse named colors
-fn parse_named(input: &str) -> Result<Color, ParseError> {
-    match input.to_lowercase().as_str() {
-        
-        "red" => Ok(Color { r: 255, g: 0, b: 0 }),
-        "lime" => Ok(Color { r: 0, g: 255, b: 0 }),
-        "blue" => Ok(Color { r: 0, g: 0, b: 255 }),
-        "white" => Ok(Color { r: 255, g: 255, b: 255 }),
-        "black" => Ok(Color { r: 0, g: 0, b: 0 }),
-        "yellow" => Ok(Color { r: 255, g: 255, b: 0 }),
-        "cyan" => Ok(Color { r: 0, g: 255, b: 255 }),
-        "magenta" => Ok(Color { r: 255, g: 0, b: 255 }),
-        "aqua" => Ok(Color { r: 0, g: 255, b: 255 }), // same as cyan
-        "fuchsia" => Ok(Color { r: 255, g: 0, b: 255 }), // same as magenta
-        "orange" => Ok(Color { r: 255, g: 165, b: 0 }),
-        "pink" => Ok(Color { r: 255, g: 192, b: 203 }),
-        "brown" => Ok(Color { r: 165, g: 42, b: 42 }),
-        "silver" => Ok(Color { r: 192, g: 192, b: 192 }),
-        "gray" => Ok(Color { r: 128, g: 128, b: 128 }),
-        "maroon" => Ok(Color { r: 128, g: 0, b: 0 }),
-        "olive" => Ok(Color { r: 128, g: 128, b: 0 }),
-        "green" => Ok(Color { r: 0, g: 128, b: 0 }),
-        "purple" => Ok(Color { r: 128, g: 0, b: 128 }),
-        "teal" => Ok(Color { r: 0, g: 128, b: 128 }),
-        "navy" => Ok(Color { r: 0, g: 0, b: 128 }),
-        "rebeccapurple" => Ok(Color { r: 102, g: 51, b: 153 }),
-        "coffee" => Ok(Color { r: 192, g: 255, b: 238 }),
-        _ => Err(ParseError::UnknownColorName(input.to_string())),
-    }
-}
\ No newline at end of file
+/// Without the `named-colors` feature there are no names to find.
+#[cfg(not(feature = "named-colors"))]
+pub(crate) fn parse_named(input: &str) -> Result<Color, ParseError> {
+    Err(ParseError::UnknownColorName(snippet(input)))
+}
+
+/// Without the `x11-names` feature there are no X11 names to find.
+#[cfg(not(feature = "x11-names"))]
+fn parse_x11(input: &str) -> Result<Color, ParseError> {
+    Err(ParseError::UnknownColorName(snippet(input)))
+}
@@ -0,0 +1,148 @@
+//! sRGB transfer function helpers.
+//!
+//! Channels in `Color` are gamma-encoded sRGB bytes. Anything that does
+//! physical math on light (luminance, mixing, blending) has to decode them
+//! to linear light first.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::Color;
+
+/// Decode one 8-bit sRGB channel to linear light in `0.0..=1.0`.
+pub(crate) fn srgb_to_linear(c: u8) -> f32 {
+    decode(c as f32 / 255.0)
+}
+
+/// Decode a normalized (`0.0..=1.0`) sRGB value to linear light.
+pub(crate) fn decode(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode a linear-light channel back to an 8-bit sRGB value.
+///
+/// Out-of-range input is clamped to `0.0..=1.0` first.
+pub(crate) fn linear_to_srgb(c: f32) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let encoded = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (encoded * 255.0).round() as u8
+}
+
+/// A color in linear-light sRGB.
+///
+/// Channels are nominally `0.0..=1.0` but may go outside that range
+/// while doing arithmetic; [`LinearColor::to_srgb`] clamps them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl LinearColor {
+    /// Encodes back to an 8-bit sRGB [`Color`] with the exact sRGB
+    /// transfer function, clamping out-of-range channels.
+    pub fn to_srgb(&self) -> Color {
+        Color {
+            r: linear_to_srgb(self.r),
+            g: linear_to_srgb(self.g),
+            b: linear_to_srgb(self.b),
+        }
+    }
+}
+
+impl Color {
+    /// Decodes to linear light with the exact sRGB transfer function
+    /// (including the linear segment below `0.04045`).
+    pub fn to_linear(&self) -> LinearColor {
+        LinearColor {
+            r: srgb_to_linear(self.r),
+            g: srgb_to_linear(self.g),
+            b: srgb_to_linear(self.b),
+        }
+    }
+
+    /// Multiplies the light of each channel by `factor`, the way an
+    /// exposure change does: `2.0` is one stop brighter, `0.5` one stop
+    /// darker.
+    ///
+    /// Unlike [`Color::lighten`], which moves HSL lightness towards white
+    /// and so washes colors out, this keeps the ratios between channels
+    /// until one of them clips at full brightness. `1.0` returns the color
+    /// unchanged, `0.0` (and anything negative, or NaN) gives black.
+    pub fn scale_brightness(&self, factor: f32) -> Color {
+        let factor = if factor.is_nan() { 0.0 } else { factor.max(0.0) };
+        (self.to_linear() * factor).to_srgb()
+    }
+
+    /// Raises the light of each channel to the power `gamma`, leaving
+    /// black and white where they are: above `1.0` darkens the midtones,
+    /// below `1.0` brightens them.
+    ///
+    /// Where [`Color::lighten`] and [`Color::darken`] shift every color by
+    /// the same HSL amount, a gamma curve moves midtones most and the ends
+    /// not at all. `gamma` must be positive; anything else returns the
+    /// color unchanged.
+    pub fn apply_gamma(&self, gamma: f32) -> Color {
+        if gamma.is_nan() || gamma <= 0.0 {
+            return *self;
+        }
+        let LinearColor { r, g, b } = self.to_linear();
+        LinearColor { r: r.powf(gamma), g: g.powf(gamma), b: b.powf(gamma) }.to_srgb()
+    }
+}
+
+impl Add for LinearColor {
+    type Output = LinearColor;
+
+    fn add(self, rhs: LinearColor) -> LinearColor {
+        LinearColor {
+            r: self.r + rhs.r,
+            g: self.g + rhs.g,
+            b: self.b + rhs.b,
+        }
+    }
+}
+
+impl Sub for LinearColor {
+    type Output = LinearColor;
+
+    fn sub(self, rhs: LinearColor) -> LinearColor {
+        LinearColor {
+            r: self.r - rhs.r,
+            g: self.g - rhs.g,
+            b: self.b - rhs.b,
+        }
+    }
+}
+
+impl Mul<f32> for LinearColor {
+    type Output = LinearColor;
+
+    fn mul(self, rhs: f32) -> LinearColor {
+        LinearColor {
+            r: self.r * rhs,
+            g: self.g * rhs,
+            b: self.b * rhs,
+        }
+    }
+}
+
+impl Div<f32> for LinearColor {
+    type Output = LinearColor;
+
+    fn div(self, rhs: f32) -> LinearColor {
+        LinearColor {
+            r: self.r / rhs,
+            g: self.g / rhs,
+            b: self.b / rhs,
+        }
+    }
+}
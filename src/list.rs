@@ -0,0 +1,61 @@
+//! Comma-separated lists of colors, as in `box-shadow` or a token file.
+
+use crate::{parse_color, Color, ParseError};
+
+/// Parses `red, #00ff00, rgb(0, 0, 255)` into its colors.
+///
+/// Only commas outside parentheses separate items, so the ones inside
+/// `rgb()` and `hsl()` stay put. Items are trimmed, one trailing comma
+/// is allowed, and an empty (or all-blank) input is an empty list. The
+/// first item that doesn't parse is returned as
+/// [`ParseError::ListItem`] with its index, counting from 0.
+///
+/// ```
+/// use colourss::{parse_color_list, Color};
+///
+/// # #[cfg(feature = "named-colors")] {
+/// let colors = parse_color_list("red, rgb(0, 0, 255),").unwrap();
+/// assert_eq!(colors, [Color::new(255, 0, 0), Color::new(0, 0, 255)]);
+/// assert_eq!(
+///     parse_color_list("red, nope").unwrap_err().to_string(),
+///     "Invalid list item 1: Unknown color name: \"nope\""
+/// );
+/// # }
+/// ```
+pub fn parse_color_list(input: &str) -> Result<Vec<Color>, ParseError> {
+    parse_color_list_iter(input)
+        .enumerate()
+        .map(|(index, result)| result.map_err(|error| ParseError::ListItem { index, error: Box::new(error) }))
+        .collect()
+}
+
+/// Like [`parse_color_list`], but lazily and without allocating: each
+/// item is parsed as it's reached, and errors are the item's own (with
+/// `enumerate` for the index).
+pub fn parse_color_list_iter(input: &str) -> impl Iterator<Item = Result<Color, ParseError>> + '_ {
+    items(input).map(parse_color)
+}
+
+/// The items of a list, split at commas outside parentheses and trimmed.
+fn items(input: &str) -> impl Iterator<Item = &str> {
+    let input = input.trim();
+    let input = input.strip_suffix(',').unwrap_or(input);
+    let mut rest = (!input.is_empty()).then_some(input);
+    std::iter::from_fn(move || {
+        let text = rest?;
+        let mut depth = 0usize;
+        for (i, b) in text.bytes().enumerate() {
+            match b {
+                b'(' => depth += 1,
+                b')' => depth = depth.saturating_sub(1),
+                b',' if depth == 0 => {
+                    rest = Some(&text[i + 1..]);
+                    return Some(text[..i].trim());
+                }
+                _ => {}
+            }
+        }
+        rest = None;
+        Some(text.trim())
+    })
+}
@@ -0,0 +1,139 @@
+use crate::hsl::{hsl_to_rgb, rgb_to_hsl};
+use crate::linear::srgb_to_linear;
+use crate::Color;
+
+impl Color {
+    /// Luminance below which a color counts as dark.
+    ///
+    /// `0.179` is the point where black text and white text have the same
+    /// WCAG contrast ratio against the color, so on either side of it one
+    /// of the two is always the more readable choice.
+    pub const DARK_THRESHOLD: f32 = 0.179;
+
+    /// Relative luminance as defined by WCAG 2.x, in `0.0..=1.0`.
+    ///
+    /// Channels are decoded to linear light and weighted with the
+    /// BT.709 coefficients (`0.2126 R + 0.7152 G + 0.0722 B`).
+    pub fn relative_luminance(&self) -> f32 {
+        0.2126 * srgb_to_linear(self.r)
+            + 0.7152 * srgb_to_linear(self.g)
+            + 0.0722 * srgb_to_linear(self.b)
+    }
+
+    /// Returns `true` if the color is dark, i.e. light text should go on top of it.
+    ///
+    /// Uses [`Color::DARK_THRESHOLD`]. Pure blue `#0000ff` is dark,
+    /// pure yellow `#ffff00` is light.
+    pub fn is_dark(&self) -> bool {
+        self.is_dark_with_threshold(Self::DARK_THRESHOLD)
+    }
+
+    /// Returns `true` if the color is light, i.e. dark text should go on top of it.
+    ///
+    /// Always the opposite of [`Color::is_dark`].
+    pub fn is_light(&self) -> bool {
+        !self.is_dark()
+    }
+
+    /// Like [`Color::is_dark`], but with a custom luminance threshold.
+    ///
+    /// A color whose luminance is exactly equal to `threshold` counts as light.
+    pub fn is_dark_with_threshold(&self, threshold: f32) -> bool {
+        self.relative_luminance() < threshold
+    }
+
+    /// Like [`Color::is_light`], but with a custom luminance threshold.
+    pub fn is_light_with_threshold(&self, threshold: f32) -> bool {
+        !self.is_dark_with_threshold(threshold)
+    }
+
+    /// WCAG 2.x contrast ratio between two colors, from `1.0` (same
+    /// luminance) to `21.0` (black on white).
+    ///
+    /// The order doesn't matter: the lighter color always goes on top of
+    /// the fraction `(L1 + 0.05) / (L2 + 0.05)`. The result isn't
+    /// rounded, so compare it against thresholds like `4.5` directly.
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Black or white, whichever has the higher contrast ratio against
+    /// `self`, for text on a `self` background; black on a tie.
+    /// [`Color::is_dark`] rounds the same choice to a luminance threshold.
+    /// HSL lightness gets it wrong for colors like `#777777` and pure red,
+    /// which want black, and pure blue, which wants white.
+    pub fn best_text_color(&self) -> Color {
+        let black = Color::new(0, 0, 0);
+        let white = Color::new(255, 255, 255);
+        self.best_text_color_from(&[black, white]).unwrap_or(black)
+    }
+
+    /// The color in `candidates` with the highest contrast ratio against
+    /// `self`, the first on a tie; `None` if `candidates` is empty. For
+    /// themes whose text isn't pure black or white.
+    ///
+    /// ```
+    /// use colourss::Color;
+    ///
+    /// let ink = Color::new(0x1f, 0x1f, 0x1f);
+    /// let paper = Color::new(0xfa, 0xfa, 0xf0);
+    /// let yellow = Color::new(255, 255, 0);
+    /// assert_eq!(yellow.best_text_color_from(&[ink, paper]), Some(ink));
+    /// ```
+    pub fn best_text_color_from(&self, candidates: &[Color]) -> Option<Color> {
+        let mut best: Option<(Color, f32)> = None;
+        for candidate in candidates {
+            let ratio = self.contrast_ratio(candidate);
+            if best.is_none_or(|(_, best_ratio)| ratio > best_ratio) {
+                best = Some((*candidate, ratio));
+            }
+        }
+        best.map(|(color, _)| color)
+    }
+
+    /// The color closest to `self` in HSL lightness, with the same hue and
+    /// saturation, that has at least `target_ratio` contrast against
+    /// `background`; `self` itself if it already does.
+    ///
+    /// Lightness moves away from the background: colors darker than it
+    /// get darker, lighter ones lighter. If even black (or white) isn't
+    /// enough, the color crosses over to the other side instead, and if
+    /// neither black nor white reaches the target, as for `21.0` against
+    /// mid-gray, there's no answer and this returns `None`. Very dark or
+    /// light results lose some saturation to rounding.
+    ///
+    /// ```
+    /// use colourss::Color;
+    ///
+    /// let white = Color::new(255, 255, 255);
+    /// let orange = Color::new(255, 102, 0);
+    /// let darker = orange.adjust_for_contrast(&white, 4.5).unwrap();
+    /// assert!(darker.contrast_ratio(&white) >= 4.5);
+    /// assert_eq!(white.adjust_for_contrast(&Color::new(128, 128, 128), 21.0), None);
+    /// ```
+    pub fn adjust_for_contrast(&self, background: &Color, target_ratio: f32) -> Option<Color> {
+        if self.contrast_ratio(background) >= target_ratio {
+            return Some(*self);
+        }
+        let (h, s, l) = rgb_to_hsl(self);
+        let meets = |l: f32| hsl_to_rgb(h, s, l).contrast_ratio(background) >= target_ratio;
+        let darker_first = self.relative_luminance() <= background.relative_luminance();
+        let ends = if darker_first { [0.0, 1.0] } else { [1.0, 0.0] };
+        let end = ends.into_iter().find(|&end| meets(end))?;
+
+        // the colors that pass are the ones past some lightness between
+        // `l` and `end`; find the first
+        let (mut fails, mut passes) = (l, end);
+        for _ in 0..24 {
+            let mid = (fails + passes) / 2.0;
+            if meets(mid) {
+                passes = mid;
+            } else {
+                fails = mid;
+            }
+        }
+        Some(hsl_to_rgb(h, s, passes))
+    }
+}
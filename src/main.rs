@@ -1,8 +1,8 @@
-use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
-use colourss::parse_color;
-use std::fs;
-use std::path::PathBuf;
+mod cli;
+
+use anyhow::Result;
+use clap::{CommandFactory, Parser, Subcommand};
+use std::process::ExitCode;
 
 /// A simple CSS color parser CLI
 #[derive(Parser, Debug)]
@@ -15,71 +15,183 @@ struct Cli {
 /// Defines the subcommands for the CLI
 #[derive(Subcommand, Debug)]
 enum Commands {
-    /// Parses a file line by line
-    Parse {
-        /// The file to parse
-        #[arg(value_name = "FILE")]
-        file: PathBuf,
-    },
+    /// Finds the nearest color keyword for each color
+    ///
+    /// Prints the closest CSS name and its distance (`--top N` for more
+    /// candidates). `-`, or no arguments with stdin piped, reads colors
+    /// from stdin. Exits with 1 if any color didn't parse.
+    Name(cli::name::NameArgs),
+    /// Lists the color keywords the parser knows
+    ///
+    /// Prints `name<TAB>#hex` for each one, with extras that aren't CSS
+    /// marked `nonstandard`.
+    Names(cli::names::NamesArgs),
+    /// Rewrites the colors in a stylesheet in one notation
+    ///
+    /// Prints the file with every color in `--to` notation, leaving
+    /// everything else as it was; `--write` changes the file instead and
+    /// `--check` exits with 1 if anything would change.
+    Normalize(cli::normalize::NormalizeArgs),
+    /// Parses files line by line, or colors given directly
+    ///
+    /// Each argument that names an existing file is read line by line;
+    /// `-` reads standard input, as does giving no arguments when stdin
+    /// is piped. Directories and glob patterns stand for the files in
+    /// them (`--recursive`, `--ext`). Anything else is parsed as a color.
+    /// Quote colors that contain spaces: `colourss parse "rgb(1, 2, 3)"`.
+    ///
+    /// Exits with 0 if everything parsed, 1 if anything failed and 2 if a
+    /// file couldn't be read (the others are still parsed).
+    Parse(cli::parse::ParseArgs),
+    /// Checks the WCAG contrast ratio between two colors
+    ///
+    /// Prints the ratio and whether it passes AA and AAA for normal and
+    /// large text. With `--require`, exits with 1 when normal text fails
+    /// that level.
+    Contrast(cli::contrast::ContrastArgs),
+    /// Checks the contrast of every color pair in a file
+    ///
+    /// Reads one text and background pair per line and prints each ratio
+    /// with its AA and AAA verdicts, then the worst pairs. With
+    /// `--require`, exits with 1 when any pair fails that level.
+    CheckContrast(cli::check_contrast::CheckContrastArgs),
+    /// Converts colors to another notation
+    ///
+    /// Prints each color as hex (the default), `rgb()`, `hsl()` or `hwb()`,
+    /// one per line. `-`, or no arguments with stdin piped, converts every
+    /// line of stdin. Exits with 1 if any color didn't parse.
+    Convert(cli::convert::ConvertArgs),
+    /// Removes duplicate colors from a list
+    ///
+    /// Reads one color per line from a file or stdin and prints the first
+    /// occurrence of each color. `--tolerance` also merges colors that
+    /// look the same.
+    Dedupe(cli::dedupe::DedupeArgs),
+    /// Compares two palette files
+    ///
+    /// Lists removed (`-`), added (`+`) and, with `--tolerance`, changed
+    /// colors. Exits with 1 if the palettes differ.
+    Diff(cli::diff::DiffArgs),
+    /// Samples a gradient through two or more colors
+    ///
+    /// Prints `--steps` evenly spaced colors from the first stop to the
+    /// last, one per line.
+    Gradient(cli::gradient::GradientArgs),
+    /// Mixes two colors, or prints the ramp between them
+    ///
+    /// Prints the mix as hex; `--steps N` prints N colors from the first
+    /// to the second instead, one per line.
+    Mix(cli::mix::MixArgs),
+    /// Prints random colors
+    ///
+    /// `--seed` makes the output reproducible; `--pastel`, `--dark` and
+    /// `--hue` limit which colors come up.
+    Random(cli::random::RandomArgs),
+    /// Parses colors interactively
+    ///
+    /// Shows each color typed at the prompt in every notation, with its
+    /// nearest name; `:help` lists the commands.
+    Repl(cli::repl::ReplArgs),
+    /// Finds the colors written anywhere in source files
+    ///
+    /// Prints `path:line:column: token -> #hex` for every color, for an
+    /// editor's quickfix list, or each color once with `--unique`.
+    Scan(cli::scan::ScanArgs),
+    /// Lists the fill, stroke and other colors in SVG files
+    ///
+    /// Prints `path:line:column: element attribute="value" -> #hex` for
+    /// every color attribute and inline style, or each color once with
+    /// `--unique`. Exits with 1 if a value didn't parse.
+    ScanSvg(cli::scan_svg::ScanSvgArgs),
+    /// Finds the colors in HTML styles and legacy color attributes
+    ///
+    /// Reads `style` attributes and `<style>` blocks as CSS, and `<font
+    /// color>`, `<body bgcolor>` and the like the way browsers do.
+    ScanHtml(cli::scan_html::ScanHtmlArgs),
+    /// Checks the colors in VS Code theme files
+    ///
+    /// Prints `path#pointer: value -> #hex` for every color in `colors`
+    /// and `tokenColors`. Exits with 1 if a value didn't parse.
+    ScanTheme(cli::scan_theme::ScanThemeArgs),
+    /// Builds a palette from a base color
+    ///
+    /// Harmonies (complementary, triadic, ...) or shade, tint and tone
+    /// ramps, one color per line.
+    Scheme(cli::scheme::SchemeArgs),
+    /// Shows colors as swatches in the terminal
+    ///
+    /// Needs a truecolor terminal (`COLORTERM=truecolor`); elsewhere only
+    /// the text is printed unless `--preview always` is given.
+    Show(cli::show::ShowArgs),
+    /// Sorts a list of colors
+    ///
+    /// Reads one color per line from a file or stdin and prints the lines
+    /// reordered, as written unless `--normalize` is given.
+    Sort(cli::sort::SortArgs),
+    /// Summarizes a color file
+    ///
+    /// Counts parse results and notations, and shows the most frequent
+    /// colors, a hue histogram and the lightest and darkest colors.
+    Stats(cli::stats::StatsArgs),
+    /// Prints a shell completion script
+    ///
+    /// For bash: `colourss completions bash > /etc/bash_completion.d/colourss`,
+    /// or `source <(colourss completions bash)` in `~/.bashrc`.
+    Completions(cli::completions::CompletionsArgs),
     /// Shows author and license info
     Credits,
 }
 
-fn main() -> Result<()> {
+/// Exit status for errors that stop a command from running at all, like
+/// unreadable files. 1 means the command ran and some input was bad;
+/// clap also uses 2 for usage errors.
+const EXIT_ERROR: u8 = 2;
+
+fn main() -> ExitCode {
     let cli = Cli::parse();
 
-    match cli.command {
-        Commands::Parse { file } => {
-            parse_file(file)?;
-        }
-        Commands::Credits => {
-            show_credits();
+    match run(cli.command) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ExitCode::from(EXIT_ERROR)
         }
     }
-
-    Ok(())
 }
 
-// this function handles reading the file and parsing each line
-fn parse_file(file_path: PathBuf) -> Result<()> {
-    let content = fs::read_to_string(&file_path)
-        .with_context(|| format!("Could not read file `{:?}`", file_path))?;
-
-    println!("Parsing file: {:?}...", file_path);
-
-    let mut success_count = 0;
-    let mut fail_count = 0;
-
-    for (i, line) in content.lines().enumerate() {
-        let line_num = i + 1;
-        if line.trim().is_empty() {
-            continue; // skip empty lines
-        }
-
-        match parse_color(line) {
-            Ok(color) => {
-                println!(
-                    "  [Line {}] OK: '{}' -> Color(r: {}, g: {}, b: {})",
-                    line_num, line, color.r, color.g, color.b
-                );
-                success_count += 1;
-            }
-            Err(e) => {
-                println!("  [Line {}] FAIL: '{}' -> Error: {}", line_num, line, e);
-                fail_count += 1;
-            }
+fn run(command: Commands) -> Result<ExitCode> {
+    match command {
+        Commands::Name(args) => cli::name::run(args),
+        Commands::Names(args) => cli::names::run(args),
+        Commands::Normalize(args) => cli::normalize::run(args),
+        Commands::Parse(args) => cli::parse::run(args),
+        Commands::Contrast(args) => cli::contrast::run(args),
+        Commands::CheckContrast(args) => cli::check_contrast::run(args),
+        Commands::Convert(args) => cli::convert::run(args),
+        Commands::Dedupe(args) => cli::dedupe::run(args),
+        Commands::Diff(args) => cli::diff::run(args),
+        Commands::Gradient(args) => cli::gradient::run(args),
+        Commands::Mix(args) => cli::mix::run(args),
+        Commands::Random(args) => cli::random::run(args),
+        Commands::Repl(args) => cli::repl::run(args),
+        Commands::Scan(args) => cli::scan::run(args),
+        Commands::ScanSvg(args) => cli::scan_svg::run(args),
+        Commands::ScanHtml(args) => cli::scan_html::run(args),
+        Commands::ScanTheme(args) => cli::scan_theme::run(args),
+        Commands::Scheme(args) => cli::scheme::run(args),
+        Commands::Show(args) => cli::show::run(args),
+        Commands::Sort(args) => cli::sort::run(args),
+        Commands::Stats(args) => cli::stats::run(args),
+        Commands::Completions(args) => cli::completions::run(args, &mut Cli::command()),
+        Commands::Credits => {
+            show_credits();
+            Ok(ExitCode::SUCCESS)
         }
     }
-
-    println!(
-        "\nParsing complete. {} successful, {} failed.",
-        success_count, fail_count
-    );
-    Ok(())
 }
 
 fn show_credits() {
     println!("--- ColourSS v0.1.0 ---");
     println!("Written by: Maister Danylo");
     println!("License: MIT");
-}
\ No newline at end of file
+}
@@ -0,0 +1,27 @@
+//! The Material Design 2 color palette, behind the opt-in
+//! `material-names` feature: `red-500`, `deep-purple-a200`, `blue-grey-900`
+//! and the rest, as published. The data is in `data/material-colors.csv`.
+
+use crate::Color;
+
+include!(concat!(env!("OUT_DIR"), "/material_colors.rs"));
+
+/// Every Material palette color with its value, sorted by name: the 19
+/// hues by shade `50` to `900`, plus accents `a100` to `a700` for all but
+/// brown, grey and blue grey. Names are lowercase with `-` between words.
+pub fn material_colors() -> impl Iterator<Item = (&'static str, Color)> {
+    MATERIAL_COLORS.iter().copied()
+}
+
+/// The Material color `input` names, ignoring ASCII case, with any mix of
+/// `-` and spaces between words: `Deep Purple 500` is `deep-purple-500`.
+pub(crate) fn parse_material(input: &str) -> Option<Color> {
+    let name: String = input
+        .chars()
+        .map(|c| if c == ' ' { '-' } else { c.to_ascii_lowercase() })
+        .collect();
+    MATERIAL_COLORS
+        .binary_search_by_key(&name.as_str(), |(n, _)| n)
+        .ok()
+        .map(|i| MATERIAL_COLORS[i].1)
+}
@@ -0,0 +1,236 @@
+use crate::{Color, Oklab};
+
+/// The color space [`Color::mix_in`] and [`Gradient::in_space`](crate::Gradient::in_space)
+/// interpolate in, named as in CSS `color-mix(in <space>, ...)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum InterpSpace {
+    /// The gamma-encoded bytes, as [`Color::mix`].
+    #[default]
+    Srgb,
+    /// Linear light, as [`Color::mix_linear`].
+    LinearRgb,
+    /// Hue, saturation and lightness, as [`Color::mix_hsl`] with
+    /// [`HuePath::Shorter`].
+    Hsl { hue_path: HuePath },
+    /// [`Oklab`], as [`Color::mix_oklab`].
+    Oklab,
+    /// Oklab's lightness, chroma and hue. Keeps saturated colors
+    /// saturated half way, where Oklab goes through gray.
+    Oklch { hue_path: HuePath },
+}
+
+/// Which way around the wheel hues go in [`InterpSpace::Hsl`] and
+/// [`InterpSpace::Oklch`], following the CSS hue interpolation rules.
+/// A gray has no hue, so mixing with one keeps the other color's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HuePath {
+    /// The short way, at most 180°: from 350° to 10° through 0°.
+    #[default]
+    Shorter,
+    /// The long way, at least 180°: from 350° to 10° through 180°, and
+    /// all the way around for equal hues.
+    Longer,
+    /// Hue only goes up: from 350° to 10° through 0°, from 10° to 350°
+    /// through 180°.
+    Increasing,
+    /// Hue only goes down: from 350° to 10° through 180°.
+    Decreasing,
+}
+
+impl HuePath {
+    /// `h2` moved by whole turns so that going from `h1` to it takes this
+    /// path; both in degrees `0.0..360.0`.
+    fn unwrap_hue(self, h1: f32, h2: f32) -> f32 {
+        let d = h2 - h1;
+        match self {
+            HuePath::Shorter if d > 180.0 => h2 - 360.0,
+            HuePath::Shorter if d < -180.0 => h2 + 360.0,
+            HuePath::Longer if d > 0.0 && d < 180.0 => h2 - 360.0,
+            HuePath::Longer if d > -180.0 && d <= 0.0 => h2 + 360.0,
+            HuePath::Increasing if d < 0.0 => h2 + 360.0,
+            HuePath::Decreasing if d > 0.0 => h2 - 360.0,
+            _ => h2,
+        }
+    }
+
+    /// The hue `t` of the way from `h1` to `h2`; `None` for a hue means
+    /// the color is gray and takes the other's.
+    fn mix(self, h1: Option<f32>, h2: Option<f32>, t: f32) -> f32 {
+        match (h1, h2) {
+            (Some(h1), Some(h2)) => h1 + (self.unwrap_hue(h1, h2) - h1) * t,
+            (Some(h), None) | (None, Some(h)) => h,
+            (None, None) => 0.0,
+        }
+    }
+}
+
+/// Oklab chroma below which a color counts as gray, with no hue.
+const OKLCH_GRAY_CHROMA: f32 = 1e-4;
+
+impl Color {
+    /// Linearly interpolates between `self` and `other` channel by channel.
+    ///
+    /// `t` is clamped to `0.0..=1.0` (NaN counts as `0.0`); `t = 0.0` returns `self` and `t = 1.0`
+    /// returns `other` exactly. Interpolation happens directly on the
+    /// gamma-encoded sRGB bytes, which matches what most tools (and CSS
+    /// `color-mix(in srgb, ...)`) do but gives darker, muddier midpoints
+    /// than [`Color::mix_linear`].
+    pub fn mix(&self, other: &Color, t: f32) -> Color {
+        if t.is_nan() || t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *other;
+        }
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Color {
+            r: lerp(self.r, other.r),
+            g: lerp(self.g, other.g),
+            b: lerp(self.b, other.b),
+        }
+    }
+
+    /// Alias for [`Color::mix`].
+    pub fn lerp(&self, other: &Color, t: f32) -> Color {
+        self.mix(other, t)
+    }
+
+    /// Like [`Color::mix`], but interpolates in linear light.
+    ///
+    /// Both colors are decoded from sRGB, mixed, and encoded again, which
+    /// is the physically correct way to blend two lights and keeps
+    /// midpoints bright. Endpoints are exact, same as `mix`.
+    pub fn mix_linear(&self, other: &Color, t: f32) -> Color {
+        if t.is_nan() || t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *other;
+        }
+        let a = self.to_linear();
+        let b = other.to_linear();
+        (a + (b - a) * t).to_srgb()
+    }
+
+    /// Like [`Color::mix`], but interpolates hue, saturation and
+    /// lightness, as CSS `color-mix(in hsl, ...)` does.
+    ///
+    /// Hue takes the shorter way around the wheel. A gray has no hue of
+    /// its own, so mixing with one keeps the other color's hue instead of
+    /// swinging through red. Endpoints are exact.
+    pub fn mix_hsl(&self, other: &Color, t: f32) -> Color {
+        self.mix_in(InterpSpace::Hsl { hue_path: HuePath::Shorter }, other, t)
+    }
+
+    /// Mixes `self` and `other` in `space`, like CSS
+    /// `color-mix(in <space>, self, other t)`: `t = 0.0` returns `self` and
+    /// `t = 1.0` returns `other`, exactly, and `t` is clamped to
+    /// `0.0..=1.0` (NaN counts as `0.0`).
+    ///
+    /// ```
+    /// use colourss::{Color, HuePath, InterpSpace};
+    ///
+    /// let red = Color::new(255, 0, 0);
+    /// let blue = Color::new(0, 0, 255);
+    /// assert_eq!(red.mix_in(InterpSpace::Srgb, &blue, 0.5), Color::new(128, 0, 128));
+    /// assert_eq!(red.mix_in(InterpSpace::Oklab, &blue, 0.5), Color::new(140, 83, 162));
+    /// let longer = InterpSpace::Hsl { hue_path: HuePath::Longer };
+    /// assert_eq!(red.mix_in(longer, &blue, 0.5), Color::new(0, 255, 0));
+    /// ```
+    pub fn mix_in(&self, space: InterpSpace, other: &Color, t: f32) -> Color {
+        if t.is_nan() || t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *other;
+        }
+        match space {
+            InterpSpace::Srgb => self.mix(other, t),
+            InterpSpace::LinearRgb => self.mix_linear(other, t),
+            InterpSpace::Oklab => self.mix_oklab(other, t),
+            InterpSpace::Hsl { hue_path } => {
+                let (h1, s1, l1) = self.to_hsl();
+                let (h2, s2, l2) = other.to_hsl();
+                let hue = |h: f32, s: f32| (s > 0.0).then_some(h);
+                let h = hue_path.mix(hue(h1, s1), hue(h2, s2), t);
+                Color::from_hsl(h, s1 + (s2 - s1) * t, l1 + (l2 - l1) * t)
+            }
+            InterpSpace::Oklch { hue_path } => {
+                let lch = |lab: Oklab| {
+                    let c = lab.a.hypot(lab.b);
+                    (lab.l, c, (c >= OKLCH_GRAY_CHROMA).then(|| lab.b.atan2(lab.a).to_degrees().rem_euclid(360.0)))
+                };
+                let (l1, c1, h1) = lch(self.to_oklab());
+                let (l2, c2, h2) = lch(other.to_oklab());
+                let h = hue_path.mix(h1, h2, t).to_radians();
+                let c = c1 + (c2 - c1) * t;
+                Oklab { l: l1 + (l2 - l1) * t, a: c * h.cos(), b: c * h.sin() }.to_color()
+            }
+        }
+    }
+
+    /// Like [`Color::mix`], but interpolates in [`Oklab`],
+    /// which gives perceptually even steps (red to blue passes through
+    /// a purple instead of a dull violet). Endpoints are exact.
+    pub fn mix_oklab(&self, other: &Color, t: f32) -> Color {
+        if t.is_nan() || t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *other;
+        }
+        let a = self.to_oklab();
+        let b = other.to_oklab();
+        Oklab {
+            l: a.l + (b.l - a.l) * t,
+            a: a.a + (b.a - a.a) * t,
+            b: a.b + (b.b - a.b) * t,
+        }
+        .to_color()
+    }
+}
+
+/// The average of `colors` in linear light, or `None` for an empty slice.
+///
+/// Like [`Color::mix_linear`], this is what the colors look like blended
+/// together: black and white average to `#bcbcbc`, where averaging the
+/// bytes gives a darker `#808080`. For that byte average, which matches
+/// most other tools, see [`average_srgb`]. Copies of one color average to
+/// that color exactly.
+///
+/// ```
+/// use colourss::{average, Color};
+///
+/// let black_and_white = [Color::new(0, 0, 0), Color::new(255, 255, 255)];
+/// assert_eq!(average(&black_and_white).unwrap().to_hex(), "#bcbcbc");
+/// assert_eq!(average(&[]), None);
+/// ```
+pub fn average(colors: &[Color]) -> Option<Color> {
+    let (first, rest) = colors.split_first()?;
+    let sum = rest.iter().fold(first.to_linear(), |sum, c| sum + c.to_linear());
+    Some((sum / colors.len() as f32).to_srgb())
+}
+
+/// The average of the gamma-encoded bytes of `colors`, channel by
+/// channel and rounded, or `None` for an empty slice; see [`average`].
+///
+/// ```
+/// use colourss::{average_srgb, Color};
+///
+/// let black_and_white = [Color::new(0, 0, 0), Color::new(255, 255, 255)];
+/// assert_eq!(average_srgb(&black_and_white).unwrap().to_hex(), "#808080");
+/// ```
+pub fn average_srgb(colors: &[Color]) -> Option<Color> {
+    if colors.is_empty() {
+        return None;
+    }
+    let n = colors.len() as f32;
+    let (r, g, b) = colors.iter().fold((0u32, 0u32, 0u32), |(r, g, b), c| {
+        (r + c.r as u32, g + c.g as u32, b + c.b as u32)
+    });
+    Some(Color {
+        r: (r as f32 / n).round() as u8,
+        g: (g as f32 / n).round() as u8,
+        b: (b as f32 / n).round() as u8,
+    })
+}
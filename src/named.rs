@@ -0,0 +1,126 @@
+//! The color keywords, behind the default-on `named-colors` feature: a
+//! constant for each, like [`REBECCA_PURPLE`], and the table the parser
+//! looks names up in, which is built from the constants. Both are
+//! generated from `data/named-colors.csv` by the build script.
+//!
+//! ```
+//! use colourss::named::{REBECCA_PURPLE, STEEL_BLUE};
+//! use colourss::parse_color;
+//!
+//! assert_eq!(parse_color("rebeccapurple").unwrap(), REBECCA_PURPLE);
+//! assert_eq!(STEEL_BLUE.name(), Some("steelblue"));
+//! ```
+//!
+//! Builds without it (a wasm widget that only ever sees hex and `rgb()`)
+//! drop the table and everything that looks names up; the parser then
+//! reports every name as [`ParseError::UnknownColorName`].
+
+use crate::{Color, ParseError};
+
+impl Color {
+    /// The color keyword with exactly this value, if there is one.
+    pub fn name(&self) -> Option<&'static str> {
+        NAMES_BY_VALUE
+            .binary_search_by_key(self, |(color, _)| *color)
+            .ok()
+            .map(|i| NAMES_BY_VALUE[i].1)
+    }
+
+    /// The color keyword closest to this color, and how far away it is.
+    ///
+    /// Distance is the CIEDE2000 difference ([`Color::delta_e2000`]), so
+    /// `0.0` means an exact match and anything below about `1.0` looks
+    /// the same. `#4682b5` comes back as `("steelblue", 0.2..)`.
+    pub fn nearest_named(&self) -> (&'static str, f32) {
+        self.nearest_named_n(1)[0]
+    }
+
+    /// The `n` color keywords closest to this color, nearest first, with
+    /// their distances as in [`Color::nearest_named`]. Names that share a
+    /// value are listed separately.
+    pub fn nearest_named_n(&self, n: usize) -> Vec<(&'static str, f32)> {
+        let mut ranked: Vec<(&'static str, f32)> = NAMED_COLORS
+            .iter()
+            .map(|(name, color)| (*name, self.delta_e2000(color)))
+            .collect();
+        // stable, so names with equal distances stay alphabetical
+        ranked.sort_by(|a, b| a.1.total_cmp(&b.1));
+        ranked.truncate(n);
+        ranked
+    }
+}
+
+/// Defines a constant for each keyword and [`NAMED_COLORS`] from them,
+/// so the two can't disagree. A constant's name is its keyword in
+/// uppercase with `_` between words, which is checked when compiling.
+macro_rules! color_keywords {
+    ($($(#[$attr:meta])* $name:literal $constant:ident ($r:literal, $g:literal, $b:literal),)*) => {
+        $(
+            #[doc = concat!("`", $name, "`, `rgb(", $r, " ", $g, " ", $b, ")`.")]
+            $(#[$attr])*
+            pub const $constant: Color = Color::new($r, $g, $b);
+            const _: () = assert!(spelled_alike(stringify!($constant), $name));
+        )*
+
+        /// Every color keyword the parser accepts with its value, sorted by
+        /// name; the table [`parse_color`](crate::parse_color) looks names
+        /// up in.
+        ///
+        /// Where several names share a value (`aqua`/`cyan`, `gray`/`grey`)
+        /// the first one is what [`Color::name`] returns.
+        pub static NAMED_COLORS: &[(&str, Color)] = &[$(($name, $constant)),*];
+    };
+}
+
+/// Whether `constant` is `name` in uppercase, give or take underscores.
+const fn spelled_alike(constant: &str, name: &str) -> bool {
+    let (constant, name) = (constant.as_bytes(), name.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    while i < constant.len() {
+        if constant[i] != b'_' {
+            if j == name.len() || constant[i] != name[j].to_ascii_uppercase() {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    j == name.len()
+}
+
+// the keywords themselves are in data/named-colors.csv; build.rs turns
+// them into a `color_keywords!` call, `NAMES_BY_VALUE` and
+// `NONSTANDARD_NAMES`
+include!(concat!(env!("OUT_DIR"), "/named_colors.rs"));
+
+/// Every color keyword the parser accepts with its value, sorted by name.
+///
+/// Besides the 148 CSS keywords this includes a few extras; see
+/// [`is_standard_name`].
+pub fn named_colors() -> impl Iterator<Item = (&'static str, Color)> {
+    NAMED_COLORS.iter().copied()
+}
+
+/// Returns `true` if the parser knows `name`, the nonstandard extras
+/// included. Case-insensitive.
+pub fn is_named_color(name: &str) -> bool {
+    let name = name.to_lowercase();
+    NAMED_COLORS.iter().any(|(n, _)| *n == name)
+}
+
+/// Returns `true` if `name` is a CSS color keyword, `false` for the
+/// parser's nonstandard extras (`coffee`) and for unknown names.
+/// Case-insensitive.
+pub fn is_standard_name(name: &str) -> bool {
+    is_named_color(name) && !NONSTANDARD_NAMES.contains(&name.to_lowercase().as_str())
+}
+
+/// Rule 4: Parse named colors
+pub(crate) fn parse_named(input: &str) -> Result<Color, ParseError> {
+    let name = input.to_lowercase();
+    NAMED_COLORS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, color)| *color)
+        .ok_or_else(|| ParseError::UnknownColorName(crate::snippet(input)))
+}
@@ -0,0 +1,47 @@
+use crate::{Color, LinearColor};
+
+/// A color in Oklab, Björn Ottosson's perceptual color space.
+///
+/// `l` is lightness in `0.0..=1.0`; `a` (green–red) and `b` (blue–yellow)
+/// stay within roughly ±0.4 for sRGB colors. Straight lines in Oklab
+/// look like even steps, which makes it a good space to mix in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Oklab {
+    /// Converts back to sRGB, clamping colors that fall outside the gamut.
+    pub fn to_color(&self) -> Color {
+        let (l, a, b) = (self.l as f64, self.a as f64, self.b as f64);
+        let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+        LinearColor {
+            r: (4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_) as f32,
+            g: (-1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_) as f32,
+            b: (-0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_) as f32,
+        }
+        .to_srgb()
+    }
+}
+
+impl Color {
+    /// Converts to Oklab via linear sRGB.
+    pub fn to_oklab(&self) -> Oklab {
+        let lin = self.to_linear();
+        let (r, g, b) = (lin.r as f64, lin.g as f64, lin.b as f64);
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        Oklab {
+            l: (0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s) as f32,
+            a: (1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s) as f32,
+            b: (0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s) as f32,
+        }
+    }
+}
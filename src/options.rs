@@ -0,0 +1,139 @@
+/// How forgiving the parser is.
+///
+/// `ParseOptions::default()` is exactly what [`parse_color`](crate::parse_color)
+/// does. [`ParseOptions::strict`] and [`ParseOptions::lenient`] are
+/// presets on either side of it; individual fields can be changed with
+/// struct update syntax:
+///
+/// ```
+/// use colourss::{parse_color_with, ParseOptions};
+///
+/// let options = ParseOptions { allow_bare_hex: true, ..ParseOptions::default() };
+/// assert_eq!(parse_color_with("ff6600", &options).unwrap().to_hex(), "#ff6600");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParseOptions {
+    /// Accept a mix of comma and space separators, like `rgb(1, 2 3)` or
+    /// `rgb(1, 2, 3 / 0.5)`.
+    pub mixed_separators: bool,
+    /// Reject `hsl(120, 100, 50)`: saturation and lightness must have `%`.
+    /// The error names the first one without it, as in `S: 100 (needs a %)`.
+    pub require_hsl_percent: bool,
+    /// Clamp alpha outside `0..=1` (or `0%..=100%`) instead of failing.
+    pub clamp_alpha: bool,
+    /// Clamp out-of-range channels, saturation and lightness, and wrap
+    /// hue into `0..360`, instead of failing. Also allows fractional
+    /// channels like `rgb(12.5, 0, 0)`, which are rounded.
+    pub clamp_components: bool,
+    /// Accept hex codes without the `#`, like `ff6600`: any 3, 4, 6 or 8
+    /// hex digits, so words like `facade` and `fed` become colors too.
+    /// No color keyword is spelled with hex digits only, so names keep
+    /// parsing as before.
+    pub allow_bare_hex: bool,
+    /// Accept hex codes written like a C or JavaScript literal,
+    /// `0xff6600` or `0xff660080` (alpha last, as in `#rrggbbaa`). Only 6
+    /// or 8 digits: `0xfff` reads as a small integer, not as `#fff`.
+    pub allow_0x_hex: bool,
+    /// More characters that separate `rgb()` and `hsl()` components the
+    /// way commas do, like the `;` in `rgb(255; 128; 0)` from locales
+    /// that use decimal commas. Everything that goes for commas goes for
+    /// them too, so `rgba(255; 128; 0; 0.5)` takes a fourth alpha.
+    pub extra_separators: &'static [char],
+    /// Ignore one comma after the last component, as in
+    /// `rgb(255, 0, 0,)`, instead of reporting an empty component.
+    pub allow_trailing_comma: bool,
+    /// Accept the `gray()` function from older CSS drafts: one channel,
+    /// a number `0..=255` or a percentage, for all of red, green and
+    /// blue, with an optional alpha after a `/` or a comma, as in
+    /// `gray(50% / 0.5)` or `gray(128, 0.5)`.
+    pub allow_gray_function: bool,
+    /// Which color names are known, and which wins where they disagree.
+    pub name_set: NameSet,
+}
+
+/// The color names [`ParseOptions::name_set`] reads.
+///
+/// X11's `rgb.txt` agrees with CSS on most names, but not on `gray`,
+/// `green`, `maroon` and `purple` (X11's `green` is CSS `lime`), and
+/// adds names with spaces, like `navajo white`, and numbered variants
+/// like `gray42` and `snow3`. The X11 names need the `x11-names`
+/// feature; without it [`NameSet::X11`] knows none and [`NameSet::Both`]
+/// is [`NameSet::Css`].
+///
+/// ```
+/// use colourss::{parse_color_with, NameSet, ParseOptions};
+///
+/// let css = ParseOptions::default();
+/// # #[cfg(feature = "named-colors")]
+/// assert_eq!(parse_color_with("green", &css).unwrap().to_hex(), "#008000");
+/// # #[cfg(feature = "x11-names")] {
+/// let x11 = ParseOptions { name_set: NameSet::X11, ..css };
+/// assert_eq!(parse_color_with("green", &x11).unwrap().to_hex(), "#00ff00");
+/// assert_eq!(parse_color_with("navajo white", &x11).unwrap().to_hex(), "#ffdead");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NameSet {
+    /// The CSS color keywords, as [`parse_color`](crate::parse_color) reads.
+    #[default]
+    Css,
+    /// Only the X11 names, as X resources and terminal themes read them.
+    X11,
+    /// The CSS keywords, then X11 names CSS doesn't have, so `green` is
+    /// CSS green but `gray42` and `navajo white` parse.
+    Both,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            mixed_separators: true,
+            require_hsl_percent: false,
+            clamp_alpha: true,
+            clamp_components: false,
+            allow_bare_hex: false,
+            allow_0x_hex: false,
+            extra_separators: &[],
+            allow_trailing_comma: false,
+            allow_gray_function: false,
+            name_set: NameSet::Css,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Only what the CSS spec allows: one kind of separator, `%` on
+    /// saturation and lightness, and errors for anything out of range.
+    pub fn strict() -> Self {
+        ParseOptions {
+            mixed_separators: false,
+            require_hsl_percent: true,
+            clamp_alpha: false,
+            clamp_components: false,
+            allow_bare_hex: false,
+            allow_0x_hex: false,
+            extra_separators: &[],
+            allow_trailing_comma: false,
+            allow_gray_function: false,
+            name_set: NameSet::Css,
+        }
+    }
+
+    /// Everything the default accepts, plus clamping of out-of-range
+    /// values, hex without `#` or with `0x`, `;` or `|` between
+    /// components, a trailing comma, and `gray()`.
+    pub fn lenient() -> Self {
+        ParseOptions {
+            mixed_separators: true,
+            require_hsl_percent: false,
+            clamp_alpha: true,
+            clamp_components: true,
+            allow_bare_hex: true,
+            allow_0x_hex: true,
+            extra_separators: &[';', '|'],
+            allow_trailing_comma: true,
+            allow_gray_function: true,
+            name_set: NameSet::Css,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Sort keys, for `sort_by_key` and ordered collections.
+
+use crate::hsl::rgb_to_hsl;
+use crate::Color;
+
+impl Color {
+    /// `(r, g, b)`, the same order `Ord` gives.
+    pub fn sort_key_rgb(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+
+    /// `(hue, lightness, saturation)`, around the wheel from red.
+    ///
+    /// Hue is `1 + ` the HSL hue in tenths of a degree, rounded, so
+    /// `1..=3601`; grays have no hue and get `0`, which puts them first,
+    /// dark to light, as [`Palette::sort_by_hue`](crate::Palette::sort_by_hue)
+    /// does. Lightness and saturation are scaled to `0..=255`.
+    pub fn sort_key_hue(&self) -> (u16, u8, u8) {
+        let (h, s, l) = rgb_to_hsl(self);
+        let hue = if s > 0.0 { 1 + (h * 10.0).round() as u16 } else { 0 };
+        (hue, (l * 255.0).round() as u8, (s * 255.0).round() as u8)
+    }
+
+    /// Relative luminance, darkest first; colors just as bright compare
+    /// equal.
+    pub fn sort_key_luminance(&self) -> impl Ord + Copy + use<> {
+        // for non-negative floats the bits sort like the values
+        self.relative_luminance().max(0.0).to_bits()
+    }
+}
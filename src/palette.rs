@@ -0,0 +1,224 @@
+use crate::hsl::rgb_to_hsl;
+use crate::{parse_color, Color, ParseError};
+
+/// An ordered list of colors.
+///
+/// Each entry can optionally carry a label (like the per-color names in a
+/// `.gpl` file), and the palette itself can have a name. Labels travel
+/// with their color through sorting and deduplication.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Palette {
+    colors: Vec<Color>,
+    labels: Vec<Option<String>>,
+    name: Option<String>,
+}
+
+impl Palette {
+    /// Creates an empty palette.
+    pub fn new() -> Palette {
+        Palette::default()
+    }
+
+    /// Parses every string, keeping the colors that parse.
+    ///
+    /// Returns the palette along with the index and error of every item
+    /// that failed, so nothing is silently dropped.
+    pub fn from_strs<I, S>(items: I) -> (Palette, Vec<(usize, ParseError)>)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut palette = Palette::new();
+        let mut errors = Vec::new();
+        for (i, item) in items.into_iter().enumerate() {
+            match parse_color(item.as_ref()) {
+                Ok(color) => palette.push(color),
+                Err(e) => errors.push((i, e)),
+            }
+        }
+        (palette, errors)
+    }
+
+    /// Appends a color.
+    pub fn push(&mut self, color: Color) {
+        self.colors.push(color);
+        self.labels.push(None);
+    }
+
+    /// Appends a color with a label.
+    pub fn push_labeled(&mut self, color: Color, label: impl Into<String>) {
+        self.colors.push(color);
+        self.labels.push(Some(label.into()));
+    }
+
+    /// The label of the color at `index`, if it has one.
+    pub fn label(&self, index: usize) -> Option<&str> {
+        self.labels.get(index)?.as_deref()
+    }
+
+    /// The palette's name, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Sets the palette's name.
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = Some(name.into());
+    }
+
+    /// Number of colors.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns `true` if the palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// The colors as a slice.
+    pub fn as_slice(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Iterates over the colors in order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Color> {
+        self.colors.iter()
+    }
+
+    /// Removes colors that are within `tolerance` of an earlier color.
+    ///
+    /// Distance is CIEDE2000 ([`Color::delta_e2000`]), so `0.0` only
+    /// removes exact duplicates and `1.0` removes colors nobody could tell
+    /// apart. The first occurrence is always the one kept.
+    pub fn dedup(&mut self, tolerance: f32) {
+        let mut colors: Vec<Color> = Vec::with_capacity(self.colors.len());
+        let mut labels = Vec::with_capacity(self.labels.len());
+        for (color, label) in self.colors.drain(..).zip(self.labels.drain(..)) {
+            if !colors.iter().any(|k| k.delta_e2000(&color) <= tolerance) {
+                colors.push(color);
+                labels.push(label);
+            }
+        }
+        self.colors = colors;
+        self.labels = labels;
+    }
+
+    /// The index and value of the color perceptually closest to `color`.
+    ///
+    /// Ties go to the earlier entry. Returns `None` for an empty palette.
+    pub fn nearest(&self, color: &Color) -> Option<(usize, Color)> {
+        let mut best: Option<(usize, f32)> = None;
+        for (i, c) in self.colors.iter().enumerate() {
+            let d = c.delta_e2000(color);
+            if best.is_none_or(|(_, bd)| d < bd) {
+                best = Some((i, d));
+            }
+        }
+        best.map(|(i, _)| (i, self.colors[i]))
+    }
+
+    /// Returns `true` if any color is within `tolerance` (CIEDE2000) of `color`.
+    pub fn contains_within(&self, color: &Color, tolerance: f32) -> bool {
+        self.colors.iter().any(|c| c.delta_e2000(color) <= tolerance)
+    }
+
+    /// Sorts by HSL hue, starting at red.
+    ///
+    /// Grays have no hue, so they all go first, dark to light. Colors with
+    /// the same hue are ordered by lightness. The sort is stable.
+    pub fn sort_by_hue(&mut self) {
+        self.sort_by(|a, b| {
+            let (ha, sa, la) = rgb_to_hsl(a);
+            let (hb, sb, lb) = rgb_to_hsl(b);
+            let key_a = (sa > 0.0, if sa > 0.0 { ha } else { 0.0 }, la);
+            let key_b = (sb > 0.0, if sb > 0.0 { hb } else { 0.0 }, lb);
+            key_a.partial_cmp(&key_b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    /// Sorts by relative luminance, darkest first. The sort is stable.
+    pub fn sort_by_luminance(&mut self) {
+        self.sort_by(|a, b| {
+            a.relative_luminance()
+                .partial_cmp(&b.relative_luminance())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    /// Sorts by HSL saturation, grays first. The sort is stable.
+    pub fn sort_by_saturation(&mut self) {
+        self.sort_by(|a, b| {
+            rgb_to_hsl(a)
+                .1
+                .partial_cmp(&rgb_to_hsl(b).1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    /// Stable sort with a custom comparison. Labels stay attached to
+    /// their colors.
+    pub fn sort_by(&mut self, mut compare: impl FnMut(&Color, &Color) -> std::cmp::Ordering) {
+        let mut entries: Vec<(Color, Option<String>)> =
+            self.colors.drain(..).zip(self.labels.drain(..)).collect();
+        entries.sort_by(|a, b| compare(&a.0, &b.0));
+        (self.colors, self.labels) = entries.into_iter().unzip();
+    }
+
+    /// Stable sort by a key computed once per color, for keys that are
+    /// expensive to get, like the nearest color keyword.
+    pub fn sort_by_cached_key<K: Ord>(&mut self, mut key: impl FnMut(&Color) -> K) {
+        let mut entries: Vec<(Color, Option<String>)> =
+            self.colors.drain(..).zip(self.labels.drain(..)).collect();
+        entries.sort_by_cached_key(|entry| key(&entry.0));
+        (self.colors, self.labels) = entries.into_iter().unzip();
+    }
+}
+
+impl From<Vec<Color>> for Palette {
+    fn from(colors: Vec<Color>) -> Palette {
+        Palette {
+            labels: vec![None; colors.len()],
+            colors,
+            name: None,
+        }
+    }
+}
+
+impl From<Palette> for Vec<Color> {
+    fn from(palette: Palette) -> Vec<Color> {
+        palette.colors
+    }
+}
+
+impl FromIterator<Color> for Palette {
+    fn from_iter<I: IntoIterator<Item = Color>>(iter: I) -> Palette {
+        Palette::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl Extend<Color> for Palette {
+    fn extend<I: IntoIterator<Item = Color>>(&mut self, iter: I) {
+        for color in iter {
+            self.push(color);
+        }
+    }
+}
+
+impl IntoIterator for Palette {
+    type Item = Color;
+    type IntoIter = std::vec::IntoIter<Color>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.colors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Palette {
+    type Item = &'a Color;
+    type IntoIter = std::slice::Iter<'a, Color>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.colors.iter()
+    }
+}
@@ -0,0 +1,169 @@
+//! Python bindings, enabled with the `python` feature.
+//!
+//! Build and install into the current virtualenv with `maturin develop`;
+//! the extension module is importable as `colourss`.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::hsl::rgb_to_hsl;
+use crate::{parse_color_alpha, Color, ParseError};
+
+fn value_error(e: ParseError) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+/// An sRGB color with 8-bit channels.
+#[pyclass(name = "Color", module = "colourss", frozen, eq)]
+#[derive(Clone, PartialEq)]
+pub struct PyColor(Color);
+
+#[pymethods]
+impl PyColor {
+    #[new]
+    fn new(r: u8, g: u8, b: u8) -> Self {
+        PyColor(Color { r, g, b })
+    }
+
+    /// Parses a CSS color string, raising `ValueError` on failure. Alpha
+    /// is discarded.
+    #[staticmethod]
+    fn parse(input: &str) -> PyResult<Self> {
+        parse_color_alpha(input).map(|(c, _)| PyColor(c)).map_err(value_error)
+    }
+
+    #[getter]
+    fn r(&self) -> u8 {
+        self.0.r
+    }
+
+    #[getter]
+    fn g(&self) -> u8 {
+        self.0.g
+    }
+
+    #[getter]
+    fn b(&self) -> u8 {
+        self.0.b
+    }
+
+    fn to_hex(&self) -> String {
+        self.0.to_hex()
+    }
+
+    /// `(hue in degrees, saturation, lightness)`, the last two in `0..1`.
+    fn to_hsl(&self) -> (f32, f32, f32) {
+        rgb_to_hsl(&self.0)
+    }
+
+    fn to_tuple(&self) -> (u8, u8, u8) {
+        (self.0.r, self.0.g, self.0.b)
+    }
+
+    /// The CSS keyword for this exact color, if there is one.
+    fn name(&self) -> Option<&'static str> {
+        self.0.name()
+    }
+
+    fn relative_luminance(&self) -> f32 {
+        self.0.relative_luminance()
+    }
+
+    fn is_dark(&self) -> bool {
+        self.0.is_dark()
+    }
+
+    fn is_light(&self) -> bool {
+        self.0.is_light()
+    }
+
+    fn lighten(&self, amount: f32) -> Self {
+        PyColor(self.0.lighten(amount))
+    }
+
+    fn darken(&self, amount: f32) -> Self {
+        PyColor(self.0.darken(amount))
+    }
+
+    fn saturate(&self, amount: f32) -> Self {
+        PyColor(self.0.saturate(amount))
+    }
+
+    fn desaturate(&self, amount: f32) -> Self {
+        PyColor(self.0.desaturate(amount))
+    }
+
+    fn rotate_hue(&self, degrees: f32) -> Self {
+        PyColor(self.0.rotate_hue(degrees))
+    }
+
+    fn invert(&self) -> Self {
+        PyColor(self.0.invert())
+    }
+
+    fn grayscale(&self) -> Self {
+        PyColor(self.0.grayscale())
+    }
+
+    fn sepia(&self) -> Self {
+        PyColor(self.0.sepia())
+    }
+
+    /// Interpolates towards `other` in sRGB; `t = 0` is `self`.
+    fn mix(&self, other: &PyColor, t: f32) -> Self {
+        PyColor(self.0.mix(&other.0, t))
+    }
+
+    /// Like `mix`, but in linear light.
+    fn mix_linear(&self, other: &PyColor, t: f32) -> Self {
+        PyColor(self.0.mix_linear(&other.0, t))
+    }
+
+    fn delta_e(&self, other: &PyColor) -> f32 {
+        self.0.delta_e2000(&other.0)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Color('{}')", self.0.to_hex())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_hex()
+    }
+}
+
+/// Parses a CSS color string into `(r, g, b, a)`, raising `ValueError`
+/// with the parser's message on failure.
+#[pyfunction]
+fn parse_color(input: &str) -> PyResult<(u8, u8, u8, f32)> {
+    let (c, a) = parse_color_alpha(input).map_err(value_error)?;
+    Ok((c.r, c.g, c.b, a))
+}
+
+/// Every color token in `text` as `(start, end, Color)`.
+///
+/// Offsets are Python string indices (code points), so
+/// `text[start:end]` is the matched token.
+#[pyfunction]
+fn find_colors(text: &str) -> Vec<(usize, usize, PyColor)> {
+    let mut chars = 0;
+    let mut byte = 0;
+    let mut to_index = |offset: usize| {
+        chars += text[byte..offset].chars().count();
+        byte = offset;
+        chars
+    };
+    crate::find_colors(text)
+        .into_iter()
+        .map(|m| (to_index(m.start), to_index(m.end), PyColor(m.color)))
+        .collect()
+}
+
+#[pymodule]
+#[pyo3(name = "colourss")]
+fn python_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyColor>()?;
+    m.add_function(wrap_pyfunction!(parse_color, m)?)?;
+    m.add_function(wrap_pyfunction!(find_colors, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+
+use crate::{average_srgb, Color, Oklab, Palette};
+
+/// Reduces `colors` to at most `n` representative colors using median cut.
+///
+/// All colors start in one box. The box with the widest spread on any
+/// single channel is repeatedly sorted along that channel and cut in two
+/// at the median, until there are `n` boxes or nothing left to split. The
+/// cut moves to the nearest change of value, so equal colors always share
+/// a box. Each box is then represented by the (rounded) average of its
+/// members.
+///
+/// The result is ordered by population, largest box first, with ties kept
+/// in split order, so the same input in the same order always gives the
+/// same palette. Fewer than `n` colors come back when the input doesn't
+/// have `n` distinct values.
+pub fn quantize(colors: &[Color], n: usize) -> Palette {
+    if colors.is_empty() || n == 0 {
+        return Palette::new();
+    }
+
+    let mut boxes: Vec<Vec<Color>> = vec![colors.to_vec()];
+    while boxes.len() < n {
+        // pick the box with the largest single-channel range
+        let Some((index, channel)) = boxes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, b)| widest_channel(b).map(|(ch, range)| (i, ch, range)))
+            .max_by(|a, b| a.2.cmp(&b.2).then(b.0.cmp(&a.0)))
+            .map(|(i, ch, _)| (i, ch))
+        else {
+            break; // every box holds a single distinct color
+        };
+
+        let mut members = std::mem::take(&mut boxes[index]);
+        members.sort_by_key(|c| channel_value(c, channel));
+
+        let values: Vec<u8> = members.iter().map(|c| channel_value(c, channel)).collect();
+        let split = median_split(&values);
+
+        let upper = members.split_off(split);
+        boxes[index] = members;
+        boxes.push(upper);
+    }
+
+    boxes.sort_by_key(|b| std::cmp::Reverse(b.len()));
+    // boxes are never empty
+    boxes.iter().filter_map(|b| average_srgb(b)).collect()
+}
+
+/// The `k` dominant colors of `colors` by k-means clustering, each with
+/// the share of `colors` in its cluster, largest share first.
+///
+/// Clustering happens in [`Oklab`], where distance follows perceived
+/// difference, so a cluster is colors that look alike rather than ones
+/// with close bytes. Centers are seeded with k-means++ from a fixed seed,
+/// so the same input in the same order always gives the same result,
+/// then refined for up to `max_iters` rounds or until no color changes
+/// cluster. When `colors` has at most `k` distinct values each is its own
+/// cluster; an empty slice or `k = 0` gives nothing.
+///
+/// This is slower than [`quantize`], which splits boxes of sRGB bytes
+/// once, but finds tighter clusters.
+///
+/// ```
+/// use colourss::{dominant_colors, Color};
+///
+/// let mut pixels = vec![Color::new(250, 0, 0); 30];
+/// pixels.extend(vec![Color::new(0, 0, 250); 10]);
+/// let dominant = dominant_colors(&pixels, 2, 10);
+/// assert_eq!(dominant[0], (Color::new(250, 0, 0), 0.75));
+/// assert_eq!(dominant[1], (Color::new(0, 0, 250), 0.25));
+/// ```
+pub fn dominant_colors(colors: &[Color], k: usize, max_iters: usize) -> Vec<(Color, f32)> {
+    // each distinct color once, with its count, in a fixed order
+    let mut counts: BTreeMap<Color, usize> = BTreeMap::new();
+    for color in colors {
+        *counts.entry(*color).or_default() += 1;
+    }
+    let total = colors.len() as f32;
+    if k == 0 || counts.is_empty() {
+        return Vec::new();
+    }
+    if counts.len() <= k {
+        let mut clusters: Vec<(Color, f32)> = counts.into_iter().map(|(c, n)| (c, n as f32 / total)).collect();
+        clusters.sort_by(|a, b| b.1.total_cmp(&a.1));
+        return clusters;
+    }
+
+    let points: Vec<([f64; 3], f64)> = counts
+        .iter()
+        .map(|(color, &n)| {
+            let lab = color.to_oklab();
+            ([lab.l as f64, lab.a as f64, lab.b as f64], n as f64)
+        })
+        .collect();
+    let distance = |p: &[f64; 3], q: &[f64; 3]| (0..3).map(|i| (p[i] - q[i]).powi(2)).sum::<f64>();
+    let nearest = |p: &[f64; 3], centers: &[[f64; 3]]| {
+        (0..centers.len()).min_by(|&i, &j| distance(p, &centers[i]).total_cmp(&distance(p, &centers[j]))).unwrap_or(0)
+    };
+
+    // k-means++: each new center is a color picked with odds by its count
+    // times its squared distance to the nearest center so far
+    let mut random = SplitMix64(0x636f_6c6f_7572_7373);
+    let mut pick = |weights: &[f64]| {
+        let mut target = random.next_f64() * weights.iter().sum::<f64>();
+        for (i, w) in weights.iter().enumerate() {
+            if target < *w {
+                return i;
+            }
+            target -= w;
+        }
+        weights.iter().rposition(|w| *w > 0.0).unwrap_or(0)
+    };
+    let counts: Vec<f64> = points.iter().map(|(_, n)| *n).collect();
+    let mut centers = vec![points[pick(&counts)].0];
+    while centers.len() < k {
+        let weights: Vec<f64> = points.iter().map(|(p, n)| n * distance(p, &centers[nearest(p, &centers)])).collect();
+        centers.push(points[pick(&weights)].0);
+    }
+
+    let mut assignment: Vec<usize> = points.iter().map(|(p, _)| nearest(p, &centers)).collect();
+    for _ in 0..max_iters {
+        let mut sums = vec![([0.0; 3], 0.0); k];
+        for ((p, n), &cluster) in points.iter().zip(&assignment) {
+            for (sum, v) in sums[cluster].0.iter_mut().zip(p) {
+                *sum += v * n;
+            }
+            sums[cluster].1 += n;
+        }
+        // a cluster that lost every member keeps its center
+        for (center, (sum, n)) in centers.iter_mut().zip(&sums) {
+            if *n > 0.0 {
+                *center = sum.map(|v| v / n);
+            }
+        }
+        let next: Vec<usize> = points.iter().map(|(p, _)| nearest(p, &centers)).collect();
+        if next == assignment {
+            break;
+        }
+        assignment = next;
+    }
+
+    let mut shares = vec![0.0; k];
+    for ((_, n), &cluster) in points.iter().zip(&assignment) {
+        shares[cluster] += n;
+    }
+    let mut clusters: Vec<(Color, f32)> = centers
+        .iter()
+        .zip(shares)
+        .filter(|(_, n)| *n > 0.0)
+        .map(|(&[l, a, b], n)| (Oklab { l: l as f32, a: a as f32, b: b as f32 }.to_color(), (n / total as f64) as f32))
+        .collect();
+    clusters.sort_by(|a, b| b.1.total_cmp(&a.1));
+    clusters
+}
+
+/// SplitMix64, for k-means++ seeding without the `rand` feature.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Uniform in `0.0..1.0`.
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Where to cut sorted `values`, which aren't all equal: the median, or
+/// the nearest index to it between two different values (the lower one
+/// on a tie), so both sides are non-empty.
+fn median_split(values: &[u8]) -> usize {
+    let mid = values.len() / 2;
+    let changes = |i: &usize| values[*i] != values[*i - 1];
+    let below = (1..=mid).rev().find(changes);
+    let above = (mid.max(1)..values.len()).find(changes);
+    match (below, above) {
+        (Some(below), Some(above)) if above - mid < mid - below => above,
+        (Some(below), _) => below,
+        (None, above) => above.unwrap_or(mid),
+    }
+}
+
+/// The channel (0 = r, 1 = g, 2 = b) with the largest range and that
+/// range, or `None` if every color in the box is identical.
+fn widest_channel(colors: &[Color]) -> Option<(usize, u8)> {
+    let best = (0..3)
+        .map(|ch| {
+            let (min, max) = colors.iter().fold((u8::MAX, u8::MIN), |(lo, hi), c| {
+                let v = channel_value(c, ch);
+                (lo.min(v), hi.max(v))
+            });
+            (ch, max - min)
+        })
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))?;
+    (best.1 > 0).then_some(best)
+}
+
+fn channel_value(color: &Color, channel: usize) -> u8 {
+    match channel {
+        0 => color.r,
+        1 => color.g,
+        _ => color.b,
+    }
+}
@@ -0,0 +1,58 @@
+use crate::Color;
+
+const BLACK: Color = Color { r: 0, g: 0, b: 0 };
+const WHITE: Color = Color { r: 255, g: 255, b: 255 };
+const MID_GRAY: Color = Color { r: 128, g: 128, b: 128 };
+
+/// Builds `n` evenly spaced steps from `from` to `to`.
+///
+/// The first step is exactly `from` and the last is exactly `to`.
+fn ramp(from: &Color, to: &Color, n: usize, mix: fn(&Color, &Color, f32) -> Color) -> Vec<Color> {
+    match n {
+        0 => Vec::new(),
+        1 => vec![*from],
+        _ => (0..n)
+            .map(|i| mix(from, to, i as f32 / (n - 1) as f32))
+            .collect(),
+    }
+}
+
+/// Shade, tint and tone ramps.
+///
+/// Each method returns `n` colors starting at `self` and ending exactly at
+/// the target (black, white or `#808080`). The plain versions mix in sRGB,
+/// which spaces the steps fairly evenly to the eye and matches what design
+/// tools produce. The `_linear` versions mix in linear light instead: the
+/// steps are physically even, but shades darken slowly at first and tints
+/// wash out quickly.
+impl Color {
+    /// `n` steps mixing towards black.
+    pub fn shades(&self, n: usize) -> Vec<Color> {
+        ramp(self, &BLACK, n, Color::mix)
+    }
+
+    /// `n` steps mixing towards white.
+    pub fn tints(&self, n: usize) -> Vec<Color> {
+        ramp(self, &WHITE, n, Color::mix)
+    }
+
+    /// `n` steps mixing towards mid-gray (`#808080`).
+    pub fn tones(&self, n: usize) -> Vec<Color> {
+        ramp(self, &MID_GRAY, n, Color::mix)
+    }
+
+    /// Like [`Color::shades`], but mixing in linear light.
+    pub fn shades_linear(&self, n: usize) -> Vec<Color> {
+        ramp(self, &BLACK, n, Color::mix_linear)
+    }
+
+    /// Like [`Color::tints`], but mixing in linear light.
+    pub fn tints_linear(&self, n: usize) -> Vec<Color> {
+        ramp(self, &WHITE, n, Color::mix_linear)
+    }
+
+    /// Like [`Color::tones`], but mixing in linear light.
+    pub fn tones_linear(&self, n: usize) -> Vec<Color> {
+        ramp(self, &MID_GRAY, n, Color::mix_linear)
+    }
+}
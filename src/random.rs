@@ -0,0 +1,46 @@
+//! Random colors, enabled with the `rand` feature.
+//!
+//! `rng.random::<Color>()` picks uniformly from all 16.7M colors;
+//! [`Color::random_hsl`] restricts the pick to a region of HSL space,
+//! which is how you get "random pastels" or "random dark blues".
+
+use std::ops::RangeInclusive;
+
+use rand::distr::{Distribution, StandardUniform};
+use rand::Rng;
+
+use crate::Color;
+
+impl Distribution<Color> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Color {
+        let [r, g, b] = rng.random::<[u8; 3]>();
+        Color { r, g, b }
+    }
+}
+
+impl Color {
+    /// A random color with hue, saturation and lightness drawn uniformly
+    /// from the given ranges.
+    ///
+    /// Hue is in degrees. A range whose start is past its end wraps
+    /// around 360, so `330.0..=30.0` gives reds on both sides of zero.
+    /// Saturation and lightness are in `0.0..=1.0` and clamped to it.
+    pub fn random_hsl<R: Rng + ?Sized>(
+        rng: &mut R,
+        hue: RangeInclusive<f32>,
+        saturation: RangeInclusive<f32>,
+        lightness: RangeInclusive<f32>,
+    ) -> Color {
+        let (start, mut end) = (*hue.start(), *hue.end());
+        if end < start {
+            end += 360.0;
+        }
+        let pick = |rng: &mut R, lo: f32, hi: f32| {
+            if lo < hi { rng.random_range(lo..=hi) } else { lo }
+        };
+        let h = pick(rng, start, end);
+        let s = pick(rng, saturation.start().clamp(0.0, 1.0), saturation.end().clamp(0.0, 1.0));
+        let l = pick(rng, lightness.start().clamp(0.0, 1.0), lightness.end().clamp(0.0, 1.0));
+        Color::from_hsl(h, s, l)
+    }
+}
@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use crate::{parse_color_alpha, parse_color_alpha_with, Color, ParseError, ParseOptions};
+
+/// A color with an alpha channel.
+///
+/// `r`, `g` and `b` are sRGB bytes like in [`Color`] (not premultiplied),
+/// `a` is the opacity in `0.0..=1.0`. Values outside that range are
+/// clamped wherever alpha is used.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: f32,
+}
+
+impl Rgba {
+    /// Parses `input` like [`parse_color_with`](crate::parse_color_with),
+    /// keeping its alpha.
+    pub fn parse_with(input: &str, options: &ParseOptions) -> Result<Rgba, ParseError> {
+        let (Color { r, g, b }, a) = parse_color_alpha_with(input, options)?;
+        Ok(Rgba { r, g, b, a })
+    }
+
+    /// The same color with alpha set to `a`.
+    pub fn with_a(&self, a: f32) -> Rgba {
+        Rgba { a, ..*self }
+    }
+
+    /// The channels as `[r, g, b, a]`, with alpha scaled to `0..=255`
+    /// like in an RGBA8 pixel buffer.
+    pub fn as_array(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, (self.alpha() * 255.0).round() as u8]
+    }
+
+    /// The channels as `(r, g, b, a)`, with `a` as it is.
+    pub fn to_tuple(&self) -> (u8, u8, u8, f32) {
+        (self.r, self.g, self.b, self.a)
+    }
+
+    /// The color without its alpha, as if it were opaque. Use
+    /// [`Rgba::flatten_on`] to show it over something instead.
+    pub fn to_color(&self) -> Color {
+        Color { r: self.r, g: self.g, b: self.b }
+    }
+
+    /// Porter–Duff source-over: composites `self` on top of an opaque background.
+    ///
+    /// Fully opaque colors come back unchanged and fully transparent ones
+    /// return `background` exactly. Like browsers, the math is done on
+    /// the sRGB values.
+    pub fn over(&self, background: &Color) -> Color {
+        let a = self.alpha();
+        if a >= 1.0 {
+            return Color {
+                r: self.r,
+                g: self.g,
+                b: self.b,
+            };
+        }
+        if a <= 0.0 {
+            return *background;
+        }
+        let channel = |s: u8, d: u8| (s as f32 * a + d as f32 * (1.0 - a)).round() as u8;
+        Color {
+            r: channel(self.r, background.r),
+            g: channel(self.g, background.g),
+            b: channel(self.b, background.b),
+        }
+    }
+
+    /// Porter–Duff source-over onto a background that may itself be translucent.
+    ///
+    /// The result's alpha is `as + ab * (1 - as)`. Compositing onto a fully
+    /// transparent background returns `self`, and the result of two
+    /// transparent colors is transparent black.
+    pub fn over_rgba(&self, background: &Rgba) -> Rgba {
+        let sa = self.alpha();
+        let ba = background.alpha();
+        if sa <= 0.0 && ba <= 0.0 {
+            return Rgba { r: 0, g: 0, b: 0, a: 0.0 };
+        }
+        if sa >= 1.0 || ba <= 0.0 {
+            return Rgba { a: sa, ..*self };
+        }
+        if sa <= 0.0 {
+            return Rgba { a: ba, ..*background };
+        }
+        let a = sa + ba * (1.0 - sa);
+        let channel = |s: u8, d: u8| {
+            ((s as f32 * sa + d as f32 * ba * (1.0 - sa)) / a).round() as u8
+        };
+        Rgba {
+            r: channel(self.r, background.r),
+            g: channel(self.g, background.g),
+            b: channel(self.b, background.b),
+            a,
+        }
+    }
+
+    /// Flattens the color onto `background` for display. Same as [`Rgba::over`].
+    pub fn flatten_on(&self, background: &Color) -> Color {
+        self.over(background)
+    }
+
+    /// Flattens the color onto white.
+    pub fn flatten_on_white(&self) -> Color {
+        self.over(&Color {
+            r: 255,
+            g: 255,
+            b: 255,
+        })
+    }
+
+    fn alpha(&self) -> f32 {
+        if self.a.is_nan() {
+            return 0.0;
+        }
+        self.a.clamp(0.0, 1.0)
+    }
+}
+
+/// An opaque `Rgba`, with `a` 1.0.
+impl From<Color> for Rgba {
+    fn from(color: Color) -> Self {
+        Rgba { r: color.r, g: color.g, b: color.b, a: 1.0 }
+    }
+}
+
+/// Parses any color [`parse_color`](crate::parse_color) accepts, keeping
+/// its alpha (`1.0` for formats without one).
+impl FromStr for Rgba {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (Color { r, g, b }, a) = parse_color_alpha(input)?;
+        Ok(Rgba { r, g, b, a })
+    }
+}
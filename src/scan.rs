@@ -0,0 +1,66 @@
+use crate::{parse_color_alpha, parse_named, Color};
+
+/// A color token found inside a larger piece of text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorMatch {
+    /// Byte offset where the token starts.
+    pub start: usize,
+    /// Byte offset just past the end of the token.
+    pub end: usize,
+    pub color: Color,
+    pub alpha: f32,
+}
+
+/// Finds every color token in `text`, in order of appearance.
+///
+/// Recognizes hex codes (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`),
+/// `rgb()`/`rgba()`/`hsl()`/`hsla()` calls and color keywords. Tokens
+/// have to stand on their own: `#fff` inside `#ffff0` or `red` inside
+/// `bored` or `red-500` don't count, and function calls that fail to
+/// parse are skipped.
+pub fn find_colors(text: &str) -> Vec<ColorMatch> {
+    let bytes = text.as_bytes();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let at_boundary = i == 0 || !is_ident(bytes[i - 1]);
+        if bytes[i] == b'#' && at_boundary && (i == 0 || bytes[i - 1] != b'&') {
+            let end = i + 1 + bytes[i + 1..].iter().take_while(|b| b.is_ascii_hexdigit()).count();
+            let digits = end - i - 1;
+            if matches!(digits, 3 | 4 | 6 | 8)
+                && !bytes.get(end).is_some_and(|&b| is_ident(b))
+                && let Ok((color, alpha)) = parse_color_alpha(&text[i..end])
+            {
+                found.push(ColorMatch { start: i, end, color, alpha });
+            }
+            i = end;
+        } else if bytes[i].is_ascii_alphabetic() && at_boundary {
+            let end = i + bytes[i..].iter().take_while(|&&b| is_ident(b)).count();
+            let word = &text[i..end];
+            if bytes.get(end) == Some(&b'(') && is_color_function(word) {
+                if let Some(close) = text[end..].find(')') {
+                    let call_end = end + close + 1;
+                    if let Ok((color, alpha)) = parse_color_alpha(&text[i..call_end]) {
+                        found.push(ColorMatch { start: i, end: call_end, color, alpha });
+                        i = call_end;
+                        continue;
+                    }
+                }
+            } else if let Ok(color) = parse_named(word) {
+                found.push(ColorMatch { start: i, end, color, alpha: 1.0 });
+            }
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    found
+}
+
+fn is_ident(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'-' || b == b'_'
+}
+
+fn is_color_function(word: &str) -> bool {
+    ["rgb", "rgba", "hsl", "hsla"].iter().any(|f| word.eq_ignore_ascii_case(f))
+}
@@ -0,0 +1,46 @@
+//! Hex-only (de)serialization for [`Color`] fields, with the `serde`
+//! feature:
+//!
+//! ```
+//! # #[derive(serde::Deserialize)]
+//! # struct Example {
+//! #[serde(with = "colourss::serde_hex")]
+//! brand: colourss::Color,
+//! # }
+//! ```
+//!
+//! Unlike the default `Deserialize`, which takes any CSS color, only
+//! `#rgb` and `#rrggbb` are accepted. Colors are written as `#rrggbb`
+//! either way.
+
+use std::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+
+use crate::{parse_color, Color};
+
+pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&color.to_hex())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    deserializer.deserialize_str(HexVisitor)
+}
+
+struct HexVisitor;
+
+impl Visitor<'_> for HexVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a hex color like #rrggbb")
+    }
+
+    fn visit_str<E: de::Error>(self, input: &str) -> Result<Color, E> {
+        if !input.starts_with('#') || !matches!(input.len(), 4 | 7) {
+            return Err(E::custom(format_args!("expected #rgb or #rrggbb, got {:?}", input)));
+        }
+        parse_color(input).map_err(E::custom)
+    }
+}
@@ -0,0 +1,41 @@
+//! `Serialize` and `Deserialize` for [`Color`], enabled with the `serde`
+//! feature.
+//!
+//! Colors are written as `#rrggbb` and read from any string
+//! [`parse_color`] understands, so a config file can say
+//! `accent = "steelblue"` or `accent = "hsl(210, 80%, 40%)"`; errors are
+//! the [`ParseError`](crate::ParseError), which names the input. Use
+//! [`serde_hex`](crate::serde_hex) on fields that should only take hex.
+
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{parse_color, Color};
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        deserializer.deserialize_str(ColorVisitor)
+    }
+}
+
+struct ColorVisitor;
+
+impl Visitor<'_> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a CSS color string")
+    }
+
+    fn visit_str<E: de::Error>(self, input: &str) -> Result<Color, E> {
+        parse_color(input).map_err(E::custom)
+    }
+}
@@ -0,0 +1,24 @@
+//! The Tailwind CSS v3 default palette, behind the opt-in
+//! `tailwind-names` feature: `slate-500`, `emerald-300`, `zinc-950` and
+//! the rest. The data is in `data/tailwind-colors.csv`.
+
+use crate::Color;
+
+include!(concat!(env!("OUT_DIR"), "/tailwind_colors.rs"));
+
+/// Every Tailwind palette color with its value, sorted by name: the 22
+/// hues by shade `50` to `950`, like `sky-500`.
+pub fn tailwind_colors() -> impl Iterator<Item = (&'static str, Color)> {
+    TAILWIND_COLORS.iter().copied()
+}
+
+/// The Tailwind color `input` names, ignoring ASCII case, with an optional
+/// `tailwind:` prefix for names the Material palette has too.
+pub(crate) fn parse_tailwind(input: &str) -> Option<Color> {
+    let name = input.to_ascii_lowercase();
+    let name = name.strip_prefix("tailwind:").unwrap_or(&name);
+    TAILWIND_COLORS
+        .binary_search_by_key(&name, |(n, _)| n)
+        .ok()
+        .map(|i| TAILWIND_COLORS[i].1)
+}
@@ -0,0 +1,152 @@
+//! The colors in VS Code and TextMate-style theme files, enabled with the
+//! `themes` feature (on with the CLI).
+//!
+//! [`extract`] reads the `colors` object of a VS Code color theme and the
+//! `foreground` and `background` of each `tokenColors[].settings`, and
+//! parses every value with [`parse_rgba`], so `#rrggbbaa` keeps its alpha.
+//! Everything else in the file (`name`, `type`, `scope`, `fontStyle`, ...)
+//! is ignored, and so are values in those places that aren't strings,
+//! like the `null` VS Code uses to reset a color.
+//!
+//! Themes are usually JSON with comments, so `//` and `/* */` comments and
+//! trailing commas are allowed.
+
+use thiserror::Error;
+
+use crate::{parse_rgba, ParseError, Rgba};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ThemeError {
+    /// serde_json's message, with the line and column.
+    #[error("Invalid JSON: {0}")]
+    InvalidJson(String),
+    #[error("Expected a JSON object at the top level")]
+    NotAnObject,
+}
+
+/// A color value found in a theme.
+#[derive(Debug)]
+pub struct ThemeColor {
+    /// Where the value is, as a JSON pointer (RFC 6901), like
+    /// `/colors/editor.background` or `/tokenColors/3/settings/foreground`.
+    pub pointer: String,
+    /// The value as written.
+    pub value: String,
+    /// The parsed color, or why `value` isn't one.
+    pub color: Result<Rgba, ParseError>,
+}
+
+/// Every color in the theme `json`, in the order they appear: first the
+/// `colors` object, then `tokenColors`.
+///
+/// Only malformed JSON and a top level that isn't an object are errors;
+/// values that don't parse come back with their [`ParseError`].
+///
+/// ```
+/// use colourss::themes::extract;
+///
+/// let theme = r##"{
+///     "name": "Example",
+///     "colors": { "editor.background": "#1e1e1e", "editor.foreground": "#d4d4d4cc" },
+///     // comments are fine
+///     "tokenColors": [{ "scope": "comment", "settings": { "foreground": "#6a995", "fontStyle": "italic" } }],
+/// }"##;
+/// let found = extract(theme).unwrap();
+/// assert_eq!(found.len(), 3);
+/// assert_eq!(found[1].pointer, "/colors/editor.foreground");
+/// assert_eq!(found[1].color.as_ref().unwrap().a, 0.8);
+/// assert_eq!(found[2].pointer, "/tokenColors/0/settings/foreground");
+/// assert!(found[2].color.is_err());
+/// ```
+pub fn extract(json: &str) -> Result<Vec<ThemeColor>, ThemeError> {
+    let theme: serde_json::Value =
+        serde_json::from_str(&strip_comments(json)).map_err(|e| ThemeError::InvalidJson(e.to_string()))?;
+    let theme = theme.as_object().ok_or(ThemeError::NotAnObject)?;
+
+    let mut found = Vec::new();
+    let mut add = |pointer: String, value: &serde_json::Value| {
+        if let Some(value) = value.as_str() {
+            found.push(ThemeColor { pointer, value: value.to_string(), color: parse_rgba(value) });
+        }
+    };
+    if let Some(colors) = theme.get("colors").and_then(|colors| colors.as_object()) {
+        for (key, value) in colors {
+            add(format!("/colors/{}", escape(key)), value);
+        }
+    }
+    // `tokenColors` can also be the path of a `.tmTheme` file
+    if let Some(rules) = theme.get("tokenColors").and_then(|rules| rules.as_array()) {
+        for (i, rule) in rules.iter().enumerate() {
+            let Some(settings) = rule.get("settings").and_then(|settings| settings.as_object()) else { continue };
+            for key in ["foreground", "background"] {
+                if let Some(value) = settings.get(key) {
+                    add(format!("/tokenColors/{}/settings/{}", i, key), value);
+                }
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// `key` escaped for a JSON pointer: `~` as `~0` and `/` as `~1`.
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// `json` without comments and trailing commas. Comments become a space
+/// for each byte, keeping their newlines, so error positions still point
+/// into the original text.
+fn strip_comments(json: &str) -> String {
+    let mut out = String::with_capacity(json.len());
+    let mut chars = json.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                out.push(c);
+                while let Some(c) = chars.next() {
+                    out.push(c);
+                    match c {
+                        '\\' => out.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                out.push(' ');
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    out.extend(std::iter::repeat_n(' ', c.len_utf8()));
+                    chars.next();
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                out.push_str("  ");
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                    } else {
+                        out.extend(std::iter::repeat_n(' ', c.len_utf8()));
+                    }
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+            }
+            '}' | ']' => {
+                let end = out.trim_end().len();
+                if out[..end].ends_with(',') {
+                    out.replace_range(end - 1..end, " ");
+                }
+                out.push(c);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,109 @@
+//! JavaScript bindings, enabled with the `wasm` feature.
+//!
+//! Build with `wasm-pack build --no-default-features --features wasm`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::hsl::rgb_to_hsl;
+use crate::{parse_color_alpha, Color};
+
+/// The result of `parse()` on the JS side.
+#[wasm_bindgen]
+pub struct ParsedColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: f32,
+    format: String,
+}
+
+#[wasm_bindgen]
+impl ParsedColor {
+    /// Which notation the input was written in: `hex`, `rgb`, `hsl` or `named`.
+    #[wasm_bindgen(getter)]
+    pub fn format(&self) -> String {
+        self.format.clone()
+    }
+}
+
+/// Parses a CSS color string, throwing with the `ParseError` message on failure.
+#[wasm_bindgen]
+pub fn parse(input: &str) -> Result<ParsedColor, JsError> {
+    let (color, a) = parse_color_alpha(input).map_err(|e| JsError::new(&e.to_string()))?;
+    Ok(ParsedColor {
+        r: color.r,
+        g: color.g,
+        b: color.b,
+        a,
+        format: detect_format(input).to_string(),
+    })
+}
+
+/// Parses `input` and re-serializes it as `target`.
+///
+/// `target` is one of `hex`, `rgb`, `hsl` or `named`. Alpha below 1 is
+/// kept (`#rrggbbaa`, `rgba()`, `hsla()`). `named` throws if the color
+/// has no exact keyword, and is unknown without the `named-colors`
+/// feature.
+#[wasm_bindgen]
+pub fn normalize(input: &str, target: &str) -> Result<String, JsError> {
+    let (color, a) = parse_color_alpha(input).map_err(|e| JsError::new(&e.to_string()))?;
+    match target {
+        "hex" => Ok(to_hex(&color, a)),
+        "rgb" => Ok(to_rgb(&color, a)),
+        "hsl" => Ok(to_hsl(&color, a)),
+        #[cfg(feature = "named-colors")]
+        "named" => color
+            .name()
+            .map(str::to_string)
+            .ok_or_else(|| JsError::new(&format!("No color name for {}", color.to_hex()))),
+        _ => Err(JsError::new(&format!("Unknown target format: {}", target))),
+    }
+}
+
+/// Every color keyword the parser knows.
+#[cfg(feature = "named-colors")]
+#[wasm_bindgen]
+pub fn names() -> Vec<String> {
+    crate::named::NAMED_COLORS.iter().map(|(name, _)| name.to_string()).collect()
+}
+
+fn detect_format(input: &str) -> &'static str {
+    let input = input.trim();
+    let head = input.get(..3).unwrap_or("").to_ascii_lowercase();
+    if input.starts_with('#') {
+        "hex"
+    } else if head == "rgb" {
+        "rgb"
+    } else if head == "hsl" {
+        "hsl"
+    } else {
+        "named"
+    }
+}
+
+fn to_hex(color: &Color, a: f32) -> String {
+    if a < 1.0 {
+        format!("{}{:02x}", color.to_hex(), (a * 255.0).round() as u8)
+    } else {
+        color.to_hex()
+    }
+}
+
+fn to_rgb(color: &Color, a: f32) -> String {
+    if a < 1.0 {
+        format!("rgba({}, {}, {}, {})", color.r, color.g, color.b, a)
+    } else {
+        format!("rgb({}, {}, {})", color.r, color.g, color.b)
+    }
+}
+
+fn to_hsl(color: &Color, a: f32) -> String {
+    let (h, s, l) = rgb_to_hsl(color);
+    let (h, s, l) = (h.round(), (s * 100.0).round(), (l * 100.0).round());
+    if a < 1.0 {
+        format!("hsla({}, {}%, {}%, {})", h, s, l, a)
+    } else {
+        format!("hsl({}, {}%, {}%)", h, s, l)
+    }
+}
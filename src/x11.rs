@@ -0,0 +1,25 @@
+//! The X11 color names from `rgb.txt`, behind the opt-in `x11-names`
+//! feature, for terminal themes and other X resources. Most agree with
+//! CSS; `gray`, `green`, `maroon` and `purple` don't, and X11 adds names
+//! like `navajo white` and `gray42`. See [`NameSet`](crate::NameSet).
+
+use crate::{snippet, Color, ParseError};
+
+include!(concat!(env!("OUT_DIR"), "/x11_colors.rs"));
+
+/// Every X11 color name with its value, sorted by name. Names are
+/// lowercase without spaces: `navajowhite` for `navajo white` and
+/// `NavajoWhite`, and `gray0` to `gray100` and `grey0` to `grey100`.
+pub fn x11_colors() -> impl Iterator<Item = (&'static str, Color)> {
+    X11_COLORS.iter().copied()
+}
+
+/// The X11 color `input` names, ignoring ASCII case and spaces, so
+/// `Navajo White` and `navajowhite` are the same color.
+pub(crate) fn parse_x11(input: &str) -> Result<Color, ParseError> {
+    let name: String = input.chars().filter(|&c| c != ' ').map(|c| c.to_ascii_lowercase()).collect();
+    X11_COLORS
+        .binary_search_by_key(&name.as_str(), |(n, _)| n)
+        .map(|i| X11_COLORS[i].1)
+        .map_err(|_| ParseError::UnknownColorName(snippet(input)))
+}
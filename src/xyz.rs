@@ -0,0 +1,123 @@
+use crate::Color;
+use crate::linear::LinearColor;
+
+/// A CIE standard illuminant used as reference white.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhitePoint {
+    /// Noon daylight, ~6504K. sRGB is defined relative to this one.
+    D65,
+    /// Horizon light, ~5003K. Used by ICC profiles and CSS `lab()`.
+    D50,
+}
+
+impl WhitePoint {
+    /// The white point's tristimulus values, normalized to `Y = 1.0`.
+    pub fn xyz(self) -> Xyz {
+        match self {
+            WhitePoint::D65 => Xyz {
+                x: 0.95047,
+                y: 1.0,
+                z: 1.08883,
+            },
+            WhitePoint::D50 => Xyz {
+                x: 0.96422,
+                y: 1.0,
+                z: 0.82521,
+            },
+        }
+    }
+}
+
+/// A color in CIE 1931 XYZ, with `Y = 1.0` for reference white.
+///
+/// The struct doesn't remember which white point it's relative to; that's
+/// up to the caller, and conversions take it as an argument.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Xyz {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+// Bradford cone response matrix and its inverse.
+const BRADFORD: [[f32; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+const BRADFORD_INV: [[f32; 3]; 3] = [
+    [0.9869929, -0.1470543, 0.1599627],
+    [0.4323053, 0.5183603, 0.0492912],
+    [-0.0085287, 0.0400428, 0.9684867],
+];
+
+fn mul(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+/// Linear sRGB to XYZ relative to D65.
+pub(crate) fn linear_to_xyz(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    (
+        0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+        0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+        0.0193339 * r + 0.119192 * g + 0.9503041 * b,
+    )
+}
+
+/// XYZ relative to D65 to linear sRGB.
+pub(crate) fn xyz_to_linear(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    (
+        3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+        -0.969266 * x + 1.8760108 * y + 0.041556 * z,
+        0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+    )
+}
+
+impl Xyz {
+    /// Chromatic adaptation from one white point to another (Bradford).
+    pub fn adapt(self, from: WhitePoint, to: WhitePoint) -> Xyz {
+        if from == to {
+            return self;
+        }
+        let src = mul(&BRADFORD, to_array(from.xyz()));
+        let dst = mul(&BRADFORD, to_array(to.xyz()));
+        let cone = mul(&BRADFORD, to_array(self));
+        let scaled = [
+            cone[0] * dst[0] / src[0],
+            cone[1] * dst[1] / src[1],
+            cone[2] * dst[2] / src[2],
+        ];
+        let [x, y, z] = mul(&BRADFORD_INV, scaled);
+        Xyz { x, y, z }
+    }
+
+    /// Converts XYZ relative to `white` back to an 8-bit sRGB color.
+    ///
+    /// Colors outside the sRGB gamut are clamped per channel.
+    pub fn to_color(&self, white: WhitePoint) -> Color {
+        let d65 = self.adapt(white, WhitePoint::D65);
+        let (r, g, b) = xyz_to_linear(d65.x, d65.y, d65.z);
+        LinearColor { r, g, b }.to_srgb()
+    }
+}
+
+fn to_array(xyz: Xyz) -> [f32; 3] {
+    [xyz.x, xyz.y, xyz.z]
+}
+
+impl Color {
+    /// Converts to CIE XYZ relative to `white`.
+    ///
+    /// The standard sRGB matrix gives XYZ relative to D65, since that's
+    /// sRGB's own white point. For D50 the result is then Bradford-adapted,
+    /// so sRGB white maps to the D50 white point rather than to D65.
+    pub fn to_xyz(&self, white: WhitePoint) -> Xyz {
+        let linear = self.to_linear();
+        let (x, y, z) = linear_to_xyz(linear.r, linear.g, linear.b);
+        Xyz { x, y, z }.adapt(WhitePoint::D65, white)
+    }
+}
@@ -0,0 +1,1454 @@
+#![cfg(feature = "cli")]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+fn colourss() -> Command {
+    Command::cargo_bin("colourss").unwrap()
+}
+
+#[test]
+fn test_parse_inline_args() {
+    colourss()
+        .args(["parse", "#ff6600", "rgb(1, 2, 3)", "tomatoes"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "[Arg 1] OK: '#ff6600' -> Color(r: 255, g: 102, b: 0)",
+        ))
+        // one argument, spaces and all
+        .stdout(predicate::str::contains(
+            "[Arg 2] OK: 'rgb(1, 2, 3)' -> Color(r: 1, g: 2, b: 3)",
+        ))
+        .stdout(predicate::str::contains("[Arg 3] FAIL: 'tomatoes'"))
+        .stdout(predicate::str::contains("2 successful, 1 failed."));
+
+    colourss().args(["parse", "red", "#00f"]).assert().success();
+}
+
+#[test]
+fn test_parse_file_and_inline_mixed() {
+    colourss()
+        .args(["parse", "tests/data/colors.txt", "navy"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Parsing file:"))
+        .stdout(predicate::str::contains("[Line 5] FAIL: 'not a color'"))
+        .stdout(predicate::str::contains("[Arg 2] OK: 'navy'"))
+        .stdout(predicate::str::contains("5 successful, 1 failed."));
+}
+
+#[test]
+fn test_parse_inline_flag() {
+    // with --inline an existing path is just a (bad) color
+    colourss()
+        .args(["parse", "--inline", "tests/data/colors.txt"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("[Arg 1] FAIL: 'tests/data/colors.txt'"));
+}
+
+#[test]
+fn test_parse_stdin() {
+    colourss()
+        .args(["parse", "-"])
+        .write_stdin("#ff0000\n\nnope\nrgb(0 0 255)\n")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Parsing stdin..."))
+        .stdout(predicate::str::contains("[Line 1] OK: '#ff0000'"))
+        .stdout(predicate::str::contains("[Line 3] FAIL: 'nope'"))
+        .stdout(predicate::str::contains("[Line 4] OK: 'rgb(0 0 255)'"))
+        .stdout(predicate::str::contains("2 successful, 1 failed."));
+}
+
+#[test]
+fn test_parse_stdin_without_args() {
+    // piped stdin with no arguments at all
+    colourss()
+        .arg("parse")
+        .write_stdin("navy\nteal\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 successful, 0 failed."));
+}
+
+// snapshot of the documented JSON schema; regenerate the files in
+// tests/data/snapshots only for deliberate schema changes
+#[test]
+fn test_parse_json_snapshot() {
+    for (format, snapshot) in [("json", "parse.json"), ("ndjson", "parse.ndjson")] {
+        let expected = std::fs::read_to_string(format!("tests/data/snapshots/{}", snapshot)).unwrap();
+        colourss()
+            .args(["parse", "--format", format, "tests/data/colors.txt", "rgb(1, 2, 3)"])
+            .assert()
+            .failure()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_parse_json_is_valid() {
+    let output = colourss()
+        .args(["parse", "--format", "json", "#abc", "nope"])
+        .output()
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let items = value.as_array().unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0]["hex"], "#aabbcc");
+    assert_eq!(items[1]["ok"], false);
+    assert_eq!(items[1]["error"], "Unknown color name: \"nope\"");
+    assert_eq!(items[2]["summary"]["failed"], 1);
+}
+
+#[test]
+fn test_parse_csv_quoting() {
+    colourss()
+        .args(["parse", "--format", "csv", "rgb(1, 2, 3)", "nope", "#abc"])
+        .assert()
+        .failure()
+        .stdout("line,input,hex,r,g,b\n1,\"rgb(1, 2, 3)\",#010203,1,2,3\n3,#abc,#aabbcc,170,187,204\n");
+
+    // quotes inside a quoted field are doubled
+    colourss()
+        .args(["parse", "--format", "csv", "--columns", "input,error", "--include-errors", "say \"hi\", ok"])
+        .assert()
+        .failure()
+        .stdout("input,error\n\"say \"\"hi\"\", ok\",\"Unknown color name: \"\"say \\\"\"hi\\\"\", ok\"\"\"\n");
+}
+
+#[test]
+fn test_parse_tsv() {
+    colourss()
+        .args(["parse", "--format", "tsv", "--columns", "input,hex", "--include-errors", "rgb(1,\t2, 3)", "x"])
+        .assert()
+        .failure()
+        .stdout("input\thex\terror\nrgb(1,\\t2, 3)\t#010203\t\nx\t\tUnknown color name: \"x\"\n");
+}
+
+#[test]
+fn test_parse_exit_codes() {
+    colourss().args(["parse", "red"]).assert().code(0);
+    colourss().args(["parse", "tests/data/colors.txt"]).assert().code(1);
+    colourss()
+        .args(["parse", "tests/data/does-not-exist.txt"])
+        .assert()
+        .code(1); // not a file, so it's parsed as a (bad) color
+    // bytes that aren't UTF-8 fail that line, not the whole input
+    colourss()
+        .args(["parse", "-"])
+        .write_stdin([b'r', b'e', b'd', b'\n', 0xff, 0xfe, b'\n', b'b', b'l', b'u', b'e', b'\n'].as_slice())
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("[Line 2] FAIL: '\u{fffd}\u{fffd}' -> Error: Input is not valid UTF-8: \"\u{fffd}\u{fffd}\""))
+        .stdout(predicate::str::contains("2 successful, 1 failed."));
+}
+
+#[test]
+fn test_parse_only_failures_and_quiet() {
+    colourss()
+        .args(["parse", "--only-failures", "tests/data/colors.txt", "navy", "nope"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("[Line 5] FAIL: 'not a color'"))
+        .stdout(predicate::str::contains("[Arg 3] FAIL: 'nope'"))
+        .stdout(predicate::str::contains("OK").not())
+        .stdout(predicate::str::contains("Parsing complete. 5 successful, 2 failed."));
+    colourss()
+        .args(["parse", "--only-failures", "--format", "ndjson", "tests/data/colors.txt"])
+        .assert()
+        .code(1)
+        .stdout(concat!(
+            "{\"line\":5,\"input\":\"not a color\",\"ok\":false,\"r\":null,\"g\":null,\"b\":null,\"hex\":null,\"error\":\"Unknown color name: \\\"not a color\\\"\"}\n",
+            "{\"summary\":{\"total\":5,\"ok\":4,\"failed\":1}}\n",
+        ));
+    colourss()
+        .args(["parse", "--only-failures", "--format", "csv", "--include-errors", "--columns", "line,input", "tests/data/colors.txt"])
+        .assert()
+        .stdout("line,input,error\n5,not a color,\"Unknown color name: \"\"not a color\"\"\"\n");
+
+    colourss()
+        .args(["parse", "-q", "tests/data/colors.txt"])
+        .assert()
+        .code(1)
+        .stdout("\nParsing complete. 4 successful, 1 failed.\n");
+    colourss()
+        .args(["parse", "--quiet", "--format", "json", "red", "blue"])
+        .assert()
+        .success()
+        .stdout("[\n  {\"summary\":{\"total\":2,\"ok\":2,\"failed\":0}}\n]\n");
+    for format in ["human", "json", "csv"] {
+        colourss().args(["parse", "-qq", "--format", format, "tests/data/colors.txt"]).assert().code(1).stdout("");
+    }
+    colourss().args(["parse", "-qq", "--fail-fast", "nope"]).assert().code(1).stdout("").stderr("");
+    colourss().args(["parse", "-qq", "red"]).assert().success().stdout("");
+}
+
+#[test]
+fn test_parse_html_report() {
+    let dir = std::env::temp_dir().join(format!("colourss-html-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let report = dir.join("report.html");
+    colourss()
+        .args(["parse", "--format", "html", "-o", report.to_str().unwrap()])
+        .args(["rgb(70, 130, 180)", "<script>alert('hi')</script>", "\" onmouseover=\"x"])
+        .assert()
+        .code(1)
+        .stdout("");
+    let html = std::fs::read_to_string(&report).unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>"), "{}", html);
+    assert!(html.contains("<p class=\"summary\">1 successful, 2 failed.</p>"), "{}", html);
+    assert!(html.contains(concat!(
+        "<tr><td>Arg 1</td><td><code>rgb(70, 130, 180)</code></td>",
+        "<td class=\"swatch\" style=\"background-color: #4682b4\"></td><td><code>#4682b4</code></td><td></td></tr>",
+    )), "{}", html);
+    assert!(html.contains("<td><code>&lt;script&gt;alert(&#39;hi&#39;)&lt;/script&gt;</code></td>"), "{}", html);
+    assert!(html.contains("<td><code>&quot; onmouseover=&quot;x</code></td>"), "{}", html);
+    assert!(!html.contains("<script>"), "{}", html);
+    assert!(html.ends_with("</html>\n"));
+
+    colourss()
+        .args(["stats", "--format", "html", "tests/data/colors.txt"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("<p class=\"summary\">5 lines: 4 parsed, 1 failed.</p>"))
+        .stdout(predicate::str::contains("<td class=\"swatch\" style=\"background-color: #0000ff\"></td><td><code>#0000ff</code></td><td>blue</td>"));
+    let stats = dir.join("stats.json");
+    colourss().args(["stats", "--json", "-o", stats.to_str().unwrap(), "tests/data/colors.txt"]).assert().success().stdout("");
+    assert!(std::fs::read_to_string(&stats).unwrap().contains("\"failed\": 1"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_parse_streams_large_input() {
+    let mut input = Vec::new();
+    for i in 0..200_000u32 {
+        input.extend_from_slice(format!("#{:06x}\n", i * 83).as_bytes());
+        if i == 123_456 {
+            input.extend_from_slice(b"caf\xe9\r\n");
+        }
+    }
+    colourss()
+        .args(["parse", "-", "--format", "csv", "--columns", "line,hex", "--include-errors"])
+        .write_stdin(input)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("\n123457,#9c5ac0,\n123458,,\"Input is not valid UTF-8: \"\"caf\u{fffd}\"\"\"\n123459,#9c5b13,\n"))
+        .stdout(predicate::str::ends_with("200001,#fd4b6d,\n"));
+}
+
+#[test]
+fn test_parse_jobs_match_sequential() {
+    let dir = std::env::temp_dir().join(format!("colourss-jobs-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let mut input = Vec::new();
+    for i in 0..100_000u32 {
+        match i % 997 {
+            0 => input.extend_from_slice(b"not-a-color\n"),
+            1 => input.extend_from_slice(b"\n"),
+            2 => input.extend_from_slice(b"r\xe9d\n"),
+            _ => input.extend_from_slice(format!("rgb({} {} {})\n", i % 256, i / 256 % 256, i * 7 % 256).as_bytes()),
+        }
+    }
+    let path = dir.join("big.txt");
+    std::fs::write(&path, &input).unwrap();
+    let path = path.to_str().unwrap();
+
+    let run = |args: &[&str], jobs: &str, stdin: bool| {
+        let mut cmd = colourss();
+        cmd.arg("parse").args(args).args(["--jobs", jobs]);
+        if stdin {
+            cmd.arg("-").write_stdin(input.clone());
+        } else {
+            cmd.arg(path);
+        }
+        let output = cmd.output().unwrap();
+        (output.status.code(), output.stdout, output.stderr)
+    };
+    let formats: [&[&str]; 5] = [
+        &[],
+        &["--format", "ndjson"],
+        &["--format", "csv", "--include-errors"],
+        &["--max-failures", "50"],
+        &["--fail-fast", "--format", "json"],
+    ];
+    for args in formats {
+        let sequential = run(args, "1", false);
+        assert_eq!(run(args, "4", false), sequential, "{:?}", args);
+    }
+    // stdin only uses the threads when --jobs is given
+    let (_, stdout, _) = run(&["--format", "ndjson"], "3", true);
+    assert_eq!(stdout, run(&["--format", "ndjson"], "1", false).1);
+    assert!(String::from_utf8(stdout).unwrap().ends_with("{\"summary\":{\"total\":99899,\"ok\":99697,\"failed\":202}}\n"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_parse_reports_lines_as_they_arrive() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::process::{Command as StdCommand, Stdio};
+
+    let mut child = StdCommand::new(env!("CARGO_BIN_EXE_colourss"))
+        .args(["parse", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut line = String::new();
+    stdout.read_line(&mut line).unwrap();
+    assert_eq!(line, "Parsing stdin...\n");
+
+    // the first result shows up while stdin is still open
+    stdin.write_all(b"red\n").unwrap();
+    stdin.flush().unwrap();
+    line.clear();
+    stdout.read_line(&mut line).unwrap();
+    assert!(line.contains("[Line 1] OK: 'red'"), "{:?}", line);
+
+    drop(stdin);
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn test_parse_directory() {
+    colourss()
+        .args(["parse", "tests/data/tree"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("  tests/data/tree/a.txt: [Line 2] OK: 'rgb(0 0 0)'"))
+        .stdout(predicate::str::contains("  tests/data/tree/b.css: [Line 2] FAIL: 'not-a-color'"))
+        .stdout(predicate::str::contains("  1 successful, 1 failed.\n"))
+        .stdout(predicate::str::contains("nested").not())
+        // hidden files are skipped, as `scan` skips them
+        .stdout(predicate::str::contains(".hidden.txt").not())
+        .stdout(predicate::str::contains("Parsing complete. 3 successful, 2 failed in 3 files."));
+}
+
+#[test]
+fn test_parse_recursive() {
+    colourss()
+        .args(["parse", "tests/data/tree", "--recursive", "--ext", "txt"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("tests/data/tree/nested/c.txt: [Line 1] OK"))
+        .stdout(predicate::str::contains("tests/data/tree/nested/latin1.txt: [Line 1] FAIL"))
+        .stdout(predicate::str::contains("b.css").not())
+        .stdout(predicate::str::contains("3 successful, 1 failed in 3 files."));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_parse_continues_after_unreadable_file() {
+    let dir = std::env::temp_dir().join(format!("colourss-unreadable-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("a.txt"), "red\n").unwrap();
+    // a dangling link is listed, but can't be opened
+    let _ = std::fs::remove_file(dir.join("b.txt"));
+    std::os::unix::fs::symlink(dir.join("missing"), dir.join("b.txt")).unwrap();
+    std::fs::write(dir.join("c.txt"), "blue\n").unwrap();
+
+    colourss()
+        .arg("parse")
+        .arg(&dir)
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("a.txt: [Line 1] OK: 'red'"))
+        .stdout(predicate::str::contains("c.txt: [Line 1] OK: 'blue'"))
+        .stdout(predicate::str::contains("2 successful, 0 failed in 3 files, 1 unreadable."))
+        .stderr(predicate::str::contains("b.txt"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_parse_globs() {
+    // one file matched, so no path prefixes
+    colourss()
+        .args(["parse", "tests/data/tree/*.txt"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("  [Line 1] OK: '#fff'"))
+        .stdout(predicate::str::contains("2 successful, 0 failed.\n"));
+    colourss()
+        .args(["parse", "tests/data/tree/*.txt", "tests/data/tree/*.xyz", "red"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("[Arg 3] OK: 'red'"))
+        .stderr(predicate::str::contains("No files match `tests/data/tree/*.xyz`"));
+}
+
+#[test]
+fn test_parse_many_files_machine_formats() {
+    let output = colourss()
+        .args(["parse", "tests/data/tree", "--format", "json"])
+        .output()
+        .unwrap();
+    let results: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(results[2]["file"], "tests/data/tree/b.css");
+    assert_eq!(results[5]["summary"]["files"], 3);
+    colourss()
+        .args(["parse", "tests/data/tree/a.txt", "tests/data/tree/b.css", "--format", "csv", "--columns", "line,hex"])
+        .assert()
+        .stdout("file,line,hex\ntests/data/tree/a.txt,1,#ffffff\ntests/data/tree/a.txt,2,#000000\ntests/data/tree/b.css,1,#000080\n");
+}
+
+#[test]
+fn test_parse_fail_fast() {
+    colourss()
+        .args(["parse", "--fail-fast", "tests/data/colors.txt", "navy"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("[Line 5] FAIL"))
+        .stdout(predicate::str::contains("[Line 6]").not())
+        .stdout(predicate::str::contains("'navy'").not())
+        .stdout(predicate::str::contains("3 successful, 1 failed."))
+        .stderr(predicate::str::contains("tests/data/colors.txt:5"));
+}
+
+#[test]
+fn test_parse_max_failures() {
+    colourss()
+        .args(["parse", "--max-failures", "2", "a", "red", "b", "c"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("[Arg 3] FAIL: 'b'"))
+        .stdout(predicate::str::contains("'c'").not())
+        .stderr(predicate::str::contains("Stopping after 2 failure(s), last at argument 3"));
+    colourss().args(["parse", "--max-failures", "0", "red"]).assert().code(2);
+}
+
+#[test]
+fn test_convert_notations() {
+    colourss()
+        .args(["convert", "rebeccapurple", "rgb(255 0 0 / 50%)"])
+        .assert()
+        .success()
+        .stdout("#663399\n#ff000080\n");
+    colourss()
+        .args(["convert", "--to", "hsl", "#663399"])
+        .assert()
+        .success()
+        .stdout("hsl(270 50% 40%)\n");
+    colourss()
+        .args(["convert", "--to", "hwb", "#663399"])
+        .assert()
+        .success()
+        .stdout("hwb(270 20% 40%)\n");
+    colourss()
+        .args(["convert", "--to", "rgb", "#abc8"])
+        .assert()
+        .success()
+        .stdout("rgb(170 187 204 / 0.533)\n");
+}
+
+#[test]
+fn test_convert_alpha_and_case() {
+    colourss()
+        .args(["convert", "--uppercase", "--alpha", "strip", "#abcdef80"])
+        .assert()
+        .success()
+        .stdout("#ABCDEF\n");
+    colourss()
+        .args(["convert", "--alpha", "force:50%", "--to", "rgb", "red"])
+        .assert()
+        .success()
+        .stdout("rgb(255 0 0 / 0.5)\n");
+    colourss()
+        .args(["convert", "--alpha", "force:1.5", "red"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn test_convert_stdin_and_errors() {
+    colourss()
+        .args(["convert", "--to", "rgb"])
+        .write_stdin("red\n\nnot a color\nblue\n")
+        .assert()
+        .code(1)
+        .stdout("rgb(255 0 0)\nrgb(0 0 255)\n")
+        .stderr(predicate::str::contains("Error: Unknown color name: \"not a color\""));
+}
+
+#[test]
+fn test_parse_preview_swatch() {
+    colourss()
+        .args(["parse", "--preview=always", "#0a141e", "nope"])
+        .assert()
+        .stdout(predicate::str::contains(
+            "Color(r: 10, g: 20, b: 30) \x1b[48;2;10;20;30m  \x1b[0m\n",
+        ))
+        .stdout(predicate::str::contains("FAIL: 'nope' -> Error: Unknown color name: \"nope\"\n"));
+
+    // not a terminal, so `auto` draws nothing
+    colourss()
+        .args(["parse", "--preview", "red"])
+        .env("COLORTERM", "truecolor")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn test_show_labels() {
+    colourss()
+        .args(["show", "--preview", "always", "navy", "yellow"])
+        .assert()
+        .success()
+        // white text on dark colors, black on light ones
+        .stdout(
+            "\x1b[48;2;0;0;128m\x1b[38;2;255;255;255m #000080 \x1b[0m  rgb(0 0 128)  navy\n\
+             \x1b[48;2;255;255;0m\x1b[38;2;0;0;0m #ffff00 \x1b[0m  rgb(255 255 0)  yellow\n",
+        );
+    colourss()
+        .args(["show", "#010203", "nope"])
+        .assert()
+        .code(1)
+        .stdout("#010203  rgb(1 2 3)\n")
+        .stderr(predicate::str::contains("Error: Unknown color name: \"nope\""));
+}
+
+#[test]
+fn test_contrast_black_white() {
+    colourss()
+        .args(["contrast", "black", "#fff"])
+        .assert()
+        .success()
+        .stdout(
+            "Contrast ratio: 21.00:1\n  AA normal   PASS\n  AA large    PASS\n  AAA normal  PASS\n  AAA large   PASS\n",
+        );
+
+    let output = colourss().args(["contrast", "--json", "#767676", "white"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["ratio"], 4.54);
+    assert_eq!(value["aa_normal"], true);
+    assert_eq!(value["aaa_normal"], false);
+}
+
+#[test]
+fn test_contrast_require_exit_codes() {
+    colourss().args(["contrast", "--require", "aaa", "black", "white"]).assert().success();
+    // 4.48:1 is fine for large text but fails AA for normal text
+    colourss()
+        .args(["contrast", "--require", "aa", "#777", "white"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("AA large    PASS"));
+    colourss().args(["contrast", "#777", "white"]).assert().success();
+    colourss()
+        .args(["contrast", "nope", "white"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Invalid foreground color\n\nCaused by:\n    Unknown color name: \"nope\""));
+}
+
+#[test]
+fn test_mix() {
+    colourss().args(["mix", "red", "blue"]).assert().success().stdout("#800080\n");
+    colourss()
+        .args(["mix", "red", "blue", "--ratio", "0.25"])
+        .assert()
+        .success()
+        .stdout("#bf0040\n");
+    colourss()
+        .args(["mix", "red", "blue", "--space", "oklab"])
+        .assert()
+        .success()
+        .stdout("#8c53a2\n");
+    colourss().args(["mix", "red", "nope"]).assert().code(2);
+}
+
+#[test]
+fn test_mix_steps() {
+    let output = colourss().args(["mix", "#000", "rgb(255 255 255)", "--steps", "5"]).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[0], "#000000");
+    assert_eq!(lines[2], "#808080");
+    assert_eq!(lines[4], "#ffffff");
+
+    colourss()
+        .args(["mix", "black", "white", "--steps", "2", "--preview=always"])
+        .assert()
+        .success()
+        .stdout("#000000 \x1b[48;2;0;0;0m  \x1b[0m\n#ffffff \x1b[48;2;255;255;255m  \x1b[0m\n");
+}
+
+#[test]
+fn test_name_nearest() {
+    colourss()
+        .args(["name", "#4682b5", "#4682b4"])
+        .assert()
+        .success()
+        .stdout("#4682b5: steelblue (ΔE 0.20)\n#4682b4: steelblue (exact)\n");
+    colourss()
+        .args(["name", "--top", "2", "#4682b5"])
+        .assert()
+        .success()
+        .stdout("#4682b5:\n  1. steelblue (ΔE 0.20)\n  2. dodgerblue (ΔE 8.24)\n");
+    colourss()
+        .arg("name")
+        .write_stdin("red\nnope\n")
+        .assert()
+        .code(1)
+        .stdout("red: red (exact)\n")
+        .stderr(predicate::str::contains("Error: Unknown color name: \"nope\""));
+}
+
+#[test]
+fn test_name_json() {
+    let output = colourss().args(["name", "--json", "--top", "3", "rgb(70 130 181)"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value[0]["input"], "rgb(70 130 181)");
+    assert_eq!(value[0]["hex"], "#4682b5");
+    let matches = value[0]["matches"].as_array().unwrap();
+    assert_eq!(matches.len(), 3);
+    assert_eq!(matches[0]["name"], "steelblue");
+    assert_eq!(matches[0]["hex"], "#4682b4");
+    assert_eq!(matches[0]["exact"], false);
+}
+
+#[test]
+fn test_names_snapshot() {
+    let expected = std::fs::read_to_string("tests/data/snapshots/names-blue-hue.txt").unwrap();
+    colourss()
+        .args(["names", "--filter", "Blue", "--sort", "hue"])
+        .assert()
+        .success()
+        .stdout(expected);
+}
+
+#[test]
+fn test_names_listing() {
+    let output = colourss().arg("names").output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 149);
+    assert!(stdout.starts_with("aliceblue\t#f0f8ff\n"));
+    assert!(stdout.contains("\ncoffee\t#c0ffee\tnonstandard\n"));
+
+    colourss()
+        .args(["names", "--filter", "rebecca", "--preview=always"])
+        .assert()
+        .success()
+        .stdout("rebeccapurple\t#663399\t\x1b[48;2;102;51;153m  \x1b[0m\n");
+}
+
+// scripts rely on seeded output staying the same between releases
+#[test]
+fn test_random_seeded() {
+    colourss()
+        .args(["random", "--seed", "42", "--count", "3"])
+        .assert()
+        .success()
+        .stdout("#a1b588\n#c68c08\n#52f9b0\n");
+    colourss()
+        .args(["random", "--seed", "42", "--count", "2", "--pastel", "--format", "hsl"])
+        .assert()
+        .success()
+        .stdout("hsl(80.77 67.24% 77.25%)\nhsl(342.09 70.53% 81.37%)\n");
+}
+
+#[test]
+fn test_random_options() {
+    let output = colourss().args(["random", "--count", "50", "--dark", "--hue", "200-260"]).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 50);
+    for line in stdout.lines() {
+        let color = colourss::parse_color(line).unwrap();
+        assert!(color.is_dark(), "{}", line);
+    }
+    colourss().args(["random", "--hue", "400"]).assert().code(2);
+    colourss().args(["random", "--pastel", "--dark"]).assert().code(2);
+}
+
+#[test]
+fn test_scheme_triadic() {
+    colourss()
+        .args(["scheme", "#ff0000", "--kind", "triadic"])
+        .assert()
+        .success()
+        .stdout("#ff0000\n#00ff00\n#0000ff\n");
+    colourss()
+        .args(["scheme", "red", "--kind", "complementary", "--format", "rgb"])
+        .assert()
+        .success()
+        .stdout("rgb(255 0 0)\nrgb(0 255 255)\n");
+}
+
+#[test]
+fn test_scheme_ramps_and_json() {
+    let output = colourss().args(["scheme", "#1e3a8a", "--kind", "tints", "--count", "5"]).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[0], "#1e3a8a");
+    assert_eq!(lines[4], "#ffffff");
+
+    let output = colourss().args(["scheme", "red", "--kind", "shades", "--count", "2", "--json"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!([
+            { "hex": "#ff0000", "hsl": "hsl(0 100% 50%)" },
+            { "hex": "#000000", "hsl": "hsl(0 0% 0%)" },
+        ])
+    );
+}
+
+#[test]
+fn test_gradient_steps() {
+    let output = colourss().args(["gradient", "#000", "#fff", "--steps", "16"]).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 16);
+    assert_eq!(lines[0], "#000000");
+    assert_eq!(lines[1], "#111111");
+    assert_eq!(lines[15], "#ffffff");
+
+    colourss()
+        .args(["gradient", "red", "yellow", "green", "--positions", "0,0.3,1", "--steps", "4"])
+        .assert()
+        .success()
+        .stdout("#ff0000\n#f3f900\n#79bc00\n#008000\n");
+    colourss()
+        .args(["gradient", "red", "blue", "--space", "oklab", "--steps", "3"])
+        .assert()
+        .success()
+        .stdout("#ff0000\n#8c53a2\n#0000ff\n");
+    colourss()
+        .args(["gradient", "red", "blue", "--positions", "0"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("got 1 positions for 2 colors"));
+    for positions in ["0.5,nan", "nan,0.5", "0,inf", "-inf,1"] {
+        colourss()
+            .args(["gradient", "red", "blue", &format!("--positions={}", positions)])
+            .assert()
+            .code(2)
+            .stdout("")
+            .stderr(predicate::str::contains("is not a finite number"));
+    }
+}
+
+#[test]
+fn test_sort_keeps_notation() {
+    colourss()
+        .args(["sort", "--by", "hue", "tests/data/shuffled.txt"])
+        .assert()
+        .success()
+        .stdout("black\ngray\nwhite\nrgb(255 0 0)\nhsl(120 100% 25%)\n#00f\n");
+    colourss()
+        .args(["sort", "--by", "luminance", "--normalize", "hex", "tests/data/shuffled.txt"])
+        .assert()
+        .success()
+        .stdout("#000000\n#0000ff\n#008000\n#ff0000\n#808080\n#ffffff\n");
+}
+
+#[test]
+fn test_sort_invalid_lines() {
+    colourss()
+        .arg("sort")
+        .write_stdin("red\nnope\nblue\n")
+        .assert()
+        .code(1)
+        .stdout("")
+        .stderr(predicate::str::contains("line 2: Unknown color name: \"nope\""));
+    colourss()
+        .args(["sort", "--keep-invalid", "--by", "name", "-"])
+        .write_stdin("red\nnope\nblue\n")
+        .assert()
+        .success()
+        .stdout("blue\nred\nnope\n");
+}
+
+#[test]
+fn test_dedupe_exact() {
+    colourss()
+        .args(["dedupe", "tests/data/duplicates.txt"])
+        .assert()
+        .code(1)
+        .stdout("#ff0000\n# merged: rgb(255,0,0), red\nblue\n#fe0000\n#0000fe\n")
+        .stderr(predicate::str::contains("line 6: Unknown color name: \"nope\""));
+}
+
+#[test]
+fn test_dedupe_tolerance() {
+    colourss()
+        .args(["dedupe", "--tolerance", "1", "--count", "-"])
+        .write_stdin("#ff0000\nblue\nrgb(255,0,0)\n#fe0000\nred\n#0000fe\n")
+        .assert()
+        .success()
+        .stdout("4\t#ff0000\n2\tblue\n");
+}
+
+#[test]
+fn test_stats_snapshot() {
+    let expected = std::fs::read_to_string("tests/data/snapshots/stats.txt").unwrap();
+    colourss().args(["stats", "tests/data/tokens.txt"]).assert().success().stdout(expected);
+}
+
+#[test]
+fn test_css_wide_keywords_are_skipped() {
+    colourss()
+        .args(["parse", "-"])
+        .write_stdin("red\ninherit\nnope\nINITIAL\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("[Line 2] SKIP: 'inherit' -> \"inherit\" is a CSS-wide keyword, not a color"))
+        .stdout(predicate::str::contains("[Line 4] SKIP: 'INITIAL'"))
+        .stdout(predicate::str::contains("[Line 3] FAIL: 'nope'"))
+        .stdout(predicate::str::contains("Parsing complete. 1 successful, 1 failed, 2 skipped."));
+    // only skipped lines still exit with 0
+    colourss()
+        .args(["parse", "--format", "ndjson", "-"])
+        .write_stdin("red\nunset\n")
+        .assert()
+        .success()
+        .stdout(concat!(
+            "{\"line\":1,\"input\":\"red\",\"ok\":true,\"r\":255,\"g\":0,\"b\":0,\"hex\":\"#ff0000\",\"error\":null}\n",
+            "{\"line\":2,\"input\":\"unset\",\"ok\":false,\"r\":null,\"g\":null,\"b\":null,\"hex\":null,\"error\":\"\\\"unset\\\" is a CSS-wide keyword, not a color\",\"skipped\":true}\n",
+            "{\"summary\":{\"total\":2,\"ok\":1,\"failed\":0,\"skipped\":1}}\n",
+        ));
+    colourss()
+        .args(["parse", "--only-failures", "--max-failures", "1", "-"])
+        .write_stdin("revert\nrevert-layer\nnope\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("SKIP").not())
+        .stdout(predicate::str::contains("Parsing complete. 0 successful, 1 failed, 2 skipped."));
+
+    let output = colourss().args(["stats", "--json", "-"]).write_stdin("red\ninherit\nnope\n").output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!((&value["ok"], &value["failed"], &value["skipped"]), (&1.into(), &1.into(), &1.into()));
+    colourss()
+        .args(["stats", "-"])
+        .write_stdin("red\ninherit\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("Lines: 2 (1 parsed, 0 failed, 1 skipped)\n"));
+}
+
+#[test]
+fn test_stats_json() {
+    let output = colourss().args(["stats", "--json", "--top", "1", "tests/data/tokens.txt"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["lines"], 12);
+    assert_eq!(value["failed"], 1);
+    assert_eq!(value["formats"]["hex-long"], 5);
+    assert_eq!(value["formats"]["rgb-modern"], 1);
+    assert_eq!(value["most_frequent"], serde_json::json!([{ "hex": "#ff0000", "count": 5 }]));
+    assert_eq!(value["hues"].as_array().unwrap().len(), 12);
+    assert_eq!(value["grays"], 2);
+    assert_eq!(value["lightest"], "#ffffff");
+}
+
+#[test]
+fn test_diff_listing() {
+    colourss()
+        .args(["diff", "tests/data/diff/old.txt", "tests/data/diff/new.txt"])
+        .assert()
+        .code(1)
+        .stdout(concat!(
+            "--- tests/data/diff/old.txt\n",
+            "+++ tests/data/diff/new.txt\n",
+            "-navy\n",
+            "-#1e3a8a\n",
+            "-#cccccc\n",
+            "+#00f\n",
+            "+#1e3a8b\n",
+            "+#fbbf24\n",
+        ));
+    colourss()
+        .args(["diff", "--tolerance", "2", "tests/data/diff/old.txt", "tests/data/diff/new.txt"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("-#1e3a8a\n+#1e3a8b  (changed, ΔE 0.16)\n"));
+    // same colors in a different order and notation
+    colourss()
+        .args(["diff", "tests/data/colors.txt", "-"])
+        .write_stdin("rgb(10 20 30)\n#00f\nred\n#0f0\n")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_diff_json() {
+    let output = colourss()
+        .args(["diff", "--json", "--tolerance", "2", "tests/data/diff/old.txt", "tests/data/diff/new.txt"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["unchanged"], 1);
+    assert_eq!(value["removed"][0]["input"], "navy");
+    assert_eq!(value["added"][1]["hex"], "#fbbf24");
+    assert_eq!(value["changed"][0]["old"]["line"], 3);
+    assert_eq!(value["changed"][0]["new"]["input"], "#1e3a8b");
+    assert_eq!(value["changed"][0]["distance"], 0.16);
+}
+
+#[test]
+fn test_parse_watch_reruns_on_change() {
+    use std::io::{BufRead, BufReader};
+    use std::process::{Command as StdCommand, Stdio};
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let dir = std::env::temp_dir().join(format!("colourss-watch-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("tokens.txt");
+    std::fs::write(&file, "red\n").unwrap();
+
+    let mut child = StdCommand::new(env!("CARGO_BIN_EXE_colourss"))
+        .args(["parse", "--watch"])
+        .arg(&file)
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let (tx, rx) = mpsc::channel();
+    let stdout = child.stdout.take().unwrap();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let _ = tx.send(line);
+        }
+    });
+    let next_summary = || -> Vec<String> {
+        let mut lines = Vec::new();
+        while let Ok(line) = rx.recv_timeout(Duration::from_secs(10)) {
+            let done = line.starts_with("Parsing complete.");
+            lines.push(line);
+            if done {
+                return lines;
+            }
+        }
+        panic!("no report, got {:?}", lines);
+    };
+
+    let first = next_summary();
+    assert!(first.iter().any(|l| l.contains("OK: 'red'")));
+    assert!(first.last().unwrap().contains("(last run "));
+
+    // an atomic save: write elsewhere, then rename over the file
+    let tmp = dir.join("tokens.txt.tmp");
+    std::fs::write(&tmp, "red\nbogus\n").unwrap();
+    std::fs::rename(&tmp, &file).unwrap();
+    let second = next_summary();
+    assert!(second.iter().any(|l| l.contains("FAIL: 'bogus'")));
+    assert!(second.last().unwrap().contains("1 successful, 1 failed."));
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_parse_watch_needs_files() {
+    colourss()
+        .args(["parse", "--watch", "red"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("--watch only works on files"));
+}
+
+#[test]
+fn test_normalize_matches_fixtures() {
+    for (to, expected) in [("hex", "styles.hex.css"), ("rgb", "styles.rgb.css")] {
+        let expected = std::fs::read_to_string(format!("tests/data/normalize/{}", expected)).unwrap();
+        colourss()
+            .args(["normalize", "tests/data/normalize/styles.css", "--to", to])
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_normalize_check() {
+    colourss()
+        .args(["normalize", "--check", "tests/data/normalize/styles.hex.css", "tests/data/normalize/styles.css"])
+        .assert()
+        .code(1)
+        .stdout("")
+        .stderr("tests/data/normalize/styles.css: 7 color(s) would change\n");
+    colourss()
+        .args(["normalize", "--check", "--to", "rgb", "tests/data/normalize/styles.rgb.css"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_normalize_write() {
+    let dir = std::env::temp_dir().join(format!("colourss-normalize-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let original = std::fs::read_to_string("tests/data/normalize/styles.css").unwrap();
+    let expected = std::fs::read_to_string("tests/data/normalize/styles.hex.css").unwrap();
+    let file = dir.join("styles.css");
+    let backup = dir.join("styles.css.bak");
+
+    std::fs::write(&file, &original).unwrap();
+    colourss().arg("normalize").arg(&file).arg("--write").assert().success().stdout("");
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), expected);
+    assert_eq!(std::fs::read_to_string(&backup).unwrap(), original);
+
+    std::fs::remove_file(&backup).unwrap();
+    std::fs::write(&file, &original).unwrap();
+    colourss().arg("normalize").arg(&file).args(["--write", "--no-backup"]).assert().success();
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), expected);
+    assert!(!backup.exists());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_completions_bash() {
+    let output = colourss().args(["completions", "bash"]).output().unwrap();
+    assert!(output.status.success());
+    let script = String::from_utf8(output.stdout).unwrap();
+    assert!(script.contains("_colourss()"));
+    // every subcommand, so a new one that isn't wired up shows here
+    let help = colourss().arg("help").output().unwrap();
+    let help = String::from_utf8(help.stdout).unwrap();
+    let commands: Vec<&str> = help
+        .lines()
+        .skip_while(|line| !line.starts_with("Commands:"))
+        .skip(1)
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| line.split_whitespace().next())
+        .collect();
+    assert!(commands.contains(&"normalize"));
+    for command in [
+        "completions", "contrast", "convert", "dedupe", "diff", "gradient", "mix", "name", "names",
+        "normalize", "parse", "random", "scheme", "show", "sort", "stats",
+    ] {
+        assert!(commands.contains(&command), "{} missing from help", command);
+    }
+    for command in commands {
+        assert!(script.contains(&format!("colourss,{})", command)), "{} missing from completions", command);
+    }
+    // value hints: enum values and color keywords
+    assert!(script.contains("human json ndjson csv tsv"));
+    assert!(script.contains("rebeccapurple"));
+}
+
+#[test]
+fn test_completions_other_shells() {
+    for shell in ["zsh", "fish", "powershell"] {
+        colourss()
+            .args(["completions", shell])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("colourss"));
+    }
+    colourss().args(["completions", "tcsh"]).assert().code(2);
+}
+
+#[test]
+fn test_check_contrast() {
+    colourss()
+        .args(["check-contrast", "tests/data/contrast/pairs.txt"])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "1:  12.63:1  AA PASS  AAA PASS  #333333 on #ffffff\n",
+            "2:  12.63:1  AA PASS  AAA PASS  text.primary (#333333) on bg (#ffffff)\n",
+            "3:   4.48:1  AA FAIL  AAA FAIL  #777777 on #ffffff\n",
+            "4:   2.85:1  AA FAIL  AAA FAIL  muted (#999999) on #ffffff\n",
+            "\n4 pair(s) checked, 2 below AA.\n",
+            "Worst:\n",
+            "  4: 2.85:1  muted (#999999) on #ffffff\n",
+            "  3: 4.48:1  #777777 on #ffffff\n",
+        ));
+    colourss()
+        .args(["check-contrast", "--require", "aa", "--worst", "1", "tests/data/contrast/pairs.txt"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::ends_with("Worst:\n  4: 2.85:1  muted (#999999) on #ffffff\n"));
+    colourss()
+        .args(["check-contrast", "--require", "aaa", "-"])
+        .write_stdin("#000 #fff\nrgb(0, 0, 0), hsl(0, 0%, 90%)\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 pair(s) checked, 0 below AAA."));
+    colourss()
+        .args(["check-contrast", "--separator", " | ", "tests/data/contrast/piped.txt"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1:  13.80:1  AA PASS  AAA PASS  text (#000080) on bg (#eeeeee)\n"))
+        .stdout(predicate::str::contains("2:  21.00:1"));
+
+    // malformed lines are reported, the rest still checked
+    colourss()
+        .args(["check-contrast", "--require", "aa", "tests/data/contrast/malformed.txt"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::starts_with("1:  12.63:1"))
+        .stderr(predicate::str::contains("Error: line 2: expected two colors"))
+        .stderr(predicate::str::contains("Error: line 3: invalid background color: Unknown color name: \"nope\""));
+    let output = colourss()
+        .args(["check-contrast", "--json", "tests/data/contrast/malformed.txt"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["pairs"][0]["ratio"], 12.63);
+    assert_eq!(value["pairs"][0]["aaa_normal"], true);
+    assert_eq!(value["errors"][0], serde_json::json!({ "line": 2, "input": "justone", "error": "expected two colors" }));
+    assert_eq!(value["summary"], serde_json::json!({ "total": 1, "failed": 0, "worst": [] }));
+
+    let output = colourss().args(["check-contrast", "--json", "tests/data/contrast/pairs.txt"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["pairs"][1]["foreground_label"], "text.primary");
+    assert_eq!(value["summary"]["worst"], serde_json::json!([4, 3]));
+}
+
+#[test]
+fn test_scan_project() {
+    colourss()
+        .args(["scan", "tests/data/project"])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "tests/data/project/README.md:1:5: red -> #ff0000\n",
+            "tests/data/project/src/theme.js:1:24: hsl(120, 100%, 25%) -> #008000\n",
+            "tests/data/project/src/theme.js:2:22: #1e3a8a -> #1e3a8a\n",
+            "tests/data/project/styles/alert.css:1:17: #1E3A8A -> #1e3a8a\n",
+            "tests/data/project/styles/alert.css:1:41: tomato -> #ff6347\n",
+            "tests/data/project/styles/main.css:3:10: #1e3a8a -> #1e3a8a\n",
+            "tests/data/project/styles/main.css:4:15: rgba(255, 99, 71, 0.5) -> #ff634780\n",
+            "tests/data/project/styles/main.css:5:21: red -> #ff0000\n",
+        ));
+    colourss()
+        .args(["scan", "--unique", "tests/data/project"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "    3  #1e3a8a  tests/data/project/src/theme.js:2:22\n    2  #ff0000  tests/data/project/README.md:1:5\n",
+        ));
+    // keywords are left out, and the last of the two flags wins
+    colourss()
+        .args(["scan", "--include-named", "--no-named", "tests/data/project"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("red").not())
+        .stdout(predicate::str::contains("tomato").not())
+        .stdout(predicate::str::contains("main.css:3:10: #1e3a8a"));
+    colourss().args(["scan", "--no-named", "tests/data/project/README.md"]).assert().code(1).stdout("");
+    colourss()
+        .args(["scan", "--no-named", "--include-named", "tests/data/project/README.md"])
+        .assert()
+        .success();
+
+    let output = colourss()
+        .args(["scan", "--format", "json", "--unique", "tests/data/project/styles/*.css"])
+        .output()
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value[0]["hex"], "#1e3a8a");
+    assert_eq!(value[0]["count"], 2);
+    assert_eq!(value[0]["tokens"], serde_json::json!(["#1E3A8A", "#1e3a8a"]));
+    assert_eq!(value[0]["first"], serde_json::json!({ "file": "tests/data/project/styles/alert.css", "line": 1, "column": 17 }));
+    assert_eq!(value.as_array().unwrap().len(), 4);
+
+    // hidden directories and binary files under a directory are skipped,
+    // but a binary file named on its own is an error
+    colourss().args(["scan", "tests/data/project"]).assert().stdout(predicate::str::contains("ffffff").not());
+    colourss()
+        .args(["scan", "tests/data/project/src/logo.png", "tests/data/project/src/theme.js"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("theme.js:2:22"))
+        .stderr(predicate::str::contains("is not UTF-8 text"));
+    colourss().args(["scan", "tests/data/project/nope/*.css"]).assert().code(2).stderr(predicate::str::contains("No files match"));
+}
+
+#[test]
+fn test_scan_svg() {
+    // `none`, `currentColor`, `inherit` and a bare `url()` aren't listed,
+    // nor is the comment, and the `.txt` next to the icons is skipped
+    colourss()
+        .args(["scan-svg", "tests/data/svg/icons"])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "tests/data/svg/icons/gradient.svg:4:24: /svg/defs/linearGradient/stop stop-color=\"#1E3A8A\" -> #1e3a8a\n",
+            "tests/data/svg/icons/gradient.svg:5:24: /svg/defs/linearGradient/stop[2] stop-color=\"rgba(30, 58, 138, 0.5)\" -> #1e3a8a80\n",
+            "tests/data/svg/icons/gradient.svg:7:32: /svg/defs/filter/feFlood flood-color=\"#abc\" -> #aabbcc\n",
+            "tests/data/svg/icons/icon.svg:3:73: /svg color=\"#333\" -> #333333\n",
+            "tests/data/svg/icons/icon.svg:4:6: /svg/g stroke=\"rgb(1,2,3)\" -> #010203\n",
+            "tests/data/svg/icons/icon.svg:5:29: /svg/g/path fill=\"#abc\" -> #aabbcc\n",
+            "tests/data/svg/icons/icon.svg:6:50: /svg/g/path[2] style=\"stroke: hsl(0 100% 50%)\" -> #ff0000\n",
+            "tests/data/svg/icons/icon.svg:9:42: /svg/g[2]/circle style=\"fill: tomato\" -> #ff6347\n",
+            "tests/data/svg/icons/icon.svg:11:30: /svg/rect fill=\"url(#fade) #1e3a8a\" -> #1e3a8a\n",
+        ));
+    colourss()
+        .args(["scan-svg", "--unique", "tests/data/svg/icons/*.svg"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "    2  #1e3a8a  tests/data/svg/icons/gradient.svg:4:24\n    2  #aabbcc  tests/data/svg/icons/gradient.svg:7:32\n",
+        ));
+
+    // values that don't parse are listed with the error, and make it exit with 1
+    colourss()
+        .args(["scan-svg", "tests/data/svg/broken.svg"])
+        .assert()
+        .code(1)
+        .stdout(concat!(
+            "tests/data/svg/broken.svg:2:9: /svg/path fill=\"#12345\" error: Invalid hex code format in \"#12345\"\n",
+            "tests/data/svg/broken.svg:2:23: /svg/path stroke=\"blu\" error: Unknown color name: \"blu\"\n",
+            "tests/data/svg/broken.svg:3:16: /svg/path[2] style=\"fill: red\" -> #ff0000\n",
+        ));
+
+    let output = colourss().args(["scan-svg", "--format", "json", "tests/data/svg/broken.svg"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value[1]["attribute"], "stroke");
+    assert_eq!(value[1]["error"], "Unknown color name: \"blu\"");
+    assert!(value[1].get("hex").is_none());
+    assert_eq!(
+        value[2],
+        serde_json::json!({
+            "file": "tests/data/svg/broken.svg",
+            "line": 3,
+            "column": 16,
+            "element": "/svg/path[2]",
+            "attribute": "fill",
+            "style": true,
+            "value": "red",
+            "hex": "#ff0000",
+        })
+    );
+
+    let output = colourss().args(["scan-svg", "--format", "json", "--unique", "tests/data/svg/broken.svg"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value.as_array().unwrap().len(), 1);
+    assert_eq!(value[0]["first"]["element"], "/svg/path[2]");
+
+    colourss().args(["scan-svg", "tests/data/svg/icons/notes.txt"]).assert().code(1).stdout("");
+    colourss().args(["scan-svg", "tests/data/svg/nope.svg"]).assert().code(2);
+}
+
+#[test]
+fn test_scan_html() {
+    // `<script>` and comments are skipped, and so are the empty and
+    // `transparent` legacy attributes and the `.txt` next to the page
+    colourss()
+        .args(["scan-html", "tests/data/html"])
+        .assert()
+        .success()
+        .stdout(concat!(
+            "tests/data/html/page.html:6:17: style: navy -> #000080\n",
+            "tests/data/html/page.html:6:48: style: #ccc -> #cccccc\n",
+            "tests/data/html/page.html:10:7: body bgcolor: #FFFFCC -> #ffffcc\n",
+            "tests/data/html/page.html:10:25: body text: black -> #000000\n",
+            "tests/data/html/page.html:10:38: body link: chucknorris -> #c00000\n",
+            "tests/data/html/page.html:13:20: p style: #333 -> #333333\n",
+            "tests/data/html/page.html:13:38: p style: rgba(255, 0, 0, 0.5) -> #ff000080\n",
+            "tests/data/html/page.html:13:73: font color: red -> #ff0000\n",
+            "tests/data/html/page.html:14:10: table bgcolor: #c0c0c0 -> #c0c0c0\n",
+            "tests/data/html/page.html:14:36: td bgcolor: lime -> #00ff00\n",
+            "tests/data/html/page.html:15:12: font color: #333333 -> #333333\n",
+        ));
+    colourss()
+        .args(["scan-html", "--unique", "tests/data/html/*.html"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("    2  #333333  tests/data/html/page.html:13:20\n    1  #000080"));
+
+    let output = colourss().args(["scan-html", "--format", "json", "tests/data/html/page.html"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value.as_array().unwrap().len(), 11);
+    assert_eq!(value[0]["attribute"], serde_json::Value::Null);
+    assert_eq!(
+        value[4],
+        serde_json::json!({
+            "file": "tests/data/html/page.html",
+            "line": 10,
+            "column": 38,
+            "element": "body",
+            "attribute": "link",
+            "token": "chucknorris",
+            "hex": "#c00000",
+        })
+    );
+
+    let output = colourss().args(["scan-html", "--format", "json", "--unique", "tests/data/html/page.html"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value[0]["tokens"], serde_json::json!(["#333", "#333333"]));
+    assert_eq!(value[0]["count"], 2);
+
+    colourss()
+        .args(["scan-html", "--ext", "txt", "tests/data/html"])
+        .assert()
+        .success()
+        .stdout("tests/data/html/notes.txt:1:53: font color: red -> #ff0000\n");
+    colourss().args(["scan-html", "tests/data/html/nope.html"]).assert().code(2);
+}
+
+#[test]
+fn test_scan_theme() {
+    colourss()
+        .args(["scan-theme", "tests/data/themes/dark.json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(concat!(
+            "tests/data/themes/dark.json#/colors/editor.background: #1E1E1E -> #1e1e1e\n",
+            "tests/data/themes/dark.json#/colors/editor.foreground: #D4D4D4 -> #d4d4d4\n",
+        )))
+        .stdout(predicate::str::contains("#/colors/widget.shadow: #0000005c -> #0000005c\n"))
+        .stdout(predicate::str::contains("#/tokenColors/4/settings/background: #1E1E1E -> #1e1e1e\n"))
+        .stdout(predicate::str::contains("tab.activeBorderTop").not())
+        .stdout(predicate::str::contains("fontStyle").not());
+    colourss()
+        .args(["scan-theme", "--unique", "tests/data/themes/dark.json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "    2  #1e1e1e  tests/data/themes/dark.json#/colors/editor.background\n    2  #d4d4d4  ",
+        ));
+
+    // invalid values are listed with the error, and make it exit with 1
+    colourss()
+        .args(["scan-theme", "tests/data/themes/typos.json"])
+        .assert()
+        .code(1)
+        .stdout(concat!(
+            "tests/data/themes/typos.json#/colors/editor.background: #1e1e1 error: Invalid hex code format in \"#1e1e1\"\n",
+            "tests/data/themes/typos.json#/colors/editor.foreground: #ffffff -> #ffffff\n",
+            "tests/data/themes/typos.json#/colors/editor~1ruler~01: whitee error: Unknown color name: \"whitee\"\n",
+            "tests/data/themes/typos.json#/tokenColors/0/settings/foreground: #6a9955 -> #6a9955\n",
+        ));
+
+    let output = colourss().args(["scan-theme", "--format", "json", "tests/data/themes/typos.json"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        value[0],
+        serde_json::json!({
+            "file": "tests/data/themes/typos.json",
+            "pointer": "/colors/editor.background",
+            "value": "#1e1e1",
+            "error": "Invalid hex code format in \"#1e1e1\"",
+        })
+    );
+    assert_eq!(value[1]["hex"], "#ffffff");
+
+    let output = colourss().args(["scan-theme", "--format", "json", "--unique", "tests/data/themes/dark.json"]).output().unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value[0]["tokens"], serde_json::json!(["#1E1E1E"]));
+    assert_eq!(value[0]["first"], serde_json::json!({ "file": "tests/data/themes/dark.json", "pointer": "/colors/editor.background" }));
+
+    // a file that isn't JSON is an error, but the others are still listed
+    colourss()
+        .args(["scan-theme", "tests/data/themes"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("dark.json#/colors/editor.background"))
+        .stderr("Error: tests/data/themes/missing-comma.json: Invalid JSON: expected `,` or `}` at line 4 column 5\n");
+}
+
+#[test]
+fn test_repl() {
+    colourss()
+        .arg("repl")
+        .write_stdin("tomato\nrgb(1 2 3 / 50%)\ntomatoe\n:bogus\n:format json\n:strict on\nrgb(1, 2 3)\n:strict off\nrgb(1, 2 3)\n:quit\nred\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("  #ff6347\n  rgb(255 99 71)\n  hsl(9.13 100% 63.92%)\n  tomato\n"))
+        .stdout(predicate::str::contains("  #01020380\n  rgb(1 2 3 / 0.5)\n"))
+        .stdout(predicate::str::contains("Error: Unknown color name: \"tomatoe\"\nDid you mean 'tomato'?\n"))
+        .stdout(predicate::str::contains("unknown command :bogus"))
+        .stdout(predicate::str::contains("{\"input\":\"rgb(1, 2 3)\",\"error\":\"Invalid RGB/RGBA format in \\\"rgb(1, 2 3)\\\"\",\"suggestion\":null}"))
+        .stdout(predicate::str::contains("{\"input\":\"rgb(1, 2 3)\",\"hex\":\"#010203\""))
+        // nothing after :quit is read
+        .stdout(predicate::str::contains("#ff0000").not());
+}
+
+#[test]
+fn test_repl_switches_keep_the_other_mode() {
+    // `:strict off` doesn't undo --lenient, nor `:lenient off` --strict
+    colourss()
+        .args(["repl", "--lenient"])
+        .write_stdin(":format json\n:strict off\nff6600\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("{\"input\":\"ff6600\",\"hex\":\"#ff6600\""));
+    colourss()
+        .args(["repl", "--strict"])
+        .write_stdin(":format json\n:lenient off\nrgb(1, 2 3)\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("{\"input\":\"rgb(1, 2 3)\",\"error\""));
+}
+
+#[test]
+fn test_errors_name_the_input_once() {
+    // the message quotes the input, so the commands don't add it again
+    let once = |s: &str| s.matches("zzz").count() == 1;
+    for args in [
+        &["convert", "zzz"][..],
+        &["name", "zzz"],
+        &["show", "zzz"],
+        &["mix", "zzz", "red"],
+        &["scheme", "zzz"],
+        &["gradient", "red", "zzz"],
+        &["contrast", "zzz", "white"],
+    ] {
+        colourss()
+            .args(args)
+            .env("RUST_BACKTRACE", "0")
+            .assert()
+            .failure()
+            .stderr(predicate::function(once));
+    }
+    for (command, stdin) in [("sort", "zzz\n#fff\n"), ("dedupe", "zzz\n"), ("check-contrast", "zzz on white\n")] {
+        colourss()
+            .arg(command)
+            .write_stdin(stdin)
+            .assert()
+            .stderr(predicate::function(once));
+    }
+}
+
+#[test]
+fn test_strict_and_lenient_modes() {
+    // saturation without a %: fine by default and leniently, not strictly
+    colourss().args(["parse", "hsl(120, 100, 50%)"]).assert().success();
+    colourss().args(["parse", "--lenient", "hsl(120, 100, 50%)"]).assert().success();
+    colourss()
+        .args(["parse", "--strict", "hsl(120, 100, 50%)"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("FAIL: 'hsl(120, 100, 50%)' -> Error: Invalid component value: S: 100 (needs a %)"));
+
+    // out of range: only lenient clamps it
+    colourss().args(["parse", "rgb(300, 0, 0)"]).assert().code(1);
+    colourss()
+        .args(["parse", "--lenient", "rgb(300, 0, 0)", "ff6600"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("OK: 'rgb(300, 0, 0)' -> Color(r: 255, g: 0, b: 0)"))
+        .stdout(predicate::str::contains("OK: 'ff6600' -> Color(r: 255, g: 102, b: 0)"));
+
+    colourss().args(["parse", "--strict", "--lenient", "red"]).assert().code(2);
+}
+
+#[test]
+fn test_modes_in_other_commands() {
+    colourss().args(["convert", "--lenient", "ff6600"]).assert().success().stdout("#ff6600\n");
+    colourss().args(["convert", "ff6600"]).assert().code(1);
+    colourss().args(["convert", "--strict", "rgb(1, 2 3)"]).assert().code(1);
+    colourss().args(["contrast", "--lenient", "000", "fff"]).assert().success();
+    colourss().args(["contrast", "--strict", "hsl(0, 0, 0%)", "white"]).assert().code(2);
+    colourss().args(["mix", "--lenient", "rgb(-20, 0, 0)", "rgb(512, 0, 0)"]).assert().success().stdout("#800000\n");
+    colourss()
+        .args(["sort", "--strict"])
+        .write_stdin("red\nrgb(0, 0 255)\n")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("line 2"));
+}
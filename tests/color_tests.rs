@@ -0,0 +1,866 @@
+use colourss::{average, average_srgb, parse_color, parse_color_detailed, parse_temperature, BlendMode, Color, Color16, ColorF, ColorFormat, HuePath, InterpSpace};
+
+#[test]
+fn test_is_dark_saturated() {
+    // pure blue is dark even though one channel is maxed out
+    let blue = parse_color("#0000ff").unwrap();
+    assert!(blue.is_dark());
+    assert!(!blue.is_light());
+
+    // pure yellow is light
+    let yellow = parse_color("#ffff00").unwrap();
+    assert!(yellow.is_light());
+    assert!(!yellow.is_dark());
+}
+
+#[test]
+fn test_is_dark_black_white() {
+    assert!(Color { r: 0, g: 0, b: 0 }.is_dark());
+    assert!(Color { r: 255, g: 255, b: 255 }.is_light());
+    assert!((Color { r: 255, g: 255, b: 255 }.relative_luminance() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_is_dark_threshold_boundary() {
+    let black = Color { r: 0, g: 0, b: 0 };
+    let white = Color { r: 255, g: 255, b: 255 };
+    // luminance exactly at the threshold counts as light
+    assert!(black.is_light_with_threshold(0.0));
+    assert!(!black.is_dark_with_threshold(0.0));
+    assert!(white.is_light_with_threshold(1.0));
+    // just above the luminance it's dark
+    assert!(black.is_dark_with_threshold(0.001));
+
+    // #767676 sits just above the default threshold, #747474 just below
+    let above = parse_color("#767676").unwrap();
+    let below = parse_color("#747474").unwrap();
+    assert!(above.relative_luminance() > Color::DARK_THRESHOLD);
+    assert!(above.is_light());
+    assert!(below.relative_luminance() < Color::DARK_THRESHOLD);
+    assert!(below.is_dark());
+}
+
+#[test]
+fn test_mix_midpoint() {
+    let red = Color { r: 255, g: 0, b: 0 };
+    let blue = Color { r: 0, g: 0, b: 255 };
+    // naive sRGB lerp
+    assert_eq!(red.mix(&blue, 0.5), Color { r: 128, g: 0, b: 128 });
+    assert_eq!(red.lerp(&blue, 0.5), red.mix(&blue, 0.5));
+    // linear light keeps the midpoint brighter
+    assert_eq!(red.mix_linear(&blue, 0.5), Color { r: 188, g: 0, b: 188 });
+    // the short way from 0deg to 240deg goes through magenta
+    assert_eq!(red.mix_hsl(&blue, 0.5), Color { r: 255, g: 0, b: 255 });
+    // same as color-mix(in oklab, red, blue)
+    assert_eq!(red.mix_oklab(&blue, 0.5), Color { r: 140, g: 83, b: 162 });
+}
+
+#[test]
+fn test_mix_hsl_gray_keeps_hue() {
+    let red = Color { r: 255, g: 0, b: 0 };
+    let white = Color { r: 255, g: 255, b: 255 };
+    // a pink, not something on the way to cyan
+    assert_eq!(red.mix_hsl(&white, 0.5), Color { r: 223, g: 159, b: 159 });
+    assert_eq!(white.mix_hsl(&red, 0.5), red.mix_hsl(&white, 0.5));
+}
+
+#[test]
+fn test_mix_endpoints_and_clamping() {
+    let a = Color { r: 12, g: 200, b: 99 };
+    let b = Color { r: 250, g: 3, b: 180 };
+    assert_eq!(a.mix(&b, 0.0), a);
+    assert_eq!(a.mix(&b, 1.0), b);
+    assert_eq!(a.mix_linear(&b, 0.0), a);
+    assert_eq!(a.mix_linear(&b, 1.0), b);
+    // out of range t is clamped
+    assert_eq!(a.mix(&b, -3.0), a);
+    assert_eq!(a.mix(&b, 42.0), b);
+    assert_eq!(a.mix_linear(&b, -0.5), a);
+    assert_eq!(a.mix_linear(&b, 1.5), b);
+    for t in [0.0, 1.0] {
+        let expected = if t == 0.0 { a } else { b };
+        assert_eq!(a.mix_hsl(&b, t), expected);
+        assert_eq!(a.mix_oklab(&b, t), expected);
+    }
+}
+
+#[test]
+fn test_mix_in_spaces() {
+    let red = Color::new(255, 0, 0);
+    let blue = Color::new(0, 0, 255);
+    // sRGB gives a dull purple, Oklab a brighter, bluer one
+    let srgb = red.mix_in(InterpSpace::Srgb, &blue, 0.5);
+    let oklab = red.mix_in(InterpSpace::Oklab, &blue, 0.5);
+    assert_eq!(srgb, Color::new(128, 0, 128));
+    assert_eq!(oklab, Color::new(140, 83, 162));
+    assert!(oklab.to_oklab().l > srgb.to_oklab().l);
+
+    // the spaces the older methods mix in
+    let (a, b) = (Color::new(200, 30, 90), Color::new(20, 180, 60));
+    for t in [0.1, 0.5, 0.8] {
+        assert_eq!(a.mix_in(InterpSpace::Srgb, &b, t), a.mix(&b, t));
+        assert_eq!(a.mix_in(InterpSpace::LinearRgb, &b, t), a.mix_linear(&b, t));
+        assert_eq!(a.mix_in(InterpSpace::Hsl { hue_path: HuePath::Shorter }, &b, t), a.mix_hsl(&b, t));
+        assert_eq!(a.mix_in(InterpSpace::Oklab, &b, t), a.mix_oklab(&b, t));
+    }
+    let paths = [HuePath::Shorter, HuePath::Longer, HuePath::Increasing, HuePath::Decreasing];
+    for space in [InterpSpace::Srgb, InterpSpace::Hsl { hue_path: HuePath::Longer }, InterpSpace::Oklch { hue_path: HuePath::Longer }] {
+        assert_eq!(a.mix_in(space, &b, 0.0), a);
+        assert_eq!(a.mix_in(space, &b, 1.0), b);
+        assert_eq!(a.mix_in(space, &b, f32::NAN), a);
+    }
+
+    // Oklch keeps chroma, so it doesn't go through gray
+    let chroma = |c: Color| c.to_oklab().a.hypot(c.to_oklab().b);
+    let (yellow, purple) = (Color::new(255, 255, 0), Color::new(128, 0, 255));
+    let oklch = yellow.mix_in(InterpSpace::Oklch { hue_path: HuePath::Shorter }, &purple, 0.5);
+    assert!(chroma(oklch) > 2.0 * chroma(yellow.mix_oklab(&purple, 0.5)), "{:?}", oklch);
+    // grays have no hue, so the other color's is kept
+    let white = Color::new(255, 255, 255);
+    let oklch_hue = |c: Color| c.to_oklab().b.atan2(c.to_oklab().a).to_degrees();
+    for hue_path in paths {
+        let pink = red.mix_in(InterpSpace::Hsl { hue_path }, &white, 0.5);
+        assert!(pink.r > pink.g && pink.g == pink.b && pink.g > 0, "{:?} in {:?}", pink, hue_path);
+        let pink = red.mix_in(InterpSpace::Oklch { hue_path }, &white, 0.5);
+        assert!((oklch_hue(pink) - oklch_hue(red)).abs() < 2.0, "{:?} in {:?}", pink, hue_path);
+    }
+}
+
+#[test]
+fn test_mix_in_hue_paths() {
+    // 350deg to 10deg, and the other way, half way
+    let (from, to) = (Color::from_hsl(350.0, 1.0, 0.5), Color::from_hsl(10.0, 1.0, 0.5));
+    for (hue_path, forward, backward) in [
+        (HuePath::Shorter, 0.0, 0.0),
+        (HuePath::Longer, 180.0, 180.0),
+        (HuePath::Increasing, 0.0, 180.0),
+        (HuePath::Decreasing, 180.0, 0.0),
+    ] {
+        let space = InterpSpace::Hsl { hue_path };
+        assert_eq!(from.mix_in(space, &to, 0.5), Color::from_hsl(forward, 1.0, 0.5), "{:?}", hue_path);
+        assert_eq!(to.mix_in(space, &from, 0.5), Color::from_hsl(backward, 1.0, 0.5), "{:?}", hue_path);
+        // a quarter of the way: 355deg, or 265deg for the long way down
+        let quarter = from.mix_in(space, &to, 0.25).to_hsl().0;
+        let expected = if forward == 0.0 { 355.0 } else { 265.0 };
+        assert!((quarter - expected).abs() < 1.0, "{:?}: {}", hue_path, quarter);
+    }
+    // equal hues go all the way around the long way
+    let longer = InterpSpace::Hsl { hue_path: HuePath::Longer };
+    assert_eq!(from.mix_in(longer, &from, 0.5), Color::from_hsl(170.0, 1.0, 0.5));
+
+    // the same in Oklch, with hues from Oklab
+    let hue = |c: Color| c.to_oklab().b.atan2(c.to_oklab().a).to_degrees().rem_euclid(360.0);
+    let (pink, orange) = (Color::new(255, 0, 160), Color::new(255, 90, 60));
+    let (h1, h2) = (hue(pink), hue(orange));
+    assert!(h1 > 350.0 && h2 < 40.0, "{} {}", h1, h2);
+    let short_mid = ((h1 + h2 + 360.0) / 2.0).rem_euclid(360.0);
+    let long_mid = (h1 + h2) / 2.0;
+    for (hue_path, forward, backward) in [
+        (HuePath::Shorter, short_mid, short_mid),
+        (HuePath::Longer, long_mid, long_mid),
+        (HuePath::Increasing, short_mid, long_mid),
+        (HuePath::Decreasing, long_mid, short_mid),
+    ] {
+        let space = InterpSpace::Oklch { hue_path };
+        // gamut clipping bends hue a little
+        let close = |a: f32, b: f32| (a - b + 540.0).rem_euclid(360.0) - 180.0;
+        let there = hue(pink.mix_in(space, &orange, 0.5));
+        let back = hue(orange.mix_in(space, &pink, 0.5));
+        assert!(close(there, forward).abs() < 15.0, "{:?}: {} not {}", hue_path, there, forward);
+        assert!(close(back, backward).abs() < 15.0, "{:?}: {} not {}", hue_path, back, backward);
+    }
+}
+
+#[test]
+fn test_average() {
+    let black = Color::new(0, 0, 0);
+    let white = Color::new(255, 255, 255);
+    assert_eq!(average(&[black, white]).unwrap().to_hex(), "#bcbcbc");
+    assert_eq!(average_srgb(&[black, white]).unwrap().to_hex(), "#808080");
+    // agrees with mixing two colors
+    let red = Color::new(255, 0, 0);
+    let blue = Color::new(0, 0, 255);
+    assert_eq!(average(&[red, blue]).unwrap(), red.mix_linear(&blue, 0.5));
+    assert_eq!(average_srgb(&[red, blue]).unwrap(), red.mix(&blue, 0.5));
+    let colors = [red, red, blue];
+    assert_eq!(average_srgb(&colors).unwrap(), Color::new(170, 0, 85));
+
+    assert_eq!(average(&[]), None);
+    assert_eq!(average_srgb(&[]), None);
+
+    // copies of one color average to it exactly
+    for v in 0..=255u8 {
+        let color = Color::new(v, 255 - v, v / 3);
+        for n in [1, 2, 3, 7, 100, 1000] {
+            let copies = vec![color; n];
+            assert_eq!(average(&copies), Some(color), "{} x {:?}", n, color);
+            assert_eq!(average_srgb(&copies), Some(color), "{} x {:?}", n, color);
+        }
+    }
+}
+
+#[test]
+fn test_lighten_darken_sass() {
+    let red = Color { r: 255, g: 0, b: 0 };
+    // darken(red, 10%) -> #cc0000
+    assert_eq!(red.darken(0.1), parse_color("#cc0000").unwrap());
+    // lighten(red, 10%) -> #ff3333
+    assert_eq!(red.lighten(0.1), parse_color("#ff3333").unwrap());
+    // desaturate(red, 20%) -> #e61a1a
+    assert_eq!(red.desaturate(0.2), parse_color("#e61a1a").unwrap());
+}
+
+#[test]
+fn test_adjust_clamping() {
+    let white = Color { r: 255, g: 255, b: 255 };
+    let black = Color { r: 0, g: 0, b: 0 };
+    assert_eq!(white.lighten(0.5), white);
+    assert_eq!(black.darken(0.5), black);
+    assert_eq!(Color { r: 255, g: 0, b: 0 }.darken(2.0), black);
+    assert_eq!(Color { r: 255, g: 0, b: 0 }.saturate(1.0), Color { r: 255, g: 0, b: 0 });
+}
+
+#[test]
+fn test_rotate_hue() {
+    let red = Color { r: 255, g: 0, b: 0 };
+    assert_eq!(red.rotate_hue(120.0), Color { r: 0, g: 255, b: 0 });
+    assert_eq!(red.rotate_hue(-120.0), Color { r: 0, g: 0, b: 255 });
+    assert_eq!(red.rotate_hue(360.0), red);
+    // a gray must not pick up any color
+    let gray = Color { r: 128, g: 128, b: 128 };
+    assert_eq!(gray.rotate_hue(90.0), gray);
+}
+
+#[test]
+fn test_invert() {
+    let white = Color { r: 255, g: 255, b: 255 };
+    let black = Color { r: 0, g: 0, b: 0 };
+    assert_eq!(white.invert(), black);
+    assert_eq!(black.invert(), white);
+    let c = Color { r: 10, g: 100, b: 200 };
+    assert_eq!(c.invert(), Color { r: 245, g: 155, b: 55 });
+    // original stays untouched
+    assert_eq!(c, Color { r: 10, g: 100, b: 200 });
+    assert_eq!(c.invert().invert(), c);
+}
+
+#[test]
+fn test_grayscale_bt709() {
+    // each primary maps to its BT.709 weight
+    assert_eq!(Color { r: 255, g: 0, b: 0 }.grayscale(), Color { r: 54, g: 54, b: 54 });
+    assert_eq!(Color { r: 0, g: 255, b: 0 }.grayscale(), Color { r: 182, g: 182, b: 182 });
+    assert_eq!(Color { r: 0, g: 0, b: 255 }.grayscale(), Color { r: 18, g: 18, b: 18 });
+    // grays are unchanged
+    let gray = Color { r: 77, g: 77, b: 77 };
+    assert_eq!(gray.grayscale(), gray);
+}
+
+#[test]
+fn test_sepia() {
+    let white = Color { r: 255, g: 255, b: 255 };
+    assert_eq!(white.sepia(), Color { r: 255, g: 255, b: 239 });
+    assert_eq!(Color { r: 0, g: 0, b: 0 }.sepia(), Color { r: 0, g: 0, b: 0 });
+    assert_eq!(white, Color { r: 255, g: 255, b: 255 });
+}
+
+#[test]
+fn test_harmony_red() {
+    let red = Color { r: 255, g: 0, b: 0 };
+    let lime = Color { r: 0, g: 255, b: 0 };
+    let blue = Color { r: 0, g: 0, b: 255 };
+    let cyan = Color { r: 0, g: 255, b: 255 };
+    assert_eq!(red.complementary(), cyan);
+    assert_eq!(red.triadic(), [red, lime, blue]);
+    assert_eq!(red.tetradic()[0], red);
+    assert_eq!(red.tetradic()[2], cyan);
+    assert_eq!(red.split_complementary()[0], red);
+}
+
+#[test]
+fn test_harmony_analogous() {
+    let red = Color { r: 255, g: 0, b: 0 };
+    let colors = red.analogous(3, 60.0);
+    assert_eq!(
+        colors,
+        vec![red, Color { r: 255, g: 255, b: 0 }, Color { r: 0, g: 255, b: 0 }]
+    );
+    assert!(red.analogous(0, 30.0).is_empty());
+}
+
+#[test]
+fn test_harmony_gray() {
+    let gray = Color { r: 90, g: 90, b: 90 };
+    assert_eq!(gray.complementary(), gray);
+    assert_eq!(gray.triadic(), [gray; 3]);
+    assert_eq!(gray.tetradic(), [gray; 4]);
+    assert!(gray.analogous(5, 30.0).iter().all(|c| *c == gray));
+}
+
+#[test]
+fn test_ramps_endpoints() {
+    let brand = parse_color("#3366cc").unwrap();
+    let black = Color { r: 0, g: 0, b: 0 };
+    let white = Color { r: 255, g: 255, b: 255 };
+    let gray = Color { r: 128, g: 128, b: 128 };
+
+    for (ramp, target) in [
+        (brand.shades(5), black),
+        (brand.tints(5), white),
+        (brand.tones(5), gray),
+        (brand.shades_linear(5), black),
+        (brand.tints_linear(5), white),
+        (brand.tones_linear(5), gray),
+    ] {
+        assert_eq!(ramp.len(), 5);
+        assert_eq!(ramp[0], brand);
+        assert_eq!(ramp[4], target);
+    }
+
+    assert!(brand.shades(0).is_empty());
+    assert_eq!(brand.tints(1), vec![brand]);
+}
+
+#[test]
+fn test_ramps_monotonic() {
+    let brand = parse_color("#3366cc").unwrap();
+    let lum = |ramp: &[Color]| ramp.iter().map(|c| c.relative_luminance()).collect::<Vec<_>>();
+
+    for shades in [brand.shades(8), brand.shades_linear(8)] {
+        assert!(lum(&shades).windows(2).all(|w| w[1] < w[0]));
+    }
+    for tints in [brand.tints(8), brand.tints_linear(8)] {
+        assert!(lum(&tints).windows(2).all(|w| w[1] > w[0]));
+    }
+}
+
+#[test]
+fn test_blend_identities() {
+    let white = Color { r: 255, g: 255, b: 255 };
+    let black = Color { r: 0, g: 0, b: 0 };
+    let c = Color { r: 12, g: 130, b: 201 };
+    assert_eq!(c.blend(&white, BlendMode::Multiply), c);
+    assert_eq!(white.blend(&c, BlendMode::Multiply), c);
+    assert_eq!(c.blend(&black, BlendMode::Screen), c);
+    assert_eq!(black.blend(&c, BlendMode::Screen), c);
+    assert_eq!(c.blend(&c, BlendMode::Difference), black);
+    assert_eq!(black.blend(&c, BlendMode::Exclusion), c);
+    assert_eq!(c.blend(&white, BlendMode::Normal), c);
+}
+
+#[test]
+fn test_blend_modes() {
+    let src = Color { r: 100, g: 128, b: 200 };
+    let backdrop = Color { r: 50, g: 192, b: 200 };
+    // hand-computed with the spec formulas
+    assert_eq!(src.blend(&backdrop, BlendMode::Overlay), Color { r: 39, g: 192, b: 231 });
+    assert_eq!(
+        Color { r: 255, g: 0, b: 0 }.blend(&Color { r: 128, g: 128, b: 128 }, BlendMode::Overlay),
+        Color { r: 255, g: 1, b: 1 }
+    );
+    assert_eq!(src.blend(&backdrop, BlendMode::Darken), Color { r: 50, g: 128, b: 200 });
+    assert_eq!(src.blend(&backdrop, BlendMode::Lighten), Color { r: 100, g: 192, b: 200 });
+    assert_eq!(src.blend(&backdrop, BlendMode::Difference), Color { r: 50, g: 64, b: 0 });
+    // dodge with white source and burn with black source saturate
+    let white = Color { r: 255, g: 255, b: 255 };
+    let black = Color { r: 0, g: 0, b: 0 };
+    assert_eq!(white.blend(&backdrop, BlendMode::ColorDodge), white);
+    assert_eq!(black.blend(&backdrop, BlendMode::ColorBurn), black);
+}
+
+#[test]
+fn test_ansi256() {
+    assert_eq!(Color { r: 255, g: 0, b: 0 }.to_ansi256(), 196);
+    assert_eq!(Color { r: 0, g: 0, b: 0 }.to_ansi256(), 16);
+    assert_eq!(Color { r: 255, g: 255, b: 255 }.to_ansi256(), 231);
+    // mid gray lands on the gray ramp, not the cube
+    assert_eq!(parse_color("#808080").unwrap().to_ansi256(), 244);
+    // 100 is closer to the 95 level than 135, which naive division gets wrong
+    assert_eq!(Color { r: 100, g: 0, b: 0 }.to_ansi256(), 52);
+}
+
+#[test]
+fn test_ansi16() {
+    assert_eq!(Color { r: 255, g: 0, b: 0 }.to_ansi16(), 9);
+    assert_eq!(Color { r: 200, g: 0, b: 0 }.to_ansi16(), 1);
+    assert_eq!(Color { r: 0, g: 0, b: 0 }.to_ansi16(), 0);
+    assert_eq!(Color { r: 255, g: 255, b: 255 }.to_ansi16(), 15);
+}
+
+#[test]
+fn test_ansi_escapes() {
+    let c = Color { r: 1, g: 22, b: 255 };
+    assert_eq!(c.ansi_fg(), "\x1b[38;2;1;22;255m");
+    assert_eq!(c.ansi_bg(), "\x1b[48;2;1;22;255m");
+}
+
+#[test]
+fn test_from_kelvin_anchors() {
+    // daylight is close to white
+    let daylight = Color::from_kelvin(6600.0);
+    assert!(daylight.r >= 250 && daylight.g >= 250 && daylight.b >= 250);
+    // candle light is orange: lots of red, little blue
+    let warm = Color::from_kelvin(2000.0);
+    assert_eq!(warm.r, 255);
+    assert!(warm.g < 160 && warm.b < 40);
+    // clear sky is bluish
+    let cool = Color::from_kelvin(10000.0);
+    assert_eq!(cool.b, 255);
+    assert!(cool.r < 220);
+    // out-of-range temperatures clamp
+    assert_eq!(Color::from_kelvin(10.0), Color::from_kelvin(1000.0));
+    assert_eq!(Color::from_kelvin(1e6), Color::from_kelvin(40000.0));
+}
+
+#[test]
+fn test_estimate_kelvin_round_trip() {
+    for k in [1000.0, 1500.0, 2700.0, 4000.0, 5000.0, 6500.0, 8000.0, 10000.0, 15000.0, 25000.0, 40000.0] {
+        let estimate = Color::from_kelvin(k).estimate_kelvin().unwrap();
+        assert!(
+            (estimate - k).abs() / k < 0.05,
+            "{}K estimated as {}K",
+            k,
+            estimate
+        );
+    }
+    assert_eq!(Color { r: 0, g: 0, b: 0 }.estimate_kelvin(), None);
+    assert_eq!(Color { r: 0, g: 255, b: 0 }.estimate_kelvin(), None);
+}
+
+#[test]
+fn test_parse_temperature() {
+    // warm, neutral and cool anchors
+    let warm = parse_temperature("2700K").unwrap();
+    assert_eq!(warm.r, 255);
+    assert!(warm.g < 180 && warm.b < 110);
+    assert_eq!(parse_temperature("6600K").unwrap(), Color::new(255, 255, 255));
+    let cool = parse_temperature("10000K").unwrap();
+    assert_eq!(cool.b, 255);
+    assert!(cool.r < 220);
+
+    for input in ["4000K", "4000 K", "4000k", " 4000\tK ", "4e3K", "4000.0K"] {
+        assert_eq!(parse_temperature(input).unwrap(), Color::from_kelvin(4000.0), "{:?}", input);
+    }
+    // out of range clamps
+    assert_eq!(parse_temperature("500K").unwrap(), Color::from_kelvin(1000.0));
+    assert_eq!(parse_temperature("100000K").unwrap(), Color::from_kelvin(40000.0));
+
+    for input in ["K", " k", "", "4000", "4000KK", "K4000", "warmK", "-2700K", "4 000K"] {
+        let error = parse_temperature(input).unwrap_err();
+        assert!(error.is_syntax(), "{:?}", input);
+    }
+    assert_eq!(parse_temperature("K").unwrap_err().to_string(), "Invalid color temperature in \"K\"");
+}
+
+#[test]
+fn test_contrast_ratio() {
+    let black = Color { r: 0, g: 0, b: 0 };
+    let white = Color { r: 255, g: 255, b: 255 };
+    assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-4);
+    assert_eq!(black.contrast_ratio(&white), white.contrast_ratio(&black));
+    assert_eq!(white.contrast_ratio(&white), 1.0);
+
+    // #767676 on white is the classic "just passes AA" gray
+    let gray = parse_color("#767676").unwrap();
+    let ratio = gray.contrast_ratio(&white);
+    assert!(ratio > 4.5 && ratio < 4.6, "{}", ratio);
+}
+
+#[test]
+fn test_best_text_color() {
+    let black = Color::new(0, 0, 0);
+    let white = Color::new(255, 255, 255);
+    for (background, text) in [
+        // HSL lightness 47%, but black has the better contrast
+        ("#777777", black),
+        ("#ffff00", black),
+        ("#ff0000", black),
+        // lightness 50%, and still wants white
+        ("#0000ff", white),
+        ("#767676", black),
+        ("#757575", white),
+        ("#000000", white),
+        ("#ffffff", black),
+    ] {
+        let background = parse_color(background).unwrap();
+        assert_eq!(background.best_text_color(), text, "{:?}", background);
+        let other = if text == black { white } else { black };
+        assert!(background.contrast_ratio(&text) >= background.contrast_ratio(&other));
+        assert_eq!(background.best_text_color() == black, background.is_light(), "{:?}", background);
+    }
+
+    let ink = Color::new(0x22, 0x22, 0x22);
+    let paper = Color::new(0xf5, 0xf5, 0xf0);
+    let blue = Color::new(0, 0, 255);
+    assert_eq!(blue.best_text_color_from(&[ink, paper]), Some(paper));
+    assert_eq!(Color::new(255, 255, 0).best_text_color_from(&[ink, paper]), Some(ink));
+    assert_eq!(blue.best_text_color_from(&[ink]), Some(ink));
+    assert_eq!(blue.best_text_color_from(&[]), None);
+}
+
+#[test]
+fn test_adjust_for_contrast() {
+    let white = Color::new(255, 255, 255);
+    let black = Color::new(0, 0, 0);
+    let navy = Color::new(0x1a, 0x23, 0x7e);
+    for (brand, background, target) in [
+        ("#ff6600", white, 4.5),
+        ("#1da1f2", white, 4.5),
+        ("#ffcc00", white, 3.0),
+        ("#4caf50", white, 7.0),
+        ("#0066cc", black, 7.0),
+        ("#e91e63", navy, 4.5),
+        ("#777777", Color::new(0x77, 0x77, 0x77), 4.5),
+    ] {
+        let brand = parse_color(brand).unwrap();
+        let adjusted = brand.adjust_for_contrast(&background, target).unwrap();
+        let ratio = adjusted.contrast_ratio(&background);
+        assert!(ratio >= target, "{:?} on {:?}: {:?} has {}", brand, background, adjusted, ratio);
+        // same hue, and no further than needed
+        let (h, _, _) = adjusted.to_hsl();
+        let (brand_h, brand_s, _) = brand.to_hsl();
+        assert!(brand_s == 0.0 || (h - brand_h).abs() < 3.0, "{:?} became {:?}", brand, adjusted);
+        assert!(ratio < target + 0.3, "{:?} on {:?}: {:?} has {}", brand, background, adjusted, ratio);
+    }
+
+    // colors that already pass come back as they are
+    let blue = Color::new(0, 0, 0xcc);
+    assert_eq!(blue.adjust_for_contrast(&white, 4.5), Some(blue));
+    assert_eq!(white.adjust_for_contrast(&black, 20.0), Some(white));
+
+    // light colors on a dark background get lighter
+    let dim = Color::new(0x33, 0x44, 0x88);
+    let lighter = dim.adjust_for_contrast(&black, 7.0).unwrap();
+    assert!(lighter.relative_luminance() > dim.relative_luminance());
+
+    // going past the background when its side can't make it
+    let gray = Color::new(0x80, 0x80, 0x80);
+    let light_gray = Color::new(0x90, 0x90, 0x90);
+    let adjusted = light_gray.adjust_for_contrast(&gray, 5.0).unwrap();
+    assert!(adjusted.relative_luminance() < gray.relative_luminance(), "{:?}", adjusted);
+
+    // out of reach
+    assert_eq!(white.adjust_for_contrast(&gray, 21.0), None);
+    assert_eq!(Color::new(255, 102, 0).adjust_for_contrast(&white, 22.0), None);
+}
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_nearest_named() {
+    let (name, distance) = parse_color("#4682b5").unwrap().nearest_named();
+    assert_eq!(name, "steelblue");
+    assert!(distance > 0.0 && distance < 1.0);
+    assert_eq!(parse_color("tomato").unwrap().nearest_named(), ("tomato", 0.0));
+
+    // shared values come out alphabetically
+    let top = Color { r: 0, g: 255, b: 255 }.nearest_named_n(3);
+    assert_eq!(top[0], ("aqua", 0.0));
+    assert_eq!(top[1], ("cyan", 0.0));
+    assert!(top[2].1 > 0.0);
+    assert_eq!(Color { r: 0, g: 0, b: 0 }.nearest_named_n(500).len(), 149);
+}
+
+#[test]
+fn test_hex_formatting() {
+    let orange = Color { r: 255, g: 128, b: 0 };
+    assert_eq!(format!("{:x}", orange), "ff8000");
+    assert_eq!(format!("{:X}", orange), "FF8000");
+    assert_eq!(format!("{:#x}", orange), "#ff8000");
+    assert_eq!(format!("{:#X}", orange), "#FF8000");
+    assert_eq!(format!("{:#x}", orange), orange.to_hex());
+
+    // every channel is two digits
+    let black = Color { r: 0, g: 0, b: 0 };
+    assert_eq!(format!("{:x}", black), "000000");
+    assert_eq!(format!("{:#X}", Color { r: 1, g: 0, b: 10 }), "#01000A");
+
+    // padding covers the whole text, hash included
+    assert_eq!(format!("{:9x}", orange), "   ff8000");
+    assert_eq!(format!("{:<9x}|", orange), "ff8000   |");
+    assert_eq!(format!("{:^#10x}", orange), " #ff8000  ");
+    assert_eq!(format!("{:*>9X}", orange), "***FF8000");
+    assert_eq!(format!("{:09x}", orange), "   ff8000");
+    assert_eq!(format!("{:.2x}", orange), "ff8000");
+    assert_eq!(format!("{:3x}", orange), "ff8000");
+}
+
+// pinned: test failures everywhere print colors this way
+#[test]
+fn test_debug_format() {
+    let steel = Color { r: 70, g: 130, b: 180 };
+    assert_eq!(format!("{:?}", steel), "Color(#4682b4)");
+    assert_eq!(format!("{:#?}", steel), "Color(#4682b4)");
+    assert_eq!(format!("{:?}", Color { r: 0, g: 0, b: 0 }), "Color(#000000)");
+    assert_eq!(format!("{:?}", Some(steel)), "Some(Color(#4682b4))");
+    assert_eq!(format!("{:#?}", vec![steel]), "[\n    Color(#4682b4),\n]");
+}
+
+#[test]
+fn test_channel_access() {
+    let steel = Color { r: 70, g: 130, b: 180 };
+    assert_eq!(steel.as_array(), [70, 130, 180]);
+    assert_eq!(steel.to_tuple(), (70, 130, 180));
+    assert_eq!(steel.channels().collect::<Vec<_>>(), vec![70, 130, 180]);
+    assert_eq!(steel.channels().map(u32::from).sum::<u32>(), 380);
+    assert_eq!(Color::from([70, 130, 180]), steel);
+    assert_eq!(Color::from((70, 130, 180)), steel);
+
+    // an RGB8 texture upload takes one flat byte slice
+    let palette = [steel, Color { r: 255, g: 0, b: 0 }];
+    let texels: Vec<u8> = palette.iter().flat_map(Color::as_array).collect();
+    fn upload(pixels: &[u8], width: usize) -> usize {
+        assert_eq!(pixels.len() % 3, 0);
+        pixels.len() / 3 / width
+    }
+    assert_eq!(texels, [70, 130, 180, 255, 0, 0]);
+    assert_eq!(upload(&texels, 2), 1);
+
+    // invert, in one line
+    assert_eq!(steel.map_channels(|c| 255 - c), steel.invert());
+    assert_eq!(steel.map_channels(|c| c / 2), Color { r: 35, g: 65, b: 90 });
+
+    let translucent = colourss::Rgba { r: 70, g: 130, b: 180, a: 0.5 };
+    assert_eq!(translucent.as_array(), [70, 130, 180, 128]);
+    assert_eq!(translucent.to_tuple(), (70, 130, 180, 0.5));
+}
+
+#[test]
+fn test_channel_setters() {
+    let steel = Color { r: 70, g: 130, b: 180 };
+    assert_eq!(steel.with_g(0), Color { r: 70, g: 0, b: 180 });
+    assert_eq!(steel.with_r(1).with_b(2), Color { r: 1, g: 130, b: 2 });
+    assert_eq!(steel.with_channels(1, 2, 3), Color { r: 1, g: 2, b: 3 });
+    assert_eq!(steel.with_a(0.5), colourss::Rgba { r: 70, g: 130, b: 180, a: 0.5 });
+    assert_eq!(steel.with_a(0.5).with_a(1.0).to_color(), steel);
+    // the original stays as it was
+    assert_eq!(steel, Color { r: 70, g: 130, b: 180 });
+
+    // adjusters stop at the ends instead of wrapping around
+    let c = Color { r: 250, g: 5, b: 128 };
+    assert_eq!(c.add_r(20).r, 255);
+    assert_eq!(c.add_r(-20).r, 230);
+    assert_eq!(c.add_g(-20).g, 0);
+    assert_eq!(c.add_b(i16::MAX), Color { r: 250, g: 5, b: 255 });
+    assert_eq!(c.add_b(i16::MIN).b, 0);
+    assert_eq!(c.add_r(0), c);
+    assert_eq!(c, Color { r: 250, g: 5, b: 128 });
+}
+
+#[test]
+fn test_const_constructors() {
+    const BRAND: Color = Color::new(0x12, 0x34, 0x56);
+    const SAME: Color = Color::rgb(0x12, 0x34, 0x56);
+    const GLASS: colourss::Rgba = Color::rgba(0x12, 0x34, 0x56, 0.5);
+    assert_eq!(BRAND, parse_color("#123456").unwrap());
+    assert_eq!(SAME, BRAND);
+    assert_eq!(GLASS, colourss::Rgba { r: 0x12, g: 0x34, b: 0x56, a: 0.5 });
+    assert_eq!(GLASS.to_color(), BRAND);
+}
+
+#[test]
+fn test_colorf_round_trip() {
+    for c in [Color::new(0, 0, 0), Color::new(255, 255, 255), Color::new(1, 128, 254)] {
+        assert_eq!(ColorF::from(c).to_color(), c);
+    }
+    assert_eq!(ColorF::new(0.5, 0.499, 0.002).to_color(), Color::new(128, 127, 1));
+    assert_eq!(ColorF::new(-0.5, 1.5, f32::NAN).to_color(), Color::new(0, 255, 0));
+    let back: Color = ColorF::new(1.0, 0.0, 0.0).into();
+    assert_eq!(back, Color::new(255, 0, 0));
+}
+
+#[test]
+fn test_colorf_chain_keeps_precision() {
+    // five small steps, each less than half an 8-bit step on its own
+    let gray = Color::new(100, 100, 100);
+    let expected = (100.0 + 5.0 * 0.0015 * 255.0_f32).round() as u8; // 101.9
+    let mut bytes = gray;
+    let mut floats = ColorF::from(gray);
+    for _ in 0..5 {
+        bytes = bytes.lighten(0.0015);
+        floats = floats.lighten(0.0015);
+    }
+    assert_eq!(bytes, gray, "byte chain rounds every step away");
+    let result = floats.to_color();
+    assert!(result.r.abs_diff(expected) <= 1, "{:?}", result);
+    assert_eq!(result, Color::new(102, 102, 102));
+
+    // mixing 10% towards white five times leaves 0.9^5 of the distance
+    let start = Color::new(200, 37, 91);
+    let white = Color::new(255, 255, 255);
+    let analytic = |c: u8| c as f32 + (255.0 - c as f32) * (1.0 - 0.9_f32.powi(5));
+    let mut bytes = start;
+    let mut floats = ColorF::from(start);
+    for _ in 0..5 {
+        bytes = bytes.mix(&white, 0.1);
+        floats = floats.mix(&ColorF::from(white), 0.1);
+    }
+    let result = floats.to_color();
+    for (got, c) in [(result.r, start.r), (result.g, start.g), (result.b, start.b)] {
+        assert!((got as f32 - analytic(c)).abs() <= 1.0, "{:?}", result);
+    }
+    let drift = |got: u8, c: u8| (got as f32 - analytic(c)).abs();
+    assert!(drift(bytes.g, start.g) > drift(result.g, start.g));
+}
+
+#[test]
+fn test_colorf_blend_matches_color() {
+    let (src, dst) = (Color::new(200, 40, 90), Color::new(30, 160, 220));
+    for mode in [BlendMode::Multiply, BlendMode::Screen, BlendMode::Overlay, BlendMode::SoftLight] {
+        let f = ColorF::from(src).blend(&ColorF::from(dst), mode);
+        assert_eq!(f.to_color(), src.blend(&dst, mode));
+    }
+}
+
+#[test]
+fn test_color16_widening() {
+    let c = Color16::from(Color::new(0xab, 0x00, 0xff));
+    assert_eq!(c, Color16::new(0xabab, 0x0000, 0xffff));
+    for v in 0..=255u8 {
+        let c = Color::new(v, v, v);
+        assert_eq!(Color16::from(c).to_color(), c);
+    }
+    // narrowing rounds to the nearest byte
+    assert_eq!(Color16::new(0x8000, 0x7f7f, 0x807f).to_color(), Color::new(0x80, 0x7f, 0x80));
+    assert_eq!(Color16::new(0x1234, 0x5678, 0x9abc).to_hex(), "#123456789abc");
+}
+
+#[test]
+fn test_parse_color16_precision() {
+    use colourss::{parse_color16, parse_color16_with, ParseOptions};
+
+    let gray = parse_color16("rgb(50% 50% 50%)").unwrap();
+    assert_eq!(gray, Color16::new(0x8000, 0x8000, 0x8000));
+    // an 8-bit parse would land on 0x8080
+    assert_ne!(gray, Color16::from(parse_color("rgb(50% 50% 50%)").unwrap()));
+
+    let third = parse_color16("rgb(33.333% 0% 0%)").unwrap();
+    assert!(third.r.abs_diff(21845) <= 1, "{:?}", third);
+    assert_eq!(third.to_color(), parse_color("rgb(33.333% 0% 0%)").unwrap());
+
+    assert_eq!(parse_color16("hsl(0 0% 50%)").unwrap().r, 0x8000);
+    assert_eq!(parse_color16("#f00").unwrap(), Color16::new(0xffff, 0, 0));
+    assert_eq!(parse_color16("rgb(255 128 0)").unwrap(), Color16::new(0xffff, 0x8080, 0));
+    #[cfg(feature = "named-colors")]
+    assert_eq!(parse_color16("white").unwrap(), Color16::new(0xffff, 0xffff, 0xffff));
+    assert!(parse_color16("rgb(101% 0% 0%)").is_err());
+    let clamped = parse_color16_with("rgb(101% 0% 0%)", &ParseOptions::lenient()).unwrap();
+    assert_eq!(clamped.r, 0xffff);
+}
+
+#[test]
+fn test_ordering_and_sort_keys() {
+    use std::collections::BTreeSet;
+
+    let red = Color::new(255, 0, 0);
+    let dark_red = Color::new(128, 0, 0);
+    let green = Color::new(0, 128, 0);
+    let blue = Color::new(0, 0, 255);
+    let black = Color::new(0, 0, 0);
+    let white = Color::new(255, 255, 255);
+    let gray = Color::new(128, 128, 128);
+
+    let set: BTreeSet<Color> = [white, red, blue, green, black, red].into_iter().collect();
+    let ordered: Vec<Color> = set.into_iter().collect();
+    assert_eq!(ordered, [black, blue, green, red, white]);
+    assert!(Color::new(1, 0, 0) > Color::new(0, 255, 255));
+    assert_eq!(red.sort_key_rgb(), (255, 0, 0));
+
+    let mut colors = vec![blue, white, red, gray, green, dark_red, black];
+    colors.sort_by_key(Color::sort_key_hue);
+    assert_eq!(colors, [black, gray, white, dark_red, red, green, blue]);
+    assert_eq!(red.sort_key_hue(), (1, 128, 255));
+    assert_eq!(green.sort_key_hue().0, 1201);
+    assert_eq!(gray.sort_key_hue(), (0, 128, 0));
+
+    let mut colors = vec![white, blue, red, green, black];
+    colors.sort_by_key(Color::sort_key_luminance);
+    assert_eq!(colors, [black, blue, green, red, white]);
+
+    // equal keys keep their order
+    let (a, b) = (Color::new(10, 0, 0), Color::new(20, 0, 0));
+    let mut colors = vec![b, gray, a, Color::new(200, 0, 0)];
+    colors.sort_by_key(|c| c.sort_key_hue().0);
+    assert_eq!(colors, [gray, b, a, Color::new(200, 0, 0)]);
+}
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_format() {
+    const NAMED: ColorFormat = ColorFormat::Named { fallback: &ColorFormat::RgbLegacy };
+    let orange = Color::new(255, 102, 0);
+    let teal = Color::new(0, 128, 128);
+    let table = [
+        (ColorFormat::HexLower, "#ff6600", "#008080"),
+        (ColorFormat::HexUpper, "#FF6600", "#008080"),
+        (ColorFormat::HexShort, "#f60", "#008080"),
+        (ColorFormat::RgbLegacy, "rgb(255, 102, 0)", "rgb(0, 128, 128)"),
+        (ColorFormat::RgbModern, "rgb(255 102 0)", "rgb(0 128 128)"),
+        (ColorFormat::HslLegacy, "hsl(24, 100%, 50%)", "hsl(180, 100%, 25.1%)"),
+        (ColorFormat::HslModern, "hsl(24 100% 50%)", "hsl(180 100% 25.1%)"),
+        (NAMED, "rgb(255, 102, 0)", "teal"),
+    ];
+    for (format, a, b) in table {
+        assert_eq!(orange.format(format), a);
+        assert_eq!(teal.format(format), b);
+        // and back, to the same color and the same format
+        for (color, text) in [(orange, a), (teal, b)] {
+            let (parsed, detected) = parse_color_detailed(text).unwrap();
+            assert_eq!(parsed, color, "{}", text);
+            assert_eq!(parsed.format(detected), text);
+        }
+    }
+    // two decimals at most
+    assert_eq!(Color::new(255, 69, 10).format(ColorFormat::HslModern), "hsl(14.45 100% 51.96%)");
+    // a named fallback is written as hex
+    let nested = ColorFormat::Named { fallback: &NAMED };
+    assert_eq!(orange.format(nested), "#ff6600");
+    assert_eq!(teal.format(nested), "teal");
+}
+
+#[test]
+fn test_parse_color_detailed() {
+    let format = |input| parse_color_detailed(input).unwrap().1;
+    assert_eq!(format("#abc"), ColorFormat::HexShort);
+    assert_eq!(format("#abcd"), ColorFormat::HexShort);
+    assert_eq!(format("#AABBCC"), ColorFormat::HexUpper);
+    assert_eq!(format("#AaBbCc"), ColorFormat::HexLower);
+    assert_eq!(format("#112233"), ColorFormat::HexLower);
+    assert_eq!(format("#aabbcc80"), ColorFormat::HexLower);
+    assert_eq!(format(" rgba(1, 2, 3, 0.5) "), ColorFormat::RgbLegacy);
+    assert_eq!(format("RGB(1 2 3 / 50%)"), ColorFormat::RgbModern);
+    assert_eq!(format("hsla(1, 2%, 3%, 0.5)"), ColorFormat::HslLegacy);
+    assert_eq!(format("hsl(1 2% 3%)"), ColorFormat::HslModern);
+    #[cfg(feature = "named-colors")]
+    assert_eq!(format("SteelBlue"), ColorFormat::Named { fallback: &ColorFormat::HexLower });
+    assert!(parse_color_detailed("nope").is_err());
+}
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_to_css_shortest() {
+    let cases = [
+        (Color::new(255, 255, 255), "#fff"),
+        (Color::new(0, 0, 0), "#000"),
+        (Color::new(255, 0, 0), "red"),
+        (Color::new(0, 0, 128), "navy"),
+        (Color::new(210, 180, 140), "tan"),
+        (Color::new(0, 128, 128), "teal"),
+        (Color::new(128, 128, 128), "gray"),
+        (Color::new(0, 255, 255), "#0ff"),
+        (Color::new(0xff, 0x66, 0x00), "#f60"),
+        (Color::new(0x12, 0x34, 0x56), "#123456"),
+        (Color::new(0xc0, 0xff, 0xee), "#c0ffee"),
+        (Color::new(240, 248, 255), "#f0f8ff"),
+    ];
+    for (color, css) in cases {
+        assert_eq!(color.to_css_shortest(), css, "{:?}", color);
+    }
+    // never longer than hex, and always the same color again
+    for r in (0..=255).step_by(17) {
+        for (g, b) in [(0, 0), (128, 64), (255, 255), (34, 51)] {
+            let color = Color::new(r, g, b);
+            let css = color.to_css_shortest();
+            assert!(css.len() <= color.format(ColorFormat::HexShort).len(), "{}", css);
+            assert_eq!(parse_color(&css).unwrap(), color, "{}", css);
+        }
+    }
+}
@@ -0,0 +1,2 @@
+export const accent = "hsl(120, 100%, 25%)";
+export const text = "#1e3a8a";
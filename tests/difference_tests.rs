@@ -0,0 +1,122 @@
+use colourss::{Color, Cvd, Lab};
+
+// Sharma, Wu & Dalal (2005), table 1: (L1, a1, b1, L2, a2, b2, ΔE00)
+const SHARMA_PAIRS: [[f32; 7]; 34] = [
+    [50.0000, 2.6772, -79.7751, 50.0000, 0.0000, -82.7485, 2.0425],
+    [50.0000, 3.1571, -77.2803, 50.0000, 0.0000, -82.7485, 2.8615],
+    [50.0000, 2.8361, -74.0200, 50.0000, 0.0000, -82.7485, 3.4412],
+    [50.0000, -1.3802, -84.2814, 50.0000, 0.0000, -82.7485, 1.0000],
+    [50.0000, -1.1848, -84.8006, 50.0000, 0.0000, -82.7485, 1.0000],
+    [50.0000, -0.9009, -85.5211, 50.0000, 0.0000, -82.7485, 1.0000],
+    [50.0000, 0.0000, 0.0000, 50.0000, -1.0000, 2.0000, 2.3669],
+    [50.0000, -1.0000, 2.0000, 50.0000, 0.0000, 0.0000, 2.3669],
+    [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0009, 7.1792],
+    [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0010, 7.1792],
+    [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0011, 7.2195],
+    [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0012, 7.2195],
+    [50.0000, -0.0010, 2.4900, 50.0000, 0.0009, -2.4900, 4.8045],
+    [50.0000, -0.0010, 2.4900, 50.0000, 0.0010, -2.4900, 4.8045],
+    [50.0000, -0.0010, 2.4900, 50.0000, 0.0011, -2.4900, 4.7461],
+    [50.0000, 2.5000, 0.0000, 50.0000, 0.0000, -2.5000, 4.3065],
+    [50.0000, 2.5000, 0.0000, 73.0000, 25.0000, -18.0000, 27.1492],
+    [50.0000, 2.5000, 0.0000, 61.0000, -5.0000, 29.0000, 22.8977],
+    [50.0000, 2.5000, 0.0000, 56.0000, -27.0000, -3.0000, 31.9030],
+    [50.0000, 2.5000, 0.0000, 58.0000, 24.0000, 15.0000, 19.4535],
+    [50.0000, 2.5000, 0.0000, 50.0000, 3.1736, 0.5854, 1.0000],
+    [50.0000, 2.5000, 0.0000, 50.0000, 3.2972, 0.0000, 1.0000],
+    [50.0000, 2.5000, 0.0000, 50.0000, 1.8634, 0.5757, 1.0000],
+    [50.0000, 2.5000, 0.0000, 50.0000, 3.2592, 0.3350, 1.0000],
+    [60.2574, -34.0099, 36.2677, 60.4626, -34.1751, 39.4387, 1.2644],
+    [63.0109, -31.0961, -5.8663, 62.8187, -29.7946, -4.0864, 1.2630],
+    [61.2901, 3.7196, -5.3901, 61.4292, 2.2480, -4.9620, 1.8731],
+    [35.0831, -44.1164, 3.7933, 35.0232, -40.0716, 1.5901, 1.8645],
+    [22.7233, 20.0904, -46.6940, 23.0331, 14.9730, -42.5619, 2.0373],
+    [36.4612, 47.8580, 18.3852, 36.2715, 50.5065, 21.2231, 1.4146],
+    [90.8027, -2.0831, 1.4410, 91.1528, -1.6435, 0.0447, 1.4441],
+    [90.9257, -0.5406, -0.9208, 88.6381, -0.8985, -0.7239, 1.5381],
+    [6.7747, -0.2908, -2.4247, 5.8714, -0.0985, -2.2286, 0.6377],
+    [2.0776, 0.0795, -1.1350, 0.9033, -0.0636, -0.5514, 0.9082],
+];
+
+#[test]
+fn test_delta_e2000_sharma_pairs() {
+    for (i, p) in SHARMA_PAIRS.iter().enumerate() {
+        let lab1 = Lab { l: p[0], a: p[1], b: p[2] };
+        let lab2 = Lab { l: p[3], a: p[4], b: p[5] };
+        let de = lab1.delta_e2000(&lab2);
+        assert!(
+            (de - p[6]).abs() < 1e-3,
+            "pair {}: expected {}, got {}",
+            i + 1,
+            p[6],
+            de
+        );
+        // the formula is symmetric
+        assert!((lab2.delta_e2000(&lab1) - de).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn test_to_lab_reference() {
+    let white = Color { r: 255, g: 255, b: 255 }.to_lab();
+    assert!((white.l - 100.0).abs() < 0.01);
+    assert!(white.a.abs() < 0.01 && white.b.abs() < 0.01);
+
+    let red = Color { r: 255, g: 0, b: 0 }.to_lab();
+    assert!((red.l - 53.24).abs() < 0.05);
+    assert!((red.a - 80.09).abs() < 0.05);
+    assert!((red.b - 67.20).abs() < 0.05);
+}
+
+#[test]
+fn test_delta_e_colors() {
+    let a = Color { r: 255, g: 0, b: 0 };
+    let b = Color { r: 254, g: 0, b: 0 };
+    assert_eq!(a.delta_e2000(&a), 0.0);
+    assert!(a.delta_e2000(&b) < 1.0);
+    assert!(a.delta_e76(&b) < 1.0);
+    let black = Color { r: 0, g: 0, b: 0 };
+    let white = Color { r: 255, g: 255, b: 255 };
+    assert!((black.delta_e76(&white) - 100.0).abs() < 0.01);
+    assert!((black.delta_e2000(&white) - 100.0).abs() < 0.01);
+}
+
+#[test]
+fn test_cvd_deuteranopia_confuses_red_green() {
+    let red = Color { r: 255, g: 0, b: 0 };
+    let green = Color { r: 0, g: 128, b: 0 };
+    let blue = Color { r: 0, g: 0, b: 255 };
+    let yellow = Color { r: 255, g: 255, b: 0 };
+
+    let normal = red.delta_e2000(&green);
+    let simulated = red
+        .simulate(Cvd::Deuteranopia)
+        .delta_e2000(&green.simulate(Cvd::Deuteranopia));
+    assert!(simulated < normal / 3.0, "{} vs {}", simulated, normal);
+
+    // blue and yellow stay easy to tell apart
+    let by = blue
+        .simulate(Cvd::Deuteranopia)
+        .delta_e2000(&yellow.simulate(Cvd::Deuteranopia));
+    assert!(by > 50.0, "{}", by);
+}
+
+#[test]
+fn test_cvd_achromatopsia_and_batch() {
+    let colors = [
+        Color { r: 255, g: 0, b: 0 },
+        Color { r: 10, g: 200, b: 90 },
+        Color { r: 255, g: 255, b: 255 },
+    ];
+    let gray = Cvd::Achromatopsia.simulate_all(&colors);
+    assert_eq!(gray.len(), 3);
+    for (c, g) in colors.iter().zip(&gray) {
+        assert_eq!(*g, c.grayscale());
+    }
+    // white and black are unaffected by every deficiency
+    for cvd in [Cvd::Protanopia, Cvd::Deuteranopia, Cvd::Tritanopia] {
+        assert_eq!(Color { r: 0, g: 0, b: 0 }.simulate(cvd), Color { r: 0, g: 0, b: 0 });
+        let w = Color { r: 255, g: 255, b: 255 }.simulate(cvd);
+        assert!(w.r >= 254 && w.g >= 254 && w.b >= 254);
+    }
+}
@@ -0,0 +1,91 @@
+// Drives the C API through real `extern "C"` declarations, the same way a
+// C caller linking against the library would see it.
+#![cfg(feature = "ffi")]
+
+use std::ffi::{c_char, c_int, CStr};
+use std::ptr;
+
+use colourss::ffi::{
+    ColourssColor, COLOURSS_ERR_BUFFER_TOO_SMALL, COLOURSS_ERR_CSS_WIDE_KEYWORD,
+    COLOURSS_ERR_INVALID_COMPONENT, COLOURSS_ERR_INVALID_HEX, COLOURSS_ERR_INVALID_UTF8,
+    COLOURSS_ERR_NULL_POINTER, COLOURSS_ERR_UNKNOWN_NAME,
+};
+
+unsafe extern "C" {
+    fn colourss_parse(input: *const c_char, out: *mut ColourssColor) -> c_int;
+    fn colourss_error_message(code: c_int) -> *const c_char;
+    fn colourss_to_hex(color: *const ColourssColor, buf: *mut c_char, len: usize) -> c_int;
+}
+
+fn parse(input: &CStr) -> Result<ColourssColor, c_int> {
+    let mut out = ColourssColor { r: 0, g: 0, b: 0, a: 0.0 };
+    match unsafe { colourss_parse(input.as_ptr(), &mut out) } {
+        0 => Ok(out),
+        code => Err(code),
+    }
+}
+
+fn message(code: c_int) -> String {
+    unsafe { CStr::from_ptr(colourss_error_message(code)) }
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_ffi_parse() {
+    assert_eq!(parse(c"#FF8000"), Ok(ColourssColor { r: 255, g: 128, b: 0, a: 1.0 }));
+    assert_eq!(parse(c"rgba(0, 0, 255, 0.5)"), Ok(ColourssColor { r: 0, g: 0, b: 255, a: 0.5 }));
+    #[cfg(feature = "named-colors")]
+    assert_eq!(parse(c"navy"), Ok(ColourssColor { r: 0, g: 0, b: 128, a: 1.0 }));
+}
+
+#[test]
+fn test_ffi_error_codes() {
+    assert_eq!(parse(c"#12"), Err(COLOURSS_ERR_INVALID_HEX));
+    assert_eq!(parse(c"rgb(300, 0, 0)"), Err(COLOURSS_ERR_INVALID_COMPONENT));
+    assert_eq!(parse(c"not-a-color"), Err(COLOURSS_ERR_UNKNOWN_NAME));
+    assert_eq!(parse(c"\xff\xfe"), Err(COLOURSS_ERR_INVALID_UTF8));
+    assert_eq!(parse(c"inherit"), Err(COLOURSS_ERR_CSS_WIDE_KEYWORD));
+
+    let mut out = ColourssColor { r: 1, g: 2, b: 3, a: 1.0 };
+    assert_eq!(unsafe { colourss_parse(ptr::null(), &mut out) }, COLOURSS_ERR_NULL_POINTER);
+    assert_eq!(unsafe { colourss_parse(c"red".as_ptr(), ptr::null_mut()) }, COLOURSS_ERR_NULL_POINTER);
+    // failures leave the output alone
+    assert_eq!(unsafe { colourss_parse(c"#12".as_ptr(), &mut out) }, COLOURSS_ERR_INVALID_HEX);
+    assert_eq!(out, ColourssColor { r: 1, g: 2, b: 3, a: 1.0 });
+}
+
+#[test]
+fn test_ffi_error_message() {
+    assert_eq!(message(0), "Success");
+    assert_eq!(message(COLOURSS_ERR_INVALID_HEX), "Invalid hex code format");
+    assert_eq!(message(COLOURSS_ERR_BUFFER_TOO_SMALL), "Output buffer too small");
+    assert_eq!(message(COLOURSS_ERR_CSS_WIDE_KEYWORD), "CSS-wide keyword");
+    assert_eq!(message(12345), "Unknown error code");
+}
+
+#[test]
+fn test_ffi_to_hex() {
+    let color = ColourssColor { r: 255, g: 128, b: 0, a: 0.25 };
+    let mut buf = [0x7f as c_char; 16];
+    let written = unsafe { colourss_to_hex(&color, buf.as_mut_ptr(), buf.len()) };
+    assert_eq!(written, 7);
+    let hex = unsafe { CStr::from_ptr(buf.as_ptr()) };
+    assert_eq!(hex.to_str().unwrap(), "#ff8000");
+
+    // exactly enough room for the NUL
+    let mut tight = [0 as c_char; 8];
+    assert_eq!(unsafe { colourss_to_hex(&color, tight.as_mut_ptr(), tight.len()) }, 7);
+
+    let mut small = [0x7f as c_char; 7];
+    assert_eq!(
+        unsafe { colourss_to_hex(&color, small.as_mut_ptr(), small.len()) },
+        COLOURSS_ERR_BUFFER_TOO_SMALL
+    );
+    assert!(small.iter().all(|&b| b == 0x7f));
+    assert_eq!(
+        unsafe { colourss_to_hex(ptr::null(), small.as_mut_ptr(), small.len()) },
+        COLOURSS_ERR_NULL_POINTER
+    );
+}
@@ -0,0 +1,52 @@
+// Inputs that once panicked or misparsed, found by the targets in fuzz/.
+// Each one is replayed here so they stay fixed without a nightly toolchain.
+
+use std::fs;
+use std::path::Path;
+
+use colourss::{find_colors, parse_color, ParseError};
+
+const CRASHERS: &[&[u8]] = &[
+    // hex slicing landed inside a multi-byte char
+    &[35, 1, 223, 155, 56, 48, 56, 48, 65],
+    "#aéb".as_bytes(),
+    "#€€".as_bytes(),
+    "x #ab\u{301}".as_bytes(),
+];
+
+fn replay(data: &[u8]) {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = parse_color(input);
+        for m in find_colors(input) {
+            assert!(parse_color(&input[m.start..m.end]).is_ok());
+        }
+    }
+}
+
+#[test]
+fn test_fuzz_crashers() {
+    for data in CRASHERS {
+        replay(data);
+    }
+    assert!(matches!(parse_color("#aéb"), Err(ParseError::InvalidHexFormat(_))));
+}
+
+#[test]
+fn test_hex_sign_rejected() {
+    // `from_str_radix` takes a leading `+`, so these used to parse
+    assert!(matches!(parse_color("#+f+f+f"), Err(ParseError::InvalidHexFormat(_))));
+    assert!(matches!(parse_color("#+fff"), Err(ParseError::InvalidHexFormat(_))));
+}
+
+#[test]
+fn test_fuzz_corpus() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/corpus");
+    let mut count = 0;
+    for target in ["parse_color", "find_colors"] {
+        for entry in fs::read_dir(root.join(target)).unwrap() {
+            replay(&fs::read(entry.unwrap().path()).unwrap());
+            count += 1;
+        }
+    }
+    assert!(count > 0);
+}
@@ -0,0 +1,117 @@
+use colourss::{Color, Gradient, HuePath, InterpSpace};
+
+const BLACK: Color = Color { r: 0, g: 0, b: 0 };
+const WHITE: Color = Color { r: 255, g: 255, b: 255 };
+const RED: Color = Color { r: 255, g: 0, b: 0 };
+const LIME: Color = Color { r: 0, g: 255, b: 0 };
+const BLUE: Color = Color { r: 0, g: 0, b: 255 };
+
+#[test]
+fn test_gradient_two_stops() {
+    let g = Gradient::new(vec![(0.0, BLACK), (1.0, WHITE)]);
+    assert_eq!(g.sample(0.5), Color { r: 128, g: 128, b: 128 });
+    assert_eq!(g.sample(0.0), BLACK);
+    assert_eq!(g.sample(1.0), WHITE);
+    // out of range t is clamped
+    assert_eq!(g.sample(-2.0), BLACK);
+    assert_eq!(g.sample(7.0), WHITE);
+}
+
+#[test]
+fn test_gradient_multi_stop() {
+    let g = Gradient::new(vec![(0.0, RED), (0.25, LIME), (1.0, BLUE)]);
+    assert_eq!(g.sample(0.25), LIME);
+    assert_eq!(g.sample(0.125), RED.mix(&LIME, 0.5));
+    assert_eq!(g.sample(0.625), LIME.mix(&BLUE, 0.5));
+
+    // stops that don't reach the ends hold their color
+    let g = Gradient::new(vec![(0.2, RED), (0.8, BLUE)]);
+    assert_eq!(g.sample(0.1), RED);
+    assert_eq!(g.sample(0.9), BLUE);
+}
+
+#[test]
+fn test_gradient_position_fixup() {
+    // unpositioned stops are spread evenly
+    let g = Gradient::from_colors(vec![RED, LIME, BLUE]);
+    assert_eq!(g.stops(), &[(0.0, RED), (0.5, LIME), (1.0, BLUE)]);
+
+    let g = Gradient::with_positions(vec![(None, RED), (Some(0.2), LIME), (None, BLUE), (None, WHITE)]);
+    let positions: Vec<f32> = g.stops().iter().map(|s| s.0).collect();
+    assert_eq!(positions[0], 0.0);
+    assert_eq!(positions[1], 0.2);
+    assert!((positions[2] - 0.6).abs() < 1e-6);
+    assert_eq!(positions[3], 1.0);
+
+    // a stop before an earlier one is clamped, giving a hard edge
+    let g = Gradient::new(vec![(0.0, RED), (0.5, LIME), (0.3, BLUE), (1.0, WHITE)]);
+    assert_eq!(g.stops()[2].0, 0.5);
+    assert_eq!(g.sample(0.49), RED.mix(&LIME, 0.98));
+    assert_eq!(g.sample(0.5), BLUE);
+}
+
+#[test]
+fn test_gradient_non_finite_positions() {
+    // NaN and infinite positions count as missing and are spread evenly
+    let g = Gradient::new(vec![(0.0, RED), (f32::NAN, LIME), (1.0, BLUE)]);
+    assert_eq!(g.stops(), &[(0.0, RED), (0.5, LIME), (1.0, BLUE)]);
+    assert_eq!(g.sample(0.5), LIME);
+
+    let g = Gradient::new(vec![(f32::NAN, RED), (0.5, BLUE)]);
+    assert_eq!(g.stops(), &[(0.0, RED), (0.5, BLUE)]);
+    assert_eq!(g.sample(0.25), RED.mix(&BLUE, 0.5));
+
+    let g = Gradient::with_positions(vec![(Some(0.5), RED), (Some(f32::INFINITY), BLUE)]);
+    assert_eq!(g.stops(), &[(0.5, RED), (1.0, BLUE)]);
+    let g = Gradient::new(vec![(f32::NEG_INFINITY, RED), (0.5, LIME), (f32::NAN, BLUE)]);
+    assert_eq!(g.stops()[0].0, 0.0);
+    assert_eq!(g.stops()[2].0, 1.0);
+}
+
+#[test]
+fn test_gradient_steps() {
+    let g = Gradient::new(vec![(0.0, BLACK), (1.0, WHITE)]);
+    let steps = g.steps(5);
+    assert_eq!(steps.len(), 5);
+    assert_eq!(steps[0], BLACK);
+    assert_eq!(steps[4], WHITE);
+    assert!(g.steps(0).is_empty());
+
+    let linear = g.clone().in_linear_light();
+    assert_eq!(linear.sample(0.5), BLACK.mix_linear(&WHITE, 0.5));
+    assert_eq!(linear, g.clone().in_space(InterpSpace::LinearRgb));
+}
+
+#[test]
+fn test_gradient_in_space() {
+    let red = Color { r: 255, g: 0, b: 0 };
+    let blue = Color { r: 0, g: 0, b: 255 };
+    let gradient = Gradient::from_colors(vec![red, blue]);
+    for space in [
+        InterpSpace::Srgb,
+        InterpSpace::LinearRgb,
+        InterpSpace::Hsl { hue_path: HuePath::Longer },
+        InterpSpace::Oklab,
+        InterpSpace::Oklch { hue_path: HuePath::Decreasing },
+    ] {
+        let in_space = gradient.clone().in_space(space);
+        for t in [0.0, 0.3, 0.5, 1.0] {
+            assert_eq!(in_space.sample(t), red.mix_in(space, &blue, t), "{:?} at {}", space, t);
+        }
+    }
+    // through green the long way round
+    let longer = gradient.in_space(InterpSpace::Hsl { hue_path: HuePath::Longer });
+    assert_eq!(longer.sample(0.5), Color { r: 0, g: 255, b: 0 });
+}
+
+#[test]
+fn test_gradient_sample_with() {
+    let red = Color { r: 255, g: 0, b: 0 };
+    let blue = Color { r: 0, g: 0, b: 255 };
+    let gradient = Gradient::from_colors(vec![red, blue]);
+    assert_eq!(gradient.sample_with(0.5, Color::mix), gradient.sample(0.5));
+    assert_eq!(gradient.sample_with(0.5, Color::mix_oklab), red.mix_oklab(&blue, 0.5));
+    // endpoints never go through `mix`
+    assert_eq!(gradient.sample_with(0.0, |_, _, _| Color { r: 1, g: 2, b: 3 }), red);
+    assert_eq!(gradient.sample_with(1.0, |_, _, _| Color { r: 1, g: 2, b: 3 }), blue);
+}
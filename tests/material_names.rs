@@ -0,0 +1,68 @@
+// Run with `cargo test --features material-names --test material_names`;
+// without the feature this file is empty.
+#![cfg(feature = "material-names")]
+
+use colourss::{material_colors, parse_color, ParseError};
+
+#[test]
+fn test_material_values() {
+    for (name, hex) in [
+        ("red-500", "#f44336"),
+        ("red-a700", "#d50000"),
+        ("pink-50", "#fce4ec"),
+        ("deep-purple-500", "#673ab7"),
+        ("indigo-a200", "#536dfe"),
+        ("light-blue-900", "#01579b"),
+        ("teal-300", "#4db6ac"),
+        ("green-a400", "#00e676"),
+        ("amber-a200", "#ffd740"),
+        ("deep-orange-600", "#f4511e"),
+        ("brown-500", "#795548"),
+        ("grey-900", "#212121"),
+        ("blue-grey-500", "#607d8b"),
+    ] {
+        assert_eq!(parse_color(name).unwrap().to_hex(), hex, "{}", name);
+    }
+}
+
+#[test]
+fn test_material_spelling() {
+    let purple = parse_color("deep-purple-500").unwrap();
+    for input in ["Deep-Purple-500", "deep purple 500", "DEEP PURPLE-500", "  deep-purple-500 "] {
+        assert_eq!(parse_color(input).unwrap(), purple, "{:?}", input);
+    }
+    assert_eq!(parse_color("Amber A200").unwrap(), parse_color("amber-a200").unwrap());
+
+    // no accents for brown, grey and blue grey, and no shades in between
+    for input in ["brown-a100", "grey-a200", "red-550", "amber", "deep-purple", "deep--purple-500", "deeppurple-500"] {
+        assert!(matches!(parse_color(input), Err(ParseError::UnknownColorName(_))), "{:?}", input);
+    }
+}
+
+#[test]
+fn test_material_table() {
+    let names: Vec<&str> = material_colors().map(|(name, _)| name).collect();
+    assert_eq!(names.len(), 19 * 10 + 16 * 4);
+    assert!(names.windows(2).all(|w| w[0] < w[1]));
+    for (name, color) in material_colors() {
+        assert_eq!(parse_color(name).unwrap(), color, "{}", name);
+    }
+}
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_material_keeps_css_names() {
+    // CSS keywords keep their meaning
+    let names: Vec<&str> = material_colors().map(|(name, _)| name).collect();
+    assert!(colourss::named_colors().all(|(name, _)| !names.contains(&name)));
+    assert_eq!(parse_color("red").unwrap().to_hex(), "#ff0000");
+    assert_eq!(parse_color("grey").unwrap().to_hex(), "#808080");
+}
+
+#[cfg(feature = "tailwind-names")]
+#[test]
+fn test_material_before_tailwind() {
+    // both palettes have `red-500`; Tailwind's needs its prefix
+    assert_eq!(parse_color("red-500").unwrap().to_hex(), "#f44336");
+    assert_eq!(parse_color("tailwind:red-500").unwrap().to_hex(), "#ef4444");
+}
@@ -0,0 +1,34 @@
+// Run with `cargo test --no-default-features --test no_named_colors`;
+// with the default features this file is empty.
+#![cfg(not(feature = "named-colors"))]
+
+use colourss::{find_colors, parse_color, parse_color_legacy_html, Color, ParseError};
+
+#[test]
+fn test_names_are_unknown() {
+    for name in ["red", "steelblue", "RebeccaPurple", "transparent"] {
+        assert!(matches!(parse_color(name), Err(ParseError::UnknownColorName(n)) if n == name));
+    }
+}
+
+#[test]
+fn test_other_syntaxes_still_parse() {
+    let red = Color::new(255, 0, 0);
+    assert_eq!(parse_color("#f00").unwrap(), red);
+    assert_eq!(parse_color("rgb(255 0 0)").unwrap(), red);
+    assert_eq!(parse_color("hsl(0, 100%, 50%)").unwrap(), red);
+}
+
+#[test]
+fn test_scan_skips_words() {
+    let found = find_colors("color: red; border: 1px solid #00f;");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].color, Color::new(0, 0, 255));
+}
+
+#[test]
+fn test_legacy_html_reads_names_as_hex() {
+    // what a browser without keywords would make of it
+    assert_eq!(parse_color_legacy_html("red").unwrap(), Color::new(0x00, 0x0e, 0x0d));
+    assert!(parse_color_legacy_html("transparent").is_err());
+}
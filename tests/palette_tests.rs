@@ -0,0 +1,244 @@
+use colourss::{dominant_colors, quantize, Color, GplError, Palette};
+
+const RED: Color = Color { r: 255, g: 0, b: 0 };
+const LIME: Color = Color { r: 0, g: 255, b: 0 };
+const BLUE: Color = Color { r: 0, g: 0, b: 255 };
+const BLACK: Color = Color { r: 0, g: 0, b: 0 };
+const WHITE: Color = Color { r: 255, g: 255, b: 255 };
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_palette_from_strs() {
+    use colourss::ParseError;
+
+    let (palette, errors) = Palette::from_strs(["red", "#00ff00", "nope", "rgb(0, 0, 255)", "#12"]);
+    assert_eq!(palette.as_slice(), &[RED, LIME, BLUE]);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].0, 2);
+    assert!(matches!(errors[0].1, ParseError::UnknownColorName(_)));
+    assert_eq!(errors[1].0, 4);
+}
+
+#[test]
+fn test_palette_dedup() {
+    let mut palette = Palette::from(vec![
+        RED,
+        BLUE,
+        RED,
+        Color { r: 254, g: 0, b: 0 },
+        LIME,
+    ]);
+    palette.dedup(0.0);
+    assert_eq!(palette.len(), 4);
+
+    palette.dedup(1.0);
+    assert_eq!(palette.as_slice(), &[RED, BLUE, LIME]);
+}
+
+#[test]
+fn test_palette_nearest() {
+    let palette: Palette = vec![BLACK, RED, WHITE].into_iter().collect();
+    assert_eq!(palette.nearest(&Color { r: 200, g: 30, b: 20 }), Some((1, RED)));
+    assert_eq!(palette.nearest(&Color { r: 240, g: 240, b: 240 }), Some((2, WHITE)));
+    assert_eq!(Palette::new().nearest(&RED), None);
+
+    assert!(palette.contains_within(&Color { r: 254, g: 0, b: 0 }, 1.0));
+    assert!(!palette.contains_within(&BLUE, 10.0));
+}
+
+#[test]
+fn test_palette_sorting() {
+    let gray = Color { r: 128, g: 128, b: 128 };
+    let mut palette = Palette::from(vec![BLUE, WHITE, LIME, gray, RED, BLACK]);
+    palette.sort_by_hue();
+    assert_eq!(palette.as_slice(), &[BLACK, gray, WHITE, RED, LIME, BLUE]);
+
+    palette.sort_by_luminance();
+    assert_eq!(palette.as_slice(), &[BLACK, BLUE, RED, gray, LIME, WHITE]);
+
+    let pink = Color { r: 255, g: 192, b: 203 };
+    let mut mixed = Palette::from(vec![RED, pink, gray, BLUE]);
+    mixed.sort_by_saturation();
+    assert_eq!(mixed.as_slice(), &[gray, RED, pink, BLUE]);
+    mixed.sort_by(|a, b| b.r.cmp(&a.r));
+    assert_eq!(mixed.as_slice(), &[RED, pink, gray, BLUE]);
+    let mut calls = 0;
+    mixed.sort_by_cached_key(|c| {
+        calls += 1;
+        c.b
+    });
+    assert_eq!(mixed.as_slice(), &[RED, gray, pink, BLUE]);
+    assert_eq!(calls, 4);
+
+    let colors: Vec<Color> = palette.into();
+    assert_eq!(colors.len(), 6);
+}
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_palette_to_gpl() {
+    let palette = Palette::from(vec![RED, Color { r: 18, g: 52, b: 86 }, WHITE]);
+    let expected = concat!(
+        "GIMP Palette\n",
+        "Name: Brand\n",
+        "Columns: 16\n",
+        "#\n",
+        "255   0   0\tred\n",
+        " 18  52  86\t#123456\n",
+        "255 255 255\twhite\n",
+    );
+    assert_eq!(palette.to_gpl("Brand"), expected);
+
+    let mut buf = Vec::new();
+    palette.write_gpl(&mut buf, "Brand").unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), expected);
+}
+
+#[test]
+fn test_palette_from_gpl_tango() {
+    let palette = Palette::from_gpl(include_str!("data/tango.gpl")).unwrap();
+    assert_eq!(palette.name(), Some("Tango Icons"));
+    assert_eq!(palette.len(), 27);
+    assert_eq!(palette.as_slice()[0], Color { r: 252, g: 233, b: 79 });
+    assert_eq!(palette.label(0), Some("Butter 1"));
+    assert_eq!(palette.label(9), Some("Sky Blue 1"));
+    assert_eq!(palette.as_slice()[26], Color { r: 46, g: 52, b: 54 });
+}
+
+#[test]
+fn test_palette_from_gpl_whitespace() {
+    let palette = Palette::from_gpl(include_str!("data/loose.gpl")).unwrap();
+    assert_eq!(palette.as_slice(), &[RED, LIME, BLUE]);
+    assert_eq!(palette.label(0), Some("Untitled"));
+    assert_eq!(palette.label(1), None);
+    assert_eq!(palette.label(2), Some("Deep blue   sea"));
+}
+
+#[test]
+fn test_palette_from_gpl_errors() {
+    let broken = include_str!("data/broken.gpl");
+    assert_eq!(
+        Palette::from_gpl(broken),
+        Err(GplError::InvalidRow { line: 5, content: "12 300 4\ttoo big".to_string() })
+    );
+
+    let (palette, warnings) = Palette::from_gpl_lenient(broken).unwrap();
+    assert_eq!(palette.as_slice(), &[RED, BLACK]);
+    assert_eq!(warnings.len(), 2);
+    assert!(matches!(warnings[1], GplError::InvalidRow { line: 6, .. }));
+
+    assert_eq!(Palette::from_gpl("255 0 0\n"), Err(GplError::MissingHeader));
+}
+
+#[test]
+fn test_palette_gpl_round_trip() {
+    let original = Palette::from_gpl(include_str!("data/tango.gpl")).unwrap();
+    let exported = original.to_gpl("Tango Icons");
+    let reimported = Palette::from_gpl(&exported).unwrap();
+    assert_eq!(reimported, original);
+
+    // unlabeled colors come back labeled with their keyword or hex
+    let plain = Palette::from(vec![RED, Color { r: 1, g: 2, b: 3 }]);
+    let back = Palette::from_gpl(&plain.to_gpl("Plain")).unwrap();
+    assert_eq!(back.as_slice(), plain.as_slice());
+    #[cfg(feature = "named-colors")]
+    assert_eq!(back.label(0), Some("red"));
+    assert_eq!(back.label(1), Some("#010203"));
+}
+
+#[test]
+fn test_quantize_two_clusters() {
+    let mut colors = Vec::new();
+    for i in 0..10u8 {
+        for j in 0..10u8 {
+            colors.push(Color { r: 195 + i, g: 15 + j, b: 20 });
+        }
+    }
+    for i in 0..100u8 {
+        colors.push(Color { r: 20, g: 20 + i % 5, b: 198 + i % 5 });
+    }
+
+    // the median on the red channel falls between the clusters
+    let palette = quantize(&colors, 2);
+    assert_eq!(palette.len(), 2);
+    // as many members each, so the lower box on red comes first
+    let blue = palette.as_slice()[0];
+    let red = palette.as_slice()[1];
+    assert!(red.delta_e76(&Color { r: 200, g: 20, b: 20 }) < 3.0, "{:?}", red);
+    assert!(blue.delta_e76(&Color { r: 20, g: 22, b: 200 }) < 3.0, "{:?}", blue);
+
+    // same input, same output
+    assert_eq!(quantize(&colors, 2), palette);
+}
+
+#[test]
+fn test_quantize_cuts_at_the_median() {
+    // two colors on each side, though the gray is much closer to black
+    let gray = Color { r: 10, g: 10, b: 10 };
+    let palette = quantize(&[BLACK, WHITE, BLACK, gray], 2);
+    assert_eq!(palette.as_slice(), &[BLACK, Color { r: 133, g: 133, b: 133 }]);
+}
+
+#[test]
+fn test_quantize_degenerate() {
+    assert!(quantize(&[], 4).is_empty());
+    assert!(quantize(&[RED], 0).is_empty());
+
+    // a single color input doesn't split
+    assert_eq!(quantize(&[RED, RED, RED], 4).as_slice(), &[RED]);
+
+    // asking for more colors than there are distinct values
+    let palette = quantize(&[RED, LIME, RED, BLUE], 10);
+    assert_eq!(palette.len(), 3);
+    assert_eq!(palette.as_slice()[0], RED);
+    assert!(palette.contains_within(&LIME, 0.0));
+    assert!(palette.contains_within(&BLUE, 0.0));
+}
+
+#[test]
+fn test_dominant_colors_three_clusters() {
+    // three noisy clusters of 600, 300 and 100 colors
+    let mut colors = Vec::new();
+    let mut noise = 0u32;
+    let mut jitter = |v: u8| {
+        noise = noise.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        v.wrapping_add((noise >> 16) as u8 % 9).wrapping_sub(4)
+    };
+    for (center, n) in [((200, 40, 40), 600), ((40, 160, 60), 300), ((50, 60, 190), 100)] {
+        for _ in 0..n {
+            colors.push(Color::new(jitter(center.0), jitter(center.1), jitter(center.2)));
+        }
+    }
+
+    let dominant = dominant_colors(&colors, 3, 50);
+    assert_eq!(dominant.len(), 3);
+    for ((color, share), (expected, expected_share)) in dominant.iter().zip([
+        (Color::new(200, 40, 40), 0.6),
+        (Color::new(40, 160, 60), 0.3),
+        (Color::new(50, 60, 190), 0.1),
+    ]) {
+        assert!(color.delta_e2000(&expected) < 1.5, "{:?} for {:?}", color, expected);
+        assert!((share - expected_share).abs() < 1e-6, "{} for {:?}", share, expected);
+    }
+    let total: f32 = dominant.iter().map(|(_, share)| share).sum();
+    assert!((total - 1.0).abs() < 1e-6);
+
+    // same input, same output
+    assert_eq!(dominant_colors(&colors, 3, 50), dominant);
+}
+
+#[test]
+fn test_dominant_colors_degenerate() {
+    assert!(dominant_colors(&[], 3, 10).is_empty());
+    assert!(dominant_colors(&[RED], 0, 10).is_empty());
+
+    // fewer distinct colors than clusters: each is its own
+    let dominant = dominant_colors(&[RED, LIME, RED, BLUE], 10, 10);
+    assert_eq!(dominant, vec![(RED, 0.5), (BLUE, 0.25), (LIME, 0.25)]);
+
+    // no refinement leaves the seeds, which are members
+    let dominant = dominant_colors(&[RED, RED, LIME, BLUE], 2, 0);
+    assert_eq!(dominant.len(), 2);
+    let total: f32 = dominant.iter().map(|(_, share)| share).sum();
+    assert_eq!(total, 1.0);
+}
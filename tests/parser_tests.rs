@@ -1,4 +1,4 @@
-use colourss::{parse_color, Color};
+use colourss::{parse_color, parse_color_with, Color, ParseOptions};
 
 #[test]
 fn test_rule1_hex_short() {
@@ -134,47 +134,724 @@ fn test_rule3_hsl_fail() {
 }
 
 #[test]
-fn test_rule4_named() {
+fn test_rule4_named_fail() {
+    // not in our small list
+    assert!(parse_color("notacolor").is_err());
+}
+
+#[test]
+fn test_overall_fail() {
+    // test empty
+    assert!(parse_color("").is_err());
+    assert!(parse_color("   ").is_err());
+    // test junk
+    assert!(parse_color("rgb(255, 0, 0)a").is_err()); // junk at end
+    assert!(parse_color("hello").is_err());
+}
+
+#[test]
+fn test_function_names_case_insensitive() {
+    let red = Color { r: 255, g: 0, b: 0 };
+    assert_eq!(parse_color("RGB(255, 0, 0)").unwrap(), red);
+    assert_eq!(parse_color("Rgba(255 0 0 / 1)").unwrap(), red);
+    assert_eq!(parse_color("HSL(0, 100%, 50%)").unwrap(), red);
+    assert!(parse_color("RGBX(255, 0, 0)").is_err());
+}
+
+#[test]
+fn test_strict_options() {
+    let strict = ParseOptions::strict();
+    for input in ["rgb(1, 2, 3)", "rgb(1 2 3 / 0.5)", "rgba(1, 2, 3, 0.5)", "hsl(120, 100%, 50%)", "#abc"] {
+        assert!(parse_color_with(input, &strict).is_ok(), "{}", input);
+    }
+    #[cfg(feature = "named-colors")]
+    assert!(parse_color_with("red", &strict).is_ok());
+    // fine by default, not by the spec
+    for input in ["rgb(1, 2 3)", "rgb(1, 2, 3 / 0.5)", "hsl(120 100% 50%, 1)", "hsl(120, 100, 50%)", "rgb(0 0 0 / 1.5)"] {
+        assert!(parse_color(input).is_ok(), "{}", input);
+        assert!(parse_color_with(input, &strict).is_err(), "{}", input);
+    }
+    let err = parse_color_with("hsl(120, 100%, 50)", &strict).unwrap_err();
+    assert_eq!(err.to_string(), "Invalid component value: L: 50 (needs a %) in \"hsl(120, 100%, 50)\"");
+}
+
+#[test]
+fn test_require_hsl_percent() {
+    let on = ParseOptions { require_hsl_percent: true, ..ParseOptions::default() };
+    let off = ParseOptions { require_hsl_percent: false, ..ParseOptions::strict() };
+    for input in ["hsl(240, 100, 50)", "hsl(240 100 50)", "hsla(240, 100, 50%, 0.5)", "hsl(240deg, 100%, 50)"] {
+        assert_eq!(parse_color_with(input, &off).unwrap(), Color::new(0, 0, 255), "{}", input);
+        assert!(parse_color_with(input, &on).is_err(), "{}", input);
+    }
+    assert_eq!(parse_color_with("hsl(240, 100%, 50%)", &on).unwrap(), Color::new(0, 0, 255));
+
+    // the first component without a % is the one named
+    let message = |input| parse_color_with(input, &on).unwrap_err().to_string();
+    assert_eq!(message("hsl(240, 100, 50)"), "Invalid component value: S: 100 (needs a %) in \"hsl(240, 100, 50)\"");
+    assert_eq!(message("hsl(240 100% 50 / 1)"), "Invalid component value: L: 50 (needs a %) in \"hsl(240 100% 50 / 1)\"");
+    // checked before the number itself, so a bad number without % says so too
+    assert!(message("hsl(240, 100%, x)").contains("L: x (needs a %)"));
+}
+
+#[test]
+fn test_lenient_options() {
+    let lenient = ParseOptions::lenient();
+    let parse = |input| parse_color_with(input, &lenient).unwrap();
+    assert_eq!(parse("rgb(300, -5, 127.6)"), Color { r: 255, g: 0, b: 128 });
+    assert_eq!(parse("rgb(120% 50% 0%)"), Color { r: 255, g: 128, b: 0 });
+    assert_eq!(parse("hsl(480, 150%, 50%)"), parse_color("hsl(120, 100%, 50%)").unwrap());
+    assert_eq!(parse("ff6600"), Color { r: 255, g: 102, b: 0 });
+    assert_eq!(parse("FED"), Color { r: 255, g: 238, b: 221 });
+    #[cfg(feature = "named-colors")]
+    assert_eq!(parse("tomato"), Color { r: 255, g: 99, b: 71 });
+    assert!(parse_color_with("ff66", &lenient).is_ok());
+    assert!(parse_color_with("ff66000", &lenient).is_err());
+    assert!(parse_color_with("rgb(NaN, 0, 0)", &lenient).is_err());
+
+    // none of that by default
+    for input in ["rgb(300, 0, 0)", "rgb(12.5, 0, 0)", "hsl(480, 100%, 50%)", "ff6600"] {
+        assert!(parse_color(input).is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_parse_color_bytes() {
+    assert_eq!(colourss::parse_color_bytes(b"#ff0000").unwrap(), Color { r: 255, g: 0, b: 0 });
+    assert!(matches!(colourss::parse_color_bytes(b"r\xe9d"), Err(colourss::ParseError::InvalidUtf8(s)) if s == "r\u{fffd}d"));
+}
+
+#[test]
+fn test_parse_color_with_alpha() {
+    use colourss::parse_color_with_alpha;
+    let alpha = |input| parse_color_with_alpha(input).unwrap().1;
+    let close = |a: f32, b: f32| (a - b).abs() < 0.001;
+
+    // no alpha in the syntax
+    #[cfg(feature = "named-colors")]
+    assert_eq!(parse_color_with_alpha("red").unwrap(), (Color { r: 255, g: 0, b: 0 }, 1.0));
+    assert_eq!(parse_color_with_alpha("#abc").unwrap(), (Color { r: 170, g: 187, b: 204 }, 1.0));
+    assert_eq!(alpha("rgb(1, 2, 3)"), 1.0);
+    assert_eq!(alpha("hsl(0, 100%, 50%)"), 1.0);
+
+    // hex
+    assert!(close(alpha("#abc8"), 0.533));
+    assert!(close(alpha("#aabbcc40"), 0.251));
+    assert_eq!(alpha("#aabbcc00"), 0.0);
+    // legacy rgba/hsla
+    assert_eq!(alpha("rgba(1, 2, 3, 0.3)"), 0.3);
+    assert_eq!(alpha("hsla(0, 100%, 50%, 0.6)"), 0.6);
+    // slash notation, as a number or a percentage
+    assert_eq!(alpha("rgb(1 2 3 / 0.7)"), 0.7);
+    assert_eq!(alpha("rgb(1 2 3 / 70%)"), 0.7);
+    assert_eq!(alpha("hsl(0 100% 50% / 25%)"), 0.25);
+    assert_eq!(parse_color_with_alpha("rgba(1, 2, 3, 0.3)").unwrap().0, parse_color("rgb(1, 2, 3)").unwrap());
+
+    for input in ["rgba(1, 2, 3, x)", "rgb(1 2 3 / )", "rgb(1 2 3 / 50%%)", "hsla(0, 100%, 50%, NaN)", "rgb(1 2 3 / inf)"] {
+        assert!(parse_color_with_alpha(input).is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_alpha_validated() {
+    // `parse_color` drops the alpha, but checks it as `parse_color_with_alpha` does
+    assert!(parse_color("rgba(0, 0, 0, abc)").is_err());
+    assert!(parse_color("hsla(0 0% 0% / x)").is_err());
+    assert!(parse_color("rgba(0 0 0 / )").is_err());
+    // two alphas
+    assert!(parse_color("rgba(0 0 0 1 / 0.5)").is_err());
+    assert!(parse_color("rgba(0 0 0 / 50%)").is_ok());
+    assert!(parse_color("#0000").is_ok());
+}
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_try_from() {
+    use colourss::ParseError;
+
+    let navy = Color { r: 0, g: 0, b: 128 };
+    assert_eq!(Color::try_from("navy").unwrap(), navy);
+    assert_eq!(Color::try_from(String::from("#000080")).unwrap(), navy);
+    assert_eq!(Color::try_from(b"rgb(0, 0, 128)".as_slice()).unwrap(), navy);
+    assert_eq!("navy".parse::<Color>().unwrap(), navy);
+    let converted: Result<Color, _> = String::from("navy").try_into();
+    assert_eq!(converted.unwrap(), navy);
+
+    assert!(matches!(Color::try_from("nvay"), Err(ParseError::UnknownColorName(name)) if name == "nvay"));
+    assert!(matches!(Color::try_from(b"n\xe4vy".as_slice()), Err(ParseError::InvalidUtf8(_))));
+
+    // errors go through `?` like any other
+    fn brightest(inputs: &[&str]) -> Result<Color, ParseError> {
+        let mut best = Color::try_from(inputs[0])?;
+        for input in &inputs[1..] {
+            let color = Color::try_from(*input)?;
+            if color.relative_luminance() > best.relative_luminance() {
+                best = color;
+            }
+        }
+        Ok(best)
+    }
+    assert_eq!(brightest(&["navy", "white", "#333"]).unwrap(), Color { r: 255, g: 255, b: 255 });
+    assert!(matches!(brightest(&["navy", "rgb(1, 2)"]), Err(ParseError::InvalidRgbFormat(s)) if s == "rgb(1, 2)"));
+}
+
+#[test]
+fn test_errors_show_the_input() {
+    let message = |input: &str| parse_color(input).unwrap_err().to_string();
+    assert_eq!(message("#ff00zz"), "Invalid hex code format in \"#ff00zz\"");
+    assert_eq!(message("  rgb(1, 2)  "), "Invalid RGB/RGBA format in \"rgb(1, 2)\"");
+    assert_eq!(message("hsl(1 2)"), "Invalid HSL/HSLA format in \"hsl(1 2)\"");
+    assert_eq!(message("rgb(300 0 0)"), "Invalid component value: 300 in \"rgb(300 0 0)\"");
+    assert_eq!(message("hsl(400 50% 50%)"), "Invalid component value: H: 400 in \"hsl(400 50% 50%)\"");
+    assert_eq!(message("nope"), "Unknown color name: \"nope\"");
+    assert_eq!(message(""), "Invalid hex code format in \"\"");
     assert_eq!(
-        parse_color("red").unwrap(),
-        Color { r: 255, g: 0, b: 0 }
+        colourss::parse_color_bytes(b"caf\xe9").unwrap_err().to_string(),
+        "Input is not valid UTF-8: \"caf\u{fffd}\""
     );
-    // check case-insensitivity
+
+    // control characters and quotes are escaped, so a message is one line
+    assert_eq!(message("#12\n34"), "Invalid hex code format in \"#12\\n34\"");
+    assert_eq!(message("say \"hi\""), "Unknown color name: \"say \\\"hi\\\"\"");
+    assert_eq!(message("\u{1b}[31m"), "Unknown color name: \"\\u{1b}[31m\"");
+    assert_eq!(message("rgb(\u{7}1 2 3)"), "Invalid component value: \\u{7}1 in \"rgb(\\u{7}1 2 3)\"");
+
+    // long inputs are cut to 64 characters, on a character boundary
+    let long = format!("#{}", "é".repeat(100));
+    let colourss::ParseError::InvalidHexFormat(kept) = parse_color(&long).unwrap_err() else { panic!() };
+    assert_eq!(kept, format!("#{}...", "é".repeat(63)));
+    assert!(message(&"x".repeat(10_000)).len() < 100);
+}
+
+#[test]
+fn test_error_kinds() {
+    use colourss::{ErrorKind, ParseError};
+
+    let kind = |input: &str| parse_color(input).unwrap_err().kind();
+    assert_eq!(kind("#ff00zz"), ErrorKind::Syntax);
+    assert_eq!(kind("rgb(1, 2)"), ErrorKind::Syntax);
+    assert_eq!(kind("hsl(1 2)"), ErrorKind::Syntax);
+    assert_eq!(kind("rgb(300 0 0)"), ErrorKind::OutOfRange);
+    assert_eq!(kind("hsl(400 50% 50%)"), ErrorKind::OutOfRange);
+    assert_eq!(kind("tomatoe"), ErrorKind::UnknownName);
+    assert_eq!(colourss::parse_color_bytes(b"\xff").unwrap_err().kind(), ErrorKind::Unsupported);
     assert_eq!(
-        parse_color("WHITE").unwrap(),
-        Color {
-            r: 255,
-            g: 255,
-            b: 255
-        }
+        parse_color_with("hsl(120, 100%, 50)", &ParseOptions::strict()).unwrap_err().kind(),
+        ErrorKind::OutOfRange
     );
+    let internal = ParseError::ParseFailure { what: "number", input: "1e".to_string() };
+    assert_eq!(internal.kind(), ErrorKind::Internal);
+    assert_eq!(internal.to_string(), "Failed to parse number in \"1e\"");
+
+    let e = parse_color("tomatoe").unwrap_err();
+    assert!(e.is_unknown_name() && !e.is_syntax() && !e.is_out_of_range());
+    assert!(parse_color("#ff").unwrap_err().is_syntax());
+    assert!(parse_color("rgb(0 0 256)").unwrap_err().is_out_of_range());
+}
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_parse_color_list() {
+    use colourss::{parse_color_list, parse_color_list_iter, ErrorKind, ParseError};
+
+    let (red, green, blue) = (Color::new(255, 0, 0), Color::new(0, 255, 0), Color::new(0, 0, 255));
+    assert_eq!(parse_color_list("red, #00ff00, rgb(0, 0, 255)").unwrap(), [red, green, blue]);
+    // commas inside functions don't split, however they're spaced
     assert_eq!(
-        parse_color("rebeccapurple").unwrap(),
-        Color {
-            r: 102,
-            g: 51,
-            b: 153
-        }
+        parse_color_list("rgba(255,0,0,1),hsl(120, 100%, 50%) ,  rgb(0 0 255 / 50%)").unwrap(),
+        [red, green, blue]
     );
-    // This was in fail test, but it's implemented
+    assert_eq!(parse_color_list("  blue  ").unwrap(), [blue]);
+
+    // one trailing comma is fine, an empty item in between isn't
+    assert_eq!(parse_color_list("red, blue,").unwrap(), [red, blue]);
+    assert_eq!(parse_color_list("red, blue , \n").unwrap(), [red, blue]);
+    assert!(matches!(parse_color_list("red, , blue"), Err(ParseError::ListItem { index: 1, .. })));
+    assert!(matches!(parse_color_list("red,,"), Err(ParseError::ListItem { index: 1, .. })));
+    assert!(parse_color_list("").unwrap().is_empty());
+    assert!(parse_color_list("   ").unwrap().is_empty());
+
+    let e = parse_color_list("red, rgb(0, 0), blue").unwrap_err();
+    assert!(matches!(&e, ParseError::ListItem { index: 1, error } if matches!(**error, ParseError::InvalidRgbFormat(_))));
+    assert_eq!(e.to_string(), "Invalid list item 1: Invalid RGB/RGBA format in \"rgb(0, 0)\"");
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+    assert!(parse_color_list("red, tomatoe").unwrap_err().is_unknown_name());
+    // an unclosed function swallows the rest of the list
+    assert!(matches!(parse_color_list("rgb(0, 0, 255, red"), Err(ParseError::ListItem { index: 0, .. })));
+
+    let mut items = parse_color_list_iter("red, nope, blue");
+    assert_eq!(items.next().unwrap().unwrap(), red);
+    assert!(matches!(items.next().unwrap(), Err(ParseError::UnknownColorName(_))));
+    assert_eq!(items.next().unwrap().unwrap(), blue);
+    assert!(items.next().is_none());
+}
+
+#[test]
+fn test_bare_hex_ambiguity() {
+    use colourss::ParseError;
+
+    let lenient = ParseOptions::lenient();
+    let bare = |input| parse_color_with(input, &lenient);
+    assert_eq!(bare("facade").unwrap(), Color::new(0xfa, 0xca, 0xde));
+    assert_eq!(bare("FaCaDe").unwrap(), Color::new(0xfa, 0xca, 0xde));
+    assert_eq!(bare("fed").unwrap(), Color::new(0xff, 0xee, 0xdd));
+    assert_eq!(bare("beef").unwrap(), Color::new(0xbb, 0xee, 0xee));
+    assert_eq!(bare("  ff6600  ").unwrap(), Color::new(0xff, 0x66, 0x00));
+    assert_eq!(bare("decade80").unwrap(), Color::new(0xde, 0xca, 0xde));
+    // other lengths and non-hex words are still names
+    assert!(matches!(bare("faded"), Err(ParseError::UnknownColorName(_))));
+    assert!(matches!(bare("ff"), Err(ParseError::UnknownColorName(_))));
+    #[cfg(feature = "named-colors")]
+    {
+        assert_eq!(bare("red").unwrap(), Color::new(255, 0, 0));
+        assert_eq!(bare("beige").unwrap(), parse_color("beige").unwrap());
+    }
+
+    // off by default and in strict mode, so a typo stays an error
+    for options in [ParseOptions::default(), ParseOptions::strict()] {
+        for input in ["facade", "fed", "ff6600"] {
+            assert!(matches!(parse_color_with(input, &options), Err(ParseError::UnknownColorName(_))));
+        }
+    }
+    assert!(parse_color("facade").unwrap_err().is_unknown_name());
+    let only_bare = ParseOptions { allow_bare_hex: true, ..ParseOptions::default() };
+    assert!(parse_color_with("facade", &only_bare).is_ok());
+}
+
+#[test]
+fn test_0x_hex() {
+    use colourss::{ParseError, Rgba};
+
+    let lenient = ParseOptions::lenient();
+    let hex = |input| parse_color_with(input, &lenient);
+    assert_eq!(hex("0xFF6600").unwrap(), Color::new(0xff, 0x66, 0x00));
+    assert_eq!(hex("0Xff6600").unwrap(), Color::new(0xff, 0x66, 0x00));
+    let rgba = Rgba::parse_with("0xff660080", &lenient).unwrap();
+    assert_eq!((rgba.to_color(), rgba.a), (Color::new(0xff, 0x66, 0x00), 128.0 / 255.0));
     assert_eq!(
-        parse_color("orange").unwrap(),
-        Color { r: 255, g: 165, b: 0 }
+        Rgba::parse_with("0xff660080", &lenient).unwrap(),
+        Rgba::parse_with("#ff660080", &lenient).unwrap()
     );
+
+    // 3 and 4 digits look like small integers, so they're refused
+    for input in ["0xfff", "0xf00f", "0xGG0000", "0x", "0xff66", "0x ff6600", "0xff660080ff"] {
+        let e = hex(input).unwrap_err();
+        assert!(matches!(&e, ParseError::InvalidHexFormat(s) if s == input), "{}: {}", input, e);
+    }
+
+    // only when asked for
+    assert!(parse_color("0xff6600").unwrap_err().is_unknown_name());
+    assert!(parse_color_with("0xff6600", &ParseOptions::strict()).is_err());
+    let only_0x = ParseOptions { allow_0x_hex: true, ..ParseOptions::default() };
+    assert!(parse_color_with("0xff6600", &only_0x).is_ok());
+    assert!(parse_color_with("ff6600", &only_0x).is_err());
 }
 
 #[test]
-fn test_rule4_named_fail() {
-    // not in our small list
-    assert!(parse_color("notacolor").is_err());
+fn test_extra_separators() {
+    use colourss::ParseError;
+
+    let lenient = ParseOptions::lenient();
+    let orange = Color::new(255, 128, 0);
+    assert_eq!(parse_color_with("rgb(255; 128; 0)", &lenient).unwrap(), orange);
+    assert_eq!(parse_color_with("rgb(255|128|0)", &lenient).unwrap(), orange);
+    assert_eq!(parse_color_with("hsl(120; 100%; 50%)", &lenient).unwrap(), Color::new(0, 255, 0));
+    let rgba = colourss::Rgba::parse_with("rgba(255; 128; 0; 50%)", &lenient).unwrap();
+    assert_eq!((rgba.to_color(), rgba.a), (orange, 0.5));
+    // the components are checked as usual; values are still clamped
+    assert!(parse_color_with("rgb(255; abc; 0)", &lenient).is_err());
+    assert_eq!(parse_color_with("rgb(300; 128; 0)", &lenient).unwrap(), orange);
+    assert!(matches!(parse_color_with("rgb(255; 128)", &lenient), Err(ParseError::InvalidRgbFormat(_))));
+
+    for options in [ParseOptions::default(), ParseOptions::strict()] {
+        assert!(parse_color_with("rgb(255; 128; 0)", &options).is_err());
+        assert!(parse_color_with("rgb(255|128|0)", &options).is_err());
+    }
+
+    // exactly the ones asked for, with the strict rules otherwise
+    let semicolons = ParseOptions { extra_separators: &[';'], ..ParseOptions::strict() };
+    assert_eq!(parse_color_with("rgb(255; 128; 0)", &semicolons).unwrap(), orange);
+    assert!(parse_color_with("rgb(255|128|0)", &semicolons).is_err());
+    assert!(parse_color_with("rgb(255; 128 0)", &semicolons).is_err());
 }
 
 #[test]
-fn test_overall_fail() {
-    // test empty
-    assert!(parse_color("").is_err());
-    assert!(parse_color("   ").is_err());
-    // test junk
-    assert!(parse_color("rgb(255, 0, 0)a").is_err()); // junk at end
-    assert!(parse_color("hello").is_err());
-}
\ No newline at end of file
+fn test_empty_components() {
+    use colourss::{ErrorKind, ParseError};
+
+    let empty = |input: &str, options: &ParseOptions| match parse_color_with(input, options) {
+        Err(ParseError::EmptyComponent { position, input: shown }) => {
+            assert_eq!(shown, input.trim());
+            position
+        }
+        other => panic!("{}: {:?}", input, other),
+    };
+    let default = ParseOptions::default();
+    assert_eq!(empty("rgb(255, 0, 0,)", &default), 3);
+    assert_eq!(empty("rgb(255,,0)", &default), 1);
+    assert_eq!(empty("rgb(,255,0)", &default), 0);
+    assert_eq!(empty("rgb(255, 0, , 0)", &default), 2);
+    assert_eq!(empty("hsl(, 100%, 50%)", &default), 0);
+    assert_eq!(empty("hsla(120, 100%, 50%,)", &default), 3);
+    assert_eq!(empty("rgba(255, 0, 0, 0.5,)", &default), 4);
+    assert_eq!(empty("rgb(255 0 0 / )", &default), 3);
+    assert_eq!(empty("rgb(255, 0, 0,,)", &ParseOptions::lenient()), 3);
+    assert_eq!(empty("rgb(,,)", &ParseOptions::lenient()), 0);
+
+    let e = parse_color("rgb(255, 0, 0,)").unwrap_err();
+    assert_eq!(e.to_string(), "Empty component at position 3 in \"rgb(255, 0, 0,)\"");
+    assert_eq!(e.kind(), ErrorKind::Syntax);
+
+    // lenient mode lets one trailing comma through, strict never does
+    let lenient = ParseOptions::lenient();
+    assert_eq!(parse_color_with("rgb(255, 0, 0,)", &lenient).unwrap(), Color::new(255, 0, 0));
+    assert_eq!(parse_color_with("hsl(120, 100%, 50%, )", &lenient).unwrap(), Color::new(0, 255, 0));
+    assert_eq!(parse_color_with("rgb(255; 0; 0;)", &lenient).unwrap(), Color::new(255, 0, 0));
+    assert!(parse_color_with("rgb(255, 0, 0,)", &ParseOptions::strict()).is_err());
+    // and the rest of the checks still apply
+    assert!(matches!(parse_color_with("rgb(255, 0,)", &lenient), Err(ParseError::InvalidRgbFormat(_))));
+    assert!(matches!(parse_color("rgb()"), Err(ParseError::InvalidRgbFormat(_))));
+}
+
+#[test]
+fn test_css_number_grammar() {
+    use colourss::parse_rgba;
+
+    // [+-]? (digits ('.' digits)? | '.' digits) ([eE] [+-]? digits)?
+    let accepted = [
+        ("120", 120.0),
+        ("+120", 120.0),
+        ("-0", 0.0),
+        (".5", 0.5),
+        ("+.25", 0.25),
+        ("0.5", 0.5),
+        ("1e2", 100.0),
+        ("1E+2", 100.0),
+        ("12e-1", 1.2),
+        ("1.2e1", 12.0),
+    ];
+    for (number, hue) in accepted {
+        let color = parse_color(&format!("hsl({}, 100%, 50%)", number)).unwrap();
+        assert_eq!(color, parse_color(&format!("hsl({}, 100%, 50%)", hue)).unwrap(), "{}", number);
+    }
+
+    let rejected = ["inf", "-inf", "infinity", "NaN", "5.", "1_000", "+", "-", ".", "+.", "e2", "1e", "1e+", "--1", "+-1", "1.2.3", "0x10", "1e99"];
+    for number in rejected {
+        for input in [
+            format!("hsl({}, 100%, 50%)", number),
+            format!("hsl(0, {}%, 50%)", number),
+            format!("rgb({}%, 0%, 0%)", number),
+            format!("rgb(0, 0, 0, {})", number),
+        ] {
+            assert!(parse_color(&input).is_err(), "{}", input);
+            assert!(parse_color_with(&input, &ParseOptions::lenient()).is_err(), "{}", input);
+        }
+    }
+
+    // plain channels take any spelling of a whole number
+    assert_eq!(parse_color("rgb(+255, 1e2, -0)").unwrap(), Color::new(255, 100, 0));
+    assert_eq!(parse_color("rgb(2.55e2, 0, 0)").unwrap(), Color::new(255, 0, 0));
+    assert!(parse_color("rgb(1.5, 0, 0)").is_err());
+    assert!(parse_color("rgb(1e3, 0, 0)").is_err());
+    assert_eq!(parse_color_with("rgb(1e3, .5, 0)", &ParseOptions::lenient()).unwrap(), Color::new(255, 1, 0));
+    assert_eq!(parse_rgba("rgba(0, 0, 0, +.5)").unwrap().a, 0.5);
+    assert_eq!(parse_rgba("rgb(0 0 0 / 5e1%)").unwrap().a, 0.5);
+    // one unit suffix, not several
+    assert!(parse_color("hsl(120degdeg, 100%, 50%)").is_err());
+    assert!(parse_color("hsl(120, 100%%, 50%)").is_err());
+}
+
+#[test]
+fn test_decimal_percentages() {
+    // percent, then the byte it rounds to: nearest, halves up
+    let table = [
+        ("0%", 0),
+        ("0.1%", 0),
+        ("0.2%", 1),
+        ("10%", 26),
+        ("12.5%", 32),
+        ("30%", 77),
+        ("33.333%", 85),
+        ("37.5%", 96),
+        ("50%", 128),
+        ("66.667%", 170),
+        ("70%", 179),
+        ("90%", 230),
+        ("99.8%", 254),
+        ("99.9%", 255),
+        ("100%", 255),
+    ];
+    for (percent, byte) in table {
+        let expected = Color::new(byte, 0, byte);
+        for input in [
+            format!("rgb({0}, 0%, {0})", percent),
+            format!("rgb({0} 0% {0})", percent),
+            format!("rgba({0}, 0%, {0}, 0.5)", percent),
+            format!("rgb({0} 0% {0} / 50%)", percent),
+        ] {
+            assert_eq!(parse_color(&input).unwrap(), expected, "{}", input);
+        }
+    }
+    assert_eq!(parse_color("rgb(33.333% 66.667% 0%)").unwrap(), Color::new(85, 170, 0));
+
+    let hsl = [
+        ("hsl(120, 62.5%, 47.3%)", Color::new(45, 196, 45)),
+        ("hsl(120 62.5% 47.3%)", Color::new(45, 196, 45)),
+        ("hsl(0, 0%, 50%)", Color::new(128, 128, 128)),
+        ("hsl(240, 100%, 25%)", Color::new(0, 0, 128)),
+        ("hsl(30, 100%, 50%)", Color::new(255, 128, 0)),
+        ("hsl(210, 80%, 40%)", Color::new(20, 102, 184)),
+        ("hsl(0, 0%, 33.333%)", Color::new(85, 85, 85)),
+        ("hsl(0, 0%, 0.1%)", Color::new(0, 0, 0)),
+    ];
+    for (input, expected) in hsl {
+        assert_eq!(parse_color(input).unwrap(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_gray_function() {
+    use colourss::{parse_rgba_with, ParseError};
+
+    let lenient = ParseOptions::lenient();
+    let gray = |input| parse_color_with(input, &lenient).unwrap();
+    assert_eq!(gray("gray(0)"), Color::new(0, 0, 0));
+    assert_eq!(gray("gray(100%)"), Color::new(255, 255, 255));
+    assert_eq!(gray("gray(128)"), Color::new(128, 128, 128));
+    assert_eq!(gray("gray(50%)"), Color::new(128, 128, 128));
+    assert_eq!(gray("GRAY( 25% )"), Color::new(64, 64, 64));
+
+    let options = ParseOptions { allow_gray_function: true, ..ParseOptions::default() };
+    let with_alpha = |input| {
+        let color = parse_rgba_with(input, &options).unwrap();
+        (color.to_color(), color.a)
+    };
+    assert_eq!(with_alpha("gray(50% / 0.5)"), (Color::new(128, 128, 128), 0.5));
+    assert_eq!(with_alpha("gray(50% / 25%)"), (Color::new(128, 128, 128), 0.25));
+    assert_eq!(with_alpha("gray(128, 0.5)"), (Color::new(128, 128, 128), 0.5));
+    assert_eq!(with_alpha("gray(128)"), (Color::new(128, 128, 128), 1.0));
+
+    // alpha is validated like everywhere else
+    let strict_alpha = ParseOptions { clamp_alpha: false, ..options };
+    assert!(matches!(
+        parse_color_with("gray(50% / 1.5)", &strict_alpha),
+        Err(ParseError::InvalidComponentValue { .. })
+    ));
+    assert!(parse_color_with("gray(50% / x)", &options).is_err());
+    assert!(matches!(parse_color_with("gray(50% /)", &options), Err(ParseError::EmptyComponent { position: 1, .. })));
+    // one channel, one alpha
+    for input in ["gray()", "gray(1 2)", "gray(1, 2, 3)", "gray(1, 0.5 / 0.5)", "gray(256)", "gray(-1%)"] {
+        assert!(parse_color_with(input, &options).is_err(), "{}", input);
+    }
+    assert!(matches!(parse_color_with("gray(1 2)", &options), Err(ParseError::InvalidRgbFormat(_))));
+
+    // off by default and in strict mode, where it is just an unknown name
+    assert!(matches!(parse_color("gray(50%)"), Err(ParseError::UnknownColorName(_))));
+    assert!(parse_color_with("gray(50%)", &ParseOptions::strict()).is_err());
+    #[cfg(feature = "named-colors")]
+    assert_eq!(parse_color("gray").unwrap(), Color::new(128, 128, 128));
+}
+
+#[test]
+fn test_css_wide_keywords() {
+    use colourss::{ErrorKind, ParseError};
+
+    for (input, keyword) in [
+        ("inherit", "inherit"),
+        ("Initial", "initial"),
+        (" UNSET ", "unset"),
+        ("revert", "revert"),
+        ("revert-layer", "revert-layer"),
+    ] {
+        let error = parse_color(input).unwrap_err();
+        assert!(matches!(error, ParseError::CssWideKeyword(k) if k == keyword), "{}", input);
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+        assert!(!error.is_unknown_name());
+        assert!(parse_color_with(input, &ParseOptions::lenient()).is_err());
+    }
+    assert_eq!(
+        parse_color("inherit").unwrap_err().to_string(),
+        "\"inherit\" is a CSS-wide keyword, not a color"
+    );
+    // near misses are still typos
+    assert!(matches!(parse_color("inherits"), Err(ParseError::UnknownColorName(_))));
+    assert!(matches!(parse_color("revert layer"), Err(ParseError::UnknownColorName(_))));
+}
+
+#[test]
+fn test_parse_color_legacy_html() {
+    use colourss::{parse_color_legacy_html, ParseError};
+
+    let vectors = [
+        ("chucknorris", "#c00000"),
+        ("ninjaturtle", "#00a000"),
+        ("crap", "#c0a000"),
+        ("grass", "#00a000"),
+        ("sick", "#00c000"),
+        ("mrt", "#000000"),
+        ("12345z", "#123450"),
+        ("#fff", "#ffffff"),
+        ("fff", "#0f0f0f"),
+        ("#ff", "#0f0f00"),
+        ("#abcd", "#abcd00"),
+        ("#12345678", "#124578"),
+        ("0123456789abcdef0123", "#0178ef"),
+        // thirds longer than 8 keep their last 8, then shared zeros go
+        ("a00000011b00000022c00000033", "#112233"),
+        // not a CSS keyword, so it's read as hex
+        ("coffee", "#c0ffee"),
+        // outside the BMP counts as two zeros
+        ("1\u{1F600}1", "#100100"),
+        ("   ", "#000000"),
+    ];
+    for (input, hex) in vectors {
+        assert_eq!(parse_color_legacy_html(input).unwrap().to_hex(), hex, "{:?}", input);
+    }
+    // keywords come first
+    #[cfg(feature = "named-colors")]
+    for (input, hex) in [("red", "#ff0000"), ("  RebeccaPurple\n", "#663399")] {
+        assert_eq!(parse_color_legacy_html(input).unwrap().to_hex(), hex, "{:?}", input);
+    }
+    // only the first 128 characters count
+    let long = format!("{}fff", "0".repeat(128));
+    assert_eq!(parse_color_legacy_html(&long).unwrap(), Color::new(0, 0, 0));
+
+    assert!(matches!(parse_color_legacy_html(""), Err(ParseError::InvalidHexFormat(_))));
+    assert!(matches!(parse_color_legacy_html(" Transparent "), Err(ParseError::UnknownColorName(_))));
+}
+
+// The keyword table itself, which is only there with the `named-colors`
+// feature (on by default).
+#[cfg(feature = "named-colors")]
+mod names {
+    use colourss::{is_named_color, is_standard_name, named, named_colors, parse_color, Color, NAMED_COLORS};
+
+    #[test]
+    fn test_rule4_named() {
+        assert_eq!(
+            parse_color("red").unwrap(),
+            Color { r: 255, g: 0, b: 0 }
+        );
+        // check case-insensitivity
+        assert_eq!(
+            parse_color("WHITE").unwrap(),
+            Color {
+                r: 255,
+                g: 255,
+                b: 255
+            }
+        );
+        assert_eq!(
+            parse_color("rebeccapurple").unwrap(),
+            Color {
+                r: 102,
+                g: 51,
+                b: 153
+            }
+        );
+        // This was in fail test, but it's implemented
+        assert_eq!(
+            parse_color("orange").unwrap(),
+            Color { r: 255, g: 165, b: 0 }
+        );
+    }
+
+    #[test]
+    fn test_named_colors_listing() {
+        let names: Vec<&str> = named_colors().map(|(name, _)| name).collect();
+        assert_eq!(names.len(), 149);
+        assert!(names.windows(2).all(|w| w[0] < w[1]));
+        for (name, color) in named_colors() {
+            assert_eq!(parse_color(name).unwrap(), color, "{}", name);
+        }
+
+        assert!(is_standard_name("steelblue"));
+        assert!(is_standard_name("SteelBlue"));
+        assert!(!is_standard_name("coffee"));
+        assert!(!is_standard_name("notacolor"));
+    }
+
+    #[test]
+    fn test_named_colors_table() {
+        assert!(NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(NAMED_COLORS.iter().eq(named_colors().collect::<Vec<_>>().iter()));
+        for &(name, color) in NAMED_COLORS {
+            assert_eq!(parse_color(name).unwrap(), color, "{}", name);
+            assert!(is_named_color(name), "{}", name);
+        }
+
+        assert!(is_named_color("SteelBlue"));
+        assert!(is_named_color("coffee"));
+        assert!(!is_named_color("notacolor"));
+        assert!(!is_named_color(""));
+    }
+
+    #[test]
+    fn test_named_colors_match_spec() {
+        // the named color table from CSS Color 4, section 6.1
+        let spec: Vec<(String, Color)> = include_str!("data/css-color-4-named-colors.csv")
+            .lines()
+            .skip(1)
+            .map(|line| {
+                let fields: Vec<&str> = line.split(',').collect();
+                let channel = |i: usize| fields[i].parse().unwrap();
+                (fields[0].to_string(), Color::new(channel(1), channel(2), channel(3)))
+            })
+            .collect();
+        assert_eq!(spec.len(), 148);
+        let ours: Vec<(String, Color)> = named_colors()
+            .filter(|(name, _)| is_standard_name(name))
+            .map(|(name, color)| (name.to_string(), color))
+            .collect();
+        let missing: Vec<_> = spec.iter().filter(|k| !ours.contains(k)).collect();
+        let unexpected: Vec<_> = ours.iter().filter(|k| !spec.contains(k)).collect();
+        assert!(missing.is_empty() && unexpected.is_empty(), "missing {:?}, not in the spec {:?}", missing, unexpected);
+    }
+
+    #[test]
+    fn test_no_keyword_is_bare_hex() {
+        // so `allow_bare_hex` can't shadow a keyword
+        for (name, _) in named_colors() {
+            assert!(!name.bytes().all(|b| b.is_ascii_hexdigit()), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_named_constants() {
+        // each constant against the keyword its name spells
+        macro_rules! check {
+            ($($constant:ident),* $(,)?) => {{
+                let mut count = 0;
+                $(
+                    let name = stringify!($constant).replace('_', "").to_lowercase();
+                    assert_eq!(parse_color(&name).unwrap(), named::$constant, "{}", name);
+                    count += 1;
+                )*
+                count
+            }};
+        }
+        let count = check!(
+            ALICE_BLUE, ANTIQUE_WHITE, AQUA, AQUAMARINE, AZURE, BEIGE, BISQUE, BLACK, BLANCHED_ALMOND, BLUE,
+            BLUE_VIOLET, BROWN, BURLYWOOD, CADET_BLUE, CHARTREUSE, CHOCOLATE, COFFEE, CORAL, CORNFLOWER_BLUE,
+            CORNSILK, CRIMSON, CYAN, DARK_BLUE, DARK_CYAN, DARK_GOLDENROD, DARK_GRAY, DARK_GREEN, DARK_GREY,
+            DARK_KHAKI, DARK_MAGENTA, DARK_OLIVE_GREEN, DARK_ORANGE, DARK_ORCHID, DARK_RED, DARK_SALMON,
+            DARK_SEA_GREEN, DARK_SLATE_BLUE, DARK_SLATE_GRAY, DARK_SLATE_GREY, DARK_TURQUOISE, DARK_VIOLET,
+            DEEP_PINK, DEEP_SKY_BLUE, DIM_GRAY, DIM_GREY, DODGER_BLUE, FIREBRICK, FLORAL_WHITE, FOREST_GREEN,
+            FUCHSIA, GAINSBORO, GHOST_WHITE, GOLD, GOLDENROD, GRAY, GREEN, GREEN_YELLOW, GREY, HONEYDEW, HOT_PINK,
+            INDIAN_RED, INDIGO, IVORY, KHAKI, LAVENDER, LAVENDER_BLUSH, LAWN_GREEN, LEMON_CHIFFON, LIGHT_BLUE,
+            LIGHT_CORAL, LIGHT_CYAN, LIGHT_GOLDENROD_YELLOW, LIGHT_GRAY, LIGHT_GREEN, LIGHT_GREY, LIGHT_PINK,
+            LIGHT_SALMON, LIGHT_SEA_GREEN, LIGHT_SKY_BLUE, LIGHT_SLATE_GRAY, LIGHT_SLATE_GREY, LIGHT_STEEL_BLUE,
+            LIGHT_YELLOW, LIME, LIME_GREEN, LINEN, MAGENTA, MAROON, MEDIUM_AQUAMARINE, MEDIUM_BLUE, MEDIUM_ORCHID,
+            MEDIUM_PURPLE, MEDIUM_SEA_GREEN, MEDIUM_SLATE_BLUE, MEDIUM_SPRING_GREEN, MEDIUM_TURQUOISE,
+            MEDIUM_VIOLET_RED, MIDNIGHT_BLUE, MINT_CREAM, MISTY_ROSE, MOCCASIN, NAVAJO_WHITE, NAVY, OLD_LACE, OLIVE,
+            OLIVE_DRAB, ORANGE, ORANGE_RED, ORCHID, PALE_GOLDENROD, PALE_GREEN, PALE_TURQUOISE, PALE_VIOLET_RED,
+            PAPAYA_WHIP, PEACH_PUFF, PERU, PINK, PLUM, POWDER_BLUE, PURPLE, REBECCA_PURPLE, RED, ROSY_BROWN,
+            ROYAL_BLUE, SADDLE_BROWN, SALMON, SANDY_BROWN, SEA_GREEN, SEASHELL, SIENNA, SILVER, SKY_BLUE,
+            SLATE_BLUE, SLATE_GRAY, SLATE_GREY, SNOW, SPRING_GREEN, STEEL_BLUE, TAN, TEAL, THISTLE, TOMATO,
+            TURQUOISE, VIOLET, WHEAT, WHITE, WHITE_SMOKE, YELLOW, YELLOW_GREEN
+        );
+        assert_eq!(count, named_colors().count());
+        assert_eq!(named::REBECCA_PURPLE, Color::new(102, 51, 153));
+        assert_eq!(named::STEEL_BLUE.to_hex(), "#4682b4");
+        assert_eq!(named::AQUA, named::CYAN);
+    }
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "arbitrary")]
+
+use colourss::arbitrary::{color_string, hex_string, hsl_string, rgb_string};
+use colourss::{find_colors, parse_color, parse_color_with, Color, ParseOptions};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn hex_round_trip(c in any::<Color>()) {
+        prop_assert_eq!(parse_color(&c.to_hex()).unwrap(), c);
+    }
+
+    #[test]
+    fn parse_never_panics(s in "\\PC*") {
+        let _ = parse_color(&s);
+        let _ = find_colors(&s);
+    }
+
+    #[test]
+    fn parse_never_panics_near_misses(s in "[ #a-fA-F0-9xrgbhsl(),%/.+e-]{0,24}") {
+        let _ = parse_color(&s);
+    }
+
+    #[test]
+    fn generated_strings_parse(s in color_string()) {
+        prop_assert!(parse_color(&s).is_ok(), "{:?} did not parse", s);
+    }
+
+    #[test]
+    fn strict_accepts_less_than_default_and_lenient_more(
+        s in prop_oneof![color_string(), "[ #a-fA-F0-9xrgbhsl(),%/.+e-]{0,24}"]
+    ) {
+        let strict = parse_color_with(&s, &ParseOptions::strict());
+        let default = parse_color(&s);
+        let lenient = parse_color_with(&s, &ParseOptions::lenient());
+        if let Ok(color) = strict {
+            prop_assert_eq!(default.as_ref().ok(), Some(&color), "{:?}", s);
+        }
+        if let Ok(color) = default {
+            prop_assert_eq!(lenient.ok(), Some(color), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn hex_strings_parse_to_their_color((c, _, s) in hex_string()) {
+        prop_assert_eq!(parse_color(&s).unwrap(), c);
+    }
+
+    #[test]
+    fn rgb_strings_parse_to_their_color((c, _, s) in rgb_string()) {
+        prop_assert_eq!(parse_color(&s).unwrap(), c);
+    }
+
+    #[test]
+    fn hsl_strings_match_from_hsl(((h, s, l), input) in hsl_string()) {
+        // both round to the nearest byte
+        let expected = Color::from_hsl(h as f32, s as f32 / 100.0, l as f32 / 100.0);
+        prop_assert_eq!(parse_color(&input).unwrap(), expected, "{:?}", input);
+    }
+
+    #[test]
+    fn every_format_parses_back(c in any::<Color>()) {
+        use colourss::ColorFormat;
+        for format in [
+            ColorFormat::HexLower,
+            ColorFormat::HexUpper,
+            ColorFormat::HexShort,
+            ColorFormat::RgbLegacy,
+            ColorFormat::RgbModern,
+            ColorFormat::HslLegacy,
+            ColorFormat::HslModern,
+            ColorFormat::Named { fallback: &ColorFormat::HslModern },
+        ] {
+            let text = c.format(format);
+            prop_assert_eq!(parse_color(&text).unwrap(), c, "{:?}: {}", format, text);
+        }
+        prop_assert_eq!(parse_color(&c.to_css_shortest()).unwrap(), c);
+    }
+
+    #[test]
+    fn rgb_hsl_rgb_is_exact(c in any::<Color>()) {
+        let (h, s, l) = c.to_hsl();
+        prop_assert_eq!(Color::from_hsl(h, s, l), c);
+    }
+
+    #[test]
+    fn hsl_rgb_hsl_is_stable(h in 0.0f32..360.0, s in 0.2f32..=1.0, l in 0.2f32..=0.8) {
+        // 8-bit channels lose some precision; away from the gray axis and
+        // the black/white ends it stays within a few degrees and percent
+        let (h2, s2, l2) = Color::from_hsl(h, s, l).to_hsl();
+        let dh = (h2 - h).abs().min(360.0 - (h2 - h).abs());
+        prop_assert!(dh < 3.0, "hue {} -> {}", h, h2);
+        prop_assert!((s2 - s).abs() < 0.03, "saturation {} -> {}", s, s2);
+        prop_assert!((l2 - l).abs() < 0.003, "lightness {} -> {}", l, l2);
+    }
+}
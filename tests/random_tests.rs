@@ -0,0 +1,36 @@
+#![cfg(feature = "rand")]
+
+use colourss::Color;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+#[test]
+fn test_random_is_seeded() {
+    let mut a = ChaCha8Rng::seed_from_u64(7);
+    let mut b = ChaCha8Rng::seed_from_u64(7);
+    let first: Vec<Color> = (0..20).map(|_| a.random()).collect();
+    let second: Vec<Color> = (0..20).map(|_| b.random()).collect();
+    assert_eq!(first, second);
+    assert!(first.windows(2).any(|w| w[0] != w[1]));
+}
+
+#[test]
+fn test_random_hsl_stays_in_region() {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    for _ in 0..500 {
+        let (h, s, l) = Color::random_hsl(&mut rng, 200.0..=260.0, 0.5..=1.0, 0.3..=0.6).to_hsl();
+        // channels are rounded to bytes, so allow a little slack
+        assert!((199.0..=261.0).contains(&h), "hue {}", h);
+        assert!(s >= 0.48 && (0.29..=0.61).contains(&l), "{} {}", s, l);
+    }
+
+    // a range that wraps past 360 stays around red
+    for _ in 0..500 {
+        let (h, _, _) = Color::random_hsl(&mut rng, 330.0..=30.0, 1.0..=1.0, 0.5..=0.5).to_hsl();
+        assert!(h >= 329.0 || h <= 31.0, "hue {}", h);
+    }
+
+    // empty ranges pin the value
+    let gray = Color::random_hsl(&mut rng, 0.0..=360.0, 0.0..=0.0, 0.5..=0.5);
+    assert_eq!(gray, Color { r: 128, g: 128, b: 128 });
+}
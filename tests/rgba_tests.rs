@@ -0,0 +1,108 @@
+use colourss::{parse_color, parse_rgba, Color, Rgba};
+
+#[test]
+fn test_over_half_red_on_white() {
+    let red = Rgba { r: 255, g: 0, b: 0, a: 0.5 };
+    assert_eq!(red.flatten_on_white(), parse_color("#ff8080").unwrap());
+    assert_eq!(
+        red.over(&Color { r: 0, g: 0, b: 0 }),
+        Color { r: 128, g: 0, b: 0 }
+    );
+}
+
+#[test]
+fn test_over_opaque_and_transparent() {
+    let bg = Color { r: 10, g: 20, b: 30 };
+    let opaque = Rgba { r: 200, g: 100, b: 50, a: 1.0 };
+    let clear = Rgba { r: 200, g: 100, b: 50, a: 0.0 };
+    assert_eq!(opaque.over(&bg), Color { r: 200, g: 100, b: 50 });
+    assert_eq!(clear.over(&bg), bg);
+    assert_eq!(clear.flatten_on(&bg), bg);
+    // out of range alpha is clamped
+    assert_eq!(Rgba { a: 3.0, ..opaque }.over(&bg), Color { r: 200, g: 100, b: 50 });
+    assert_eq!(Rgba { a: -1.0, ..opaque }.over(&bg), bg);
+}
+
+#[test]
+fn test_over_associative() {
+    let a = Rgba { r: 255, g: 0, b: 0, a: 0.5 };
+    let b = Rgba { r: 0, g: 0, b: 255, a: 0.4 };
+    let c = Color { r: 0, g: 255, b: 0 };
+
+    let left = a.over_rgba(&b).over(&c);
+    let right = a.over(&b.over(&c));
+    assert!((left.r as i16 - right.r as i16).abs() <= 1);
+    assert!((left.g as i16 - right.g as i16).abs() <= 1);
+    assert!((left.b as i16 - right.b as i16).abs() <= 1);
+    assert!((a.over_rgba(&b).a - 0.7).abs() < 1e-6);
+}
+
+#[test]
+fn test_over_rgba_transparent() {
+    let a = Rgba { r: 255, g: 0, b: 0, a: 0.5 };
+    let clear = Rgba { r: 10, g: 20, b: 30, a: 0.0 };
+    assert_eq!(a.over_rgba(&clear), a);
+    assert_eq!(clear.over_rgba(&a), a);
+    // nothing shows through, so the channels don't matter
+    let other = Rgba { r: 200, g: 100, b: 50, a: -1.0 };
+    assert_eq!(clear.over_rgba(&other), Rgba { r: 0, g: 0, b: 0, a: 0.0 });
+}
+
+#[test]
+fn test_from_str_keeps_alpha() {
+    let rgba: Rgba = "rgb(255 0 0 / 25%)".parse().unwrap();
+    assert_eq!(rgba, Rgba { r: 255, g: 0, b: 0, a: 0.25 });
+    #[cfg(feature = "named-colors")]
+    assert_eq!("navy".parse::<Rgba>().unwrap().a, 1.0);
+    assert!("nope".parse::<Rgba>().is_err());
+}
+
+#[test]
+fn test_parse_rgba_keeps_alpha() {
+    let red = parse_rgba("#ff000080").unwrap();
+    assert_eq!((red.r, red.g, red.b), (255, 0, 0));
+    assert!((red.a - 0.502).abs() < 0.001, "{}", red.a);
+    assert!((parse_rgba("#f008").unwrap().a - 0.533).abs() < 0.001);
+    assert_eq!(parse_rgba("rgba(0, 128, 0, 0.25)").unwrap(), Rgba { r: 0, g: 128, b: 0, a: 0.25 });
+    assert_eq!(parse_rgba("hsla(240, 100%, 50%, 0.75)").unwrap(), Rgba { r: 0, g: 0, b: 255, a: 0.75 });
+    assert_eq!(parse_rgba("rgb(1 2 3 / 40%)").unwrap(), Rgba { r: 1, g: 2, b: 3, a: 0.4 });
+    assert_eq!(parse_rgba("hsl(0 100% 50% / 0.5)").unwrap().a, 0.5);
+    #[cfg(feature = "named-colors")]
+    assert_eq!(parse_rgba("teal").unwrap().a, 1.0);
+    // alpha is clamped, and has to be a number
+    assert_eq!(parse_rgba("rgba(0, 0, 0, 1.5)").unwrap().a, 1.0);
+    assert_eq!(parse_rgba("rgb(0 0 0 / -20%)").unwrap().a, 0.0);
+    assert!(parse_rgba("rgba(0, 0, 0, half)").is_err());
+}
+
+#[test]
+fn test_rgba_to_and_from_color() {
+    let half = parse_rgba("rgb(200 100 50 / 0.5)").unwrap();
+    assert_eq!(half.to_color(), Color { r: 200, g: 100, b: 50 });
+    assert_eq!(half.flatten_on(&Color { r: 0, g: 0, b: 0 }), Color { r: 100, g: 50, b: 25 });
+
+    let navy = Color { r: 0, g: 0, b: 128 };
+    let opaque = Rgba::from(navy);
+    assert_eq!(opaque, Rgba { r: 0, g: 0, b: 128, a: 1.0 });
+    assert_eq!(opaque.to_color(), navy);
+    assert_eq!(Rgba::from(navy).flatten_on_white(), navy);
+}
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_to_css_shortest() {
+    let cases = [
+        (Rgba { r: 255, g: 255, b: 255, a: 0.6 }, "#fff9"),
+        (Rgba { r: 0, g: 0, b: 0, a: 0.0 }, "#0000"),
+        (Rgba { r: 255, g: 0, b: 0, a: 0.5 }, "#ff000080"),
+        (Rgba { r: 18, g: 52, b: 86, a: 0.2 }, "#12345633"),
+        (Rgba { r: 255, g: 255, b: 255, a: 1.0 }, "#fff"),
+        (Rgba { r: 255, g: 0, b: 0, a: 0.999 }, "red"),
+        (Rgba { r: 0, g: 0, b: 128, a: 1.5 }, "navy"),
+    ];
+    for (color, css) in cases {
+        assert_eq!(color.to_css_shortest(), css, "{:?}", color);
+        let parsed = parse_rgba(css).unwrap();
+        assert_eq!(parsed.as_array(), color.as_array(), "{}", css);
+    }
+}
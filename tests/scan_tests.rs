@@ -0,0 +1,40 @@
+// Every case mixes keywords in with the other syntaxes.
+#![cfg(feature = "named-colors")]
+
+use colourss::{find_colors, Color};
+
+fn tokens(text: &str) -> Vec<&str> {
+    find_colors(text).iter().map(|m| &text[m.start..m.end]).collect()
+}
+
+#[test]
+fn test_find_colors_css() {
+    let css = "a { color: #f00; background: rgba(0, 0, 255, 0.5); }\n\
+               .b { border: 1px solid navy; fill: hsl(120, 100%, 50%) }";
+    assert_eq!(
+        tokens(css),
+        vec!["#f00", "rgba(0, 0, 255, 0.5)", "navy", "hsl(120, 100%, 50%)"]
+    );
+
+    let found = find_colors(css);
+    assert_eq!(found[0].color, Color { r: 255, g: 0, b: 0 });
+    assert_eq!(found[1].alpha, 0.5);
+    assert_eq!(found[2].start, css.find("navy").unwrap());
+}
+
+#[test]
+fn test_find_colors_boundaries() {
+    // partial words, odd-length hex runs and entities are not colors
+    assert!(tokens("bored tan-500 #ffff0 &#123; id#fff").is_empty());
+    assert_eq!(tokens("Red, (#ABCDEF) and\tteal."), vec!["Red", "#ABCDEF", "teal"]);
+    // a broken call is skipped but a keyword after it still counts
+    assert_eq!(tokens("rgb(999, 0, 0) then blue"), vec!["blue"]);
+}
+
+#[test]
+fn test_find_colors_non_ascii() {
+    let text = "couleur: «#00ff00» — fond: white";
+    let found = find_colors(text);
+    assert_eq!(tokens(text), vec!["#00ff00", "white"]);
+    assert_eq!(&text[found[1].start..], "white");
+}
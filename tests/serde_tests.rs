@@ -0,0 +1,96 @@
+#![cfg(feature = "serde")]
+
+use colourss::Color;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+struct Theme {
+    accent: Color,
+    background: Color,
+    #[serde(with = "colourss::serde_hex")]
+    brand: Color,
+}
+
+const STEELBLUE: Color = Color::new(70, 130, 180);
+const ACCENT: Color = Color::new(20, 102, 184);
+
+#[test]
+fn test_json() {
+    let theme: Theme = serde_json::from_str(
+        r##"{"accent": "hsl(210, 80%, 40%)", "background": "rgb(70 130 180)", "brand": "#123"}"##,
+    )
+    .unwrap();
+    assert_eq!(theme, Theme { accent: ACCENT, background: STEELBLUE, brand: Color::new(0x11, 0x22, 0x33) });
+    assert_eq!(
+        serde_json::to_string(&theme).unwrap(),
+        r##"{"accent":"#1466b8","background":"#4682b4","brand":"#112233"}"##
+    );
+
+    let colors: Vec<Color> = serde_json::from_str(r##"["hsl(0 100% 50%)", "rgb(0 0 255)", "#0f0"]"##).unwrap();
+    assert_eq!(colors, [Color::new(255, 0, 0), Color::new(0, 0, 255), Color::new(0, 255, 0)]);
+    #[cfg(feature = "named-colors")]
+    assert_eq!(serde_json::from_str::<Color>(r#""steelblue""#).unwrap(), STEELBLUE);
+}
+
+#[test]
+fn test_toml() {
+    let theme: Theme = toml::from_str(
+        r##"
+accent = "hsl(210, 80%, 40%)"
+background = "#4682B4"
+brand = "#ff6600"
+"##,
+    )
+    .unwrap();
+    assert_eq!(theme.accent, ACCENT);
+    assert_eq!(theme.background, STEELBLUE);
+    assert_eq!(theme.brand, Color::new(255, 102, 0));
+    #[cfg(feature = "named-colors")]
+    {
+        let named: Theme = toml::from_str("accent = \"red\"\nbackground = \"SteelBlue\"\nbrand = \"#000\"\n").unwrap();
+        assert_eq!(named.background, STEELBLUE);
+    }
+    let round_trip: Theme = toml::from_str(&toml::to_string(&theme).unwrap()).unwrap();
+    assert_eq!(round_trip, theme);
+}
+
+#[test]
+fn test_yaml() {
+    let theme: Theme = serde_yaml_ng::from_str(
+        "accent: 'hsl(210, 80%, 40%)'\nbackground: rgb(70 130 180)\nbrand: '#ff6600'\n",
+    )
+    .unwrap();
+    assert_eq!(theme.accent, ACCENT);
+    assert_eq!(theme.background, STEELBLUE);
+    assert_eq!(serde_yaml_ng::to_string(&theme.brand).unwrap().trim(), "'#ff6600'");
+}
+
+#[test]
+fn test_errors_name_the_input() {
+    let e = serde_json::from_str::<Color>(r#""hsl(400, 80%, 40%)""#).unwrap_err().to_string();
+    assert!(e.contains("Invalid component value: H: 400 in \"hsl(400, 80%, 40%)\""), "{}", e);
+
+    let e = toml::from_str::<Theme>("accent = \"stealblue\"\nbackground = \"red\"\nbrand = \"#000\"\n")
+        .unwrap_err()
+        .to_string();
+    assert!(e.contains("Unknown color name: \"stealblue\""), "{}", e);
+
+    let e = serde_json::from_str::<Color>("255").unwrap_err().to_string();
+    assert!(e.contains("a CSS color string"), "{}", e);
+}
+
+#[test]
+fn test_serde_hex_is_strict() {
+    let parse = |brand: &str| {
+        serde_json::from_str::<Theme>(&format!(
+            r##"{{"accent": "#f00", "background": "#f00", "brand": "{}"}}"##,
+            brand
+        ))
+    };
+    assert_eq!(parse("#abcdef").unwrap().brand, Color::new(0xab, 0xcd, 0xef));
+    for bad in ["steelblue", "rgb(0, 0, 0)", "#abcd", "#ff660080", "ff6600"] {
+        let e = parse(bad).unwrap_err().to_string();
+        assert!(e.contains(&format!("expected #rgb or #rrggbb, got \"{}\"", bad)), "{}", e);
+    }
+    assert!(parse("#ggg").unwrap_err().to_string().contains("Invalid hex code format in \"#ggg\""));
+}
@@ -0,0 +1,167 @@
+use colourss::{Color, LinearColor, Oklab, WhitePoint, Xyz};
+
+#[test]
+fn test_linear_round_trip_gray_ramp() {
+    for v in 0..=255u8 {
+        let c = Color { r: v, g: v, b: v };
+        let back = c.to_linear().to_srgb();
+        assert!((back.r as i16 - v as i16).abs() <= 1, "{} -> {:?}", v, back);
+        assert_eq!(back.r, back.g);
+        assert_eq!(back.g, back.b);
+    }
+}
+
+#[test]
+fn test_linear_round_trip_channels() {
+    for v in 0..=255u8 {
+        for c in [Color { r: v, g: 0, b: 0 }, Color { r: 0, g: v, b: 0 }, Color { r: 0, g: 0, b: v }] {
+            assert_eq!(c.to_linear().to_srgb(), c);
+        }
+    }
+}
+
+#[test]
+fn test_linear_transfer_function() {
+    // the linear segment near black
+    let dark = Color { r: 10, g: 10, b: 10 }.to_linear();
+    assert!((dark.r - 10.0 / 255.0 / 12.92).abs() < 1e-7);
+    // the midpoint of the encoded range is about 21.4% light
+    let mid = Color { r: 128, g: 128, b: 128 }.to_linear();
+    assert!((mid.r - 0.2158605).abs() < 1e-5);
+    assert_eq!(Color { r: 255, g: 255, b: 255 }.to_linear(), LinearColor { r: 1.0, g: 1.0, b: 1.0 });
+}
+
+#[test]
+fn test_linear_arithmetic() {
+    let a = LinearColor { r: 0.25, g: 0.5, b: 1.0 };
+    let b = LinearColor { r: 0.25, g: 0.25, b: 0.5 };
+    assert_eq!(a + b, LinearColor { r: 0.5, g: 0.75, b: 1.5 });
+    assert_eq!(a - b, LinearColor { r: 0.0, g: 0.25, b: 0.5 });
+    assert_eq!(a * 2.0, LinearColor { r: 0.5, g: 1.0, b: 2.0 });
+    assert_eq!(a / 2.0, LinearColor { r: 0.125, g: 0.25, b: 0.5 });
+    // out of range values clamp on the way back
+    assert_eq!((a * 4.0).to_srgb(), Color { r: 255, g: 255, b: 255 });
+    assert_eq!((b - a).to_srgb(), Color { r: 0, g: 0, b: 0 });
+}
+
+/// The sRGB transfer functions in f64, to check against.
+fn decode(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+fn encode(v: f64) -> u8 {
+    let v = v.clamp(0.0, 1.0);
+    let c = if v <= 0.0031308 { v * 12.92 } else { 1.055 * v.powf(1.0 / 2.4) - 0.055 };
+    (c * 255.0).round() as u8
+}
+
+#[test]
+fn test_scale_brightness() {
+    let colors = [Color::new(255, 102, 0), Color::new(128, 128, 128), Color::new(12, 200, 77)];
+    for color in colors {
+        assert_eq!(color.scale_brightness(1.0), color);
+        assert_eq!(color.scale_brightness(0.0), Color::new(0, 0, 0));
+        assert_eq!(color.scale_brightness(-1.0), Color::new(0, 0, 0));
+        assert_eq!(color.scale_brightness(f32::NAN), Color::new(0, 0, 0));
+    }
+    // one stop up and down from mid-gray
+    let gray = Color::new(128, 128, 128);
+    assert_eq!(gray.scale_brightness(2.0), Color::new(176, 176, 176));
+    assert_eq!(gray.scale_brightness(0.5), Color::new(92, 92, 92));
+    for (color, factor) in [(Color::new(200, 64, 10), 0.5), (Color::new(64, 30, 100), 1.5), (Color::new(90, 180, 250), 1.2)] {
+        let expected = |c: u8| encode(decode(c) * factor);
+        let scaled = color.scale_brightness(factor as f32);
+        assert_eq!(scaled, Color::new(expected(color.r), expected(color.g), expected(color.b)), "{:?} x {}", color, factor);
+    }
+    // the brightest channel clips
+    assert_eq!(Color::new(200, 100, 0).scale_brightness(4.0).r, 255);
+}
+
+#[test]
+fn test_apply_gamma() {
+    let gray = Color::new(128, 128, 128);
+    assert_eq!(gray.apply_gamma(1.0), gray);
+    assert_eq!(gray.apply_gamma(2.0), Color::new(61, 61, 61));
+    assert_eq!(gray.apply_gamma(0.5), Color::new(181, 181, 181));
+    for (color, gamma) in [(Color::new(188, 20, 240), 2.2), (Color::new(10, 100, 200), 0.8)] {
+        let expected = |c: u8| encode(decode(c).powf(gamma));
+        let curved = color.apply_gamma(gamma as f32);
+        assert_eq!(curved, Color::new(expected(color.r), expected(color.g), expected(color.b)), "{:?} ^ {}", color, gamma);
+    }
+    // the ends stay put
+    for gamma in [0.3, 1.0, 2.2, 10.0] {
+        assert_eq!(Color::new(0, 0, 0).apply_gamma(gamma), Color::new(0, 0, 0));
+        assert_eq!(Color::new(255, 255, 255).apply_gamma(gamma), Color::new(255, 255, 255));
+    }
+    for gamma in [0.0, -1.0, f32::NAN] {
+        assert_eq!(gray.apply_gamma(gamma), gray);
+    }
+}
+
+#[test]
+fn test_xyz_white_points() {
+    let white = Color { r: 255, g: 255, b: 255 };
+    let d65 = white.to_xyz(WhitePoint::D65);
+    assert!((d65.x - 0.9505).abs() < 1e-3);
+    assert!((d65.y - 1.0).abs() < 1e-3);
+    assert!((d65.z - 1.089).abs() < 1e-3);
+
+    // adapted white lands on the D50 white point
+    let d50 = white.to_xyz(WhitePoint::D50);
+    let expected = WhitePoint::D50.xyz();
+    assert!((d50.x - expected.x).abs() < 1e-3);
+    assert!((d50.y - expected.y).abs() < 1e-3);
+    assert!((d50.z - expected.z).abs() < 1e-3);
+
+    let black = Color { r: 0, g: 0, b: 0 }.to_xyz(WhitePoint::D65);
+    assert_eq!(black, Xyz { x: 0.0, y: 0.0, z: 0.0 });
+}
+
+#[test]
+fn test_xyz_round_trip() {
+    let samples = [
+        Color { r: 255, g: 0, b: 0 },
+        Color { r: 0, g: 255, b: 0 },
+        Color { r: 0, g: 0, b: 255 },
+        Color { r: 70, g: 130, b: 180 },
+        Color { r: 1, g: 2, b: 3 },
+        Color { r: 255, g: 255, b: 255 },
+    ];
+    for c in samples {
+        for white in [WhitePoint::D65, WhitePoint::D50] {
+            assert_eq!(c.to_xyz(white).to_color(white), c, "{:?} via {:?}", c, white);
+        }
+    }
+    // adapting there and back is stable too
+    let xyz = Color { r: 70, g: 130, b: 180 }.to_xyz(WhitePoint::D65);
+    let back = xyz.adapt(WhitePoint::D65, WhitePoint::D50).adapt(WhitePoint::D50, WhitePoint::D65);
+    assert!((back.x - xyz.x).abs() < 1e-4 && (back.y - xyz.y).abs() < 1e-4 && (back.z - xyz.z).abs() < 1e-4);
+}
+
+#[test]
+fn test_oklab_reference_values() {
+    let white = Color { r: 255, g: 255, b: 255 }.to_oklab();
+    assert!((white.l - 1.0).abs() < 1e-4);
+    assert!(white.a.abs() < 1e-4 && white.b.abs() < 1e-4);
+
+    // values from Ottosson's reference implementation
+    let red = Color { r: 255, g: 0, b: 0 }.to_oklab();
+    assert!((red.l - 0.6280).abs() < 1e-3);
+    assert!((red.a - 0.2249).abs() < 1e-3);
+    assert!((red.b - 0.1258).abs() < 1e-3);
+}
+
+#[test]
+fn test_oklab_round_trip() {
+    for r in (0..=255).step_by(15) {
+        for g in (0..=255).step_by(15) {
+            for b in (0..=255).step_by(15) {
+                let c = Color { r, g, b };
+                assert_eq!(c.to_oklab().to_color(), c);
+            }
+        }
+    }
+    // far outside the gamut is clamped, not wrapped
+    assert_eq!(Oklab { l: 2.0, a: 0.0, b: 0.0 }.to_color(), Color { r: 255, g: 255, b: 255 });
+}
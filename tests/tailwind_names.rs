@@ -0,0 +1,60 @@
+// Run with `cargo test --features tailwind-names --test tailwind_names`;
+// without the feature this file is empty.
+#![cfg(feature = "tailwind-names")]
+
+use colourss::{parse_color, tailwind_colors, ParseError};
+
+#[test]
+fn test_tailwind_values() {
+    for (name, hex) in [
+        ("sky-500", "#0ea5e9"),
+        ("slate-500", "#64748b"),
+        ("slate-950", "#020617"),
+        ("gray-50", "#f9fafb"),
+        ("zinc-950", "#09090b"),
+        ("neutral-700", "#404040"),
+        ("stone-400", "#a8a29e"),
+        ("emerald-300", "#6ee7b7"),
+        ("lime-600", "#65a30d"),
+        ("cyan-800", "#155e75"),
+        ("indigo-600", "#4f46e5"),
+        ("violet-200", "#ddd6fe"),
+        ("fuchsia-900", "#701a75"),
+        ("rose-100", "#ffe4e6"),
+    ] {
+        let prefixed = format!("tailwind:{}", name);
+        assert_eq!(parse_color(&prefixed).unwrap().to_hex(), hex, "{}", prefixed);
+        // with Material names too, `lime-600` and co. are Material's
+        if cfg!(not(feature = "material-names")) {
+            assert_eq!(parse_color(name).unwrap().to_hex(), hex, "{}", name);
+        }
+    }
+    assert_eq!(parse_color("TAILWIND:Sky-500").unwrap().to_hex(), "#0ea5e9");
+}
+
+#[test]
+fn test_tailwind_unknown() {
+    for input in ["sky-550", "sky", "tailwind:", "tailwind:red", "tailwind:steelblue", "sky 500", "tailwind: sky-500"] {
+        assert!(matches!(parse_color(input), Err(ParseError::UnknownColorName(_))), "{:?}", input);
+    }
+}
+
+#[test]
+fn test_tailwind_table() {
+    let names: Vec<&str> = tailwind_colors().map(|(name, _)| name).collect();
+    assert_eq!(names.len(), 22 * 11);
+    assert!(names.windows(2).all(|w| w[0] < w[1]));
+    for (name, color) in tailwind_colors() {
+        assert_eq!(parse_color(&format!("tailwind:{}", name)).unwrap(), color, "{}", name);
+    }
+}
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_tailwind_keeps_css_names() {
+    // CSS keywords keep their meaning
+    let names: Vec<&str> = tailwind_colors().map(|(name, _)| name).collect();
+    assert!(colourss::named_colors().all(|(name, _)| !names.contains(&name)));
+    assert_eq!(parse_color("gray").unwrap().to_hex(), "#808080");
+    assert_eq!(parse_color("tan").unwrap().to_hex(), "#d2b48c");
+}
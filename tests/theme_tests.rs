@@ -0,0 +1,84 @@
+// Run with `cargo test --features themes --test theme_tests` (the CLI
+// feature turns it on); without the feature this file is empty.
+#![cfg(feature = "themes")]
+
+use colourss::themes::{extract, ThemeError};
+use colourss::{ParseError, Rgba};
+
+fn read(name: &str) -> String {
+    std::fs::read_to_string(format!("tests/data/themes/{}", name)).unwrap()
+}
+
+#[test]
+fn test_extract_theme() {
+    let found = extract(&read("dark.json")).unwrap();
+    // `null`, `fontStyle`, `semanticTokenColors` and the rest aren't colors
+    assert_eq!(found.len(), 18);
+    assert_eq!(found[0].pointer, "/colors/editor.background");
+    assert_eq!(found[0].value, "#1E1E1E");
+    assert_eq!(found[0].color.as_ref().unwrap().to_color().to_hex(), "#1e1e1e");
+    assert!(found.iter().all(|f| f.color.is_ok()));
+    assert!(found.iter().all(|f| f.pointer != "/colors/tab.activeBorderTop"));
+
+    let shadow = found.iter().find(|f| f.pointer == "/colors/widget.shadow").unwrap();
+    assert_eq!(*shadow.color.as_ref().unwrap(), Rgba { r: 0, g: 0, b: 0, a: 92.0 / 255.0 });
+
+    let rules: Vec<&str> = found.iter().filter(|f| f.pointer.starts_with("/tokenColors")).map(|f| f.pointer.as_str()).collect();
+    assert_eq!(
+        rules,
+        [
+            "/tokenColors/0/settings/foreground",
+            "/tokenColors/2/settings/foreground",
+            "/tokenColors/3/settings/foreground",
+            "/tokenColors/4/settings/foreground",
+            "/tokenColors/4/settings/background",
+            "/tokenColors/5/settings/foreground",
+        ]
+    );
+}
+
+#[test]
+fn test_extract_theme_invalid_values() {
+    let found = extract(&read("typos.json")).unwrap();
+    assert_eq!(found.len(), 4);
+    assert!(matches!(found[0].color, Err(ParseError::InvalidHexFormat(_))));
+    assert!(found[1].color.is_ok());
+    // keys are escaped for the pointer
+    assert_eq!(found[2].pointer, "/colors/editor~1ruler~01");
+    assert_eq!(found[2].color.as_ref().unwrap_err().to_string(), "Unknown color name: \"whitee\"");
+
+    assert!(extract("{}").unwrap().is_empty());
+    assert!(extract(r#"{ "colors": [], "tokenColors": "./syntax.tmTheme" }"#).unwrap().is_empty());
+}
+
+#[test]
+fn test_extract_theme_comments() {
+    // comment markers inside strings stay, and so do escaped quotes
+    let found = extract(
+        r##"{
+            /* a "block" comment */ "colors": {
+                "a": "#000", // a line comment
+                "b\"/*": "#fff", /* unclosed in a string: "*/
+                "c": "//#f00",
+            },
+        }"##,
+    )
+    .unwrap();
+    let pointers: Vec<&str> = found.iter().map(|f| f.pointer.as_str()).collect();
+    assert_eq!(pointers, ["/colors/a", "/colors/b\"~1*", "/colors/c"]);
+    assert_eq!(found[2].value, "//#f00");
+    assert!(found[2].color.is_err());
+}
+
+#[test]
+fn test_extract_theme_errors() {
+    assert_eq!(
+        extract(&read("missing-comma.json")).unwrap_err(),
+        ThemeError::InvalidJson("expected `,` or `}` at line 4 column 5".to_string())
+    );
+    // positions count comments as they were written
+    let err = extract("{ /* ünïcödé */ ]").unwrap_err().to_string();
+    assert!(err.ends_with("at line 1 column 21"), "{}", err);
+    assert_eq!(extract("[]").unwrap_err(), ThemeError::NotAnObject);
+    assert_eq!(extract("[]").unwrap_err().to_string(), "Expected a JSON object at the top level");
+}
@@ -0,0 +1,39 @@
+//! Run with `wasm-pack test --node --no-default-features --features wasm`, or
+//! `cargo test --target wasm32-unknown-unknown --no-default-features --features wasm`
+//! with `wasm-bindgen-test-runner` set as the target runner.
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use colourss::wasm::{normalize, parse};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn test_parse() {
+    let c = parse("rgba(255, 0, 0, 0.5)").unwrap();
+    assert_eq!((c.r, c.g, c.b), (255, 0, 0));
+    assert_eq!(c.a, 0.5);
+    assert_eq!(c.format(), "rgb");
+
+    let c = parse("#00ff00").unwrap();
+    assert_eq!((c.r, c.g, c.b, c.a), (0, 255, 0, 1.0));
+    assert_eq!(c.format(), "hex");
+
+    assert!(parse("notacolor").is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_normalize() {
+    #[cfg(feature = "named-colors")]
+    assert_eq!(normalize("red", "hex").unwrap(), "#ff0000");
+    assert_eq!(normalize("#ff000080", "rgb").unwrap(), "rgba(255, 0, 0, 0.5019608)");
+    assert_eq!(normalize("rgb(0, 255, 0)", "hsl").unwrap(), "hsl(120, 100%, 50%)");
+    #[cfg(feature = "named-colors")]
+    assert_eq!(normalize("#0000ff", "named").unwrap(), "blue");
+    assert!(normalize("#f00", "cmyk").is_err());
+}
+
+#[cfg(feature = "named-colors")]
+#[wasm_bindgen_test]
+fn test_names() {
+    let names = colourss::wasm::names();
+    assert!(names.iter().any(|n| n == "rebeccapurple"));
+}
@@ -0,0 +1,89 @@
+// Runs the CSS color parsing vectors from web-platform-tests
+// (css/css-color/parsing/color-{computed,invalid}-*.html) through
+// `parse_color`.
+//
+// tests/data/wpt/color-parsing.json holds a hand-extracted subset of those
+// cases, tagged with the file each came from: `expected`
+// is the computed `rgb()`/`rgba()` serialization, or null when the input
+// must be rejected. Only the RGB channels are compared; `parse_color`
+// doesn't return alpha.
+//
+// Inputs we knowingly get wrong are listed in tests/data/wpt/allowlist.txt.
+// The test fails on any other mismatch, and also when an allowlisted input
+// starts passing, so the list only ever shrinks.
+//
+// The vectors use color keywords throughout, so they need the
+// `named-colors` feature.
+#![cfg(feature = "named-colors")]
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use colourss::{parse_color, Color};
+use serde_json::Value;
+
+fn data(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/wpt").join(name);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e))
+}
+
+/// The color part of an expected `rgb(r, g, b)` / `rgba(r, g, b, a)`.
+fn expected_color(serialized: &str) -> Color {
+    let inner = serialized
+        .strip_prefix("rgba(")
+        .or_else(|| serialized.strip_prefix("rgb("))
+        .and_then(|s| s.strip_suffix(')'))
+        .unwrap_or_else(|| panic!("bad expectation {:?}", serialized));
+    let channels: Vec<u8> = inner.split(", ").take(3).map(|v| v.parse().unwrap()).collect();
+    Color { r: channels[0], g: channels[1], b: channels[2] }
+}
+
+/// One input per line; blank lines and lines starting with `//` are skipped.
+fn allowlist() -> HashSet<String> {
+    data("allowlist.txt")
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with("//"))
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn test_wpt_color_parsing() {
+    let cases: Vec<Value> = serde_json::from_str(&data("color-parsing.json")).unwrap();
+    let allowed = allowlist();
+
+    let (mut passed, mut known) = (0, 0);
+    let mut unexpected = Vec::new();
+    let mut stale = Vec::new();
+    for case in &cases {
+        let input = case["input"].as_str().unwrap();
+        let expected = case["expected"].as_str().map(expected_color);
+        let actual = parse_color(input).ok();
+        let ok = actual == expected;
+        match (ok, allowed.contains(input)) {
+            (true, false) => passed += 1,
+            (false, true) => known += 1,
+            (true, true) => stale.push(input),
+            (false, false) => unexpected.push(format!(
+                "{:?} ({}): expected {:?}, got {:?}",
+                input, case["source"].as_str().unwrap_or("?"), expected, actual
+            )),
+        }
+    }
+
+    println!(
+        "wpt: {} passed, {} known failures, {} unexpected failures, {} stale allowlist entries",
+        passed,
+        known,
+        unexpected.len(),
+        stale.len()
+    );
+    assert!(unexpected.is_empty(), "unexpected failures:\n{}", unexpected.join("\n"));
+    assert!(stale.is_empty(), "now passing, remove from allowlist.txt: {:?}", stale);
+
+    // every allowlist entry has to be a real case
+    let inputs: HashSet<&str> = cases.iter().map(|c| c["input"].as_str().unwrap()).collect();
+    let orphans: Vec<_> = allowed.iter().filter(|a| !inputs.contains(a.as_str())).collect();
+    assert!(orphans.is_empty(), "allowlist entries with no matching case: {:?}", orphans);
+}
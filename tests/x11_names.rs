@@ -0,0 +1,66 @@
+// Run with `cargo test --features x11-names --test x11_names`; without
+// the feature this file is empty.
+#![cfg(feature = "x11-names")]
+
+use colourss::{parse_color_with, x11_colors, NameSet, ParseError, ParseOptions};
+
+fn with(name_set: NameSet) -> ParseOptions {
+    ParseOptions { name_set, ..ParseOptions::default() }
+}
+
+fn hex(input: &str, name_set: NameSet) -> Option<String> {
+    parse_color_with(input, &with(name_set)).ok().map(|color| color.to_hex())
+}
+
+#[cfg(feature = "named-colors")]
+#[test]
+fn test_divergent_names() {
+    for (name, css, x11) in [
+        ("green", "#008000", "#00ff00"),
+        ("gray", "#808080", "#bebebe"),
+        ("maroon", "#800000", "#b03060"),
+        ("purple", "#800080", "#a020f0"),
+    ] {
+        assert_eq!(hex(name, NameSet::Css).unwrap(), css, "{}", name);
+        assert_eq!(hex(name, NameSet::X11).unwrap(), x11, "{}", name);
+        // CSS wins
+        assert_eq!(hex(name, NameSet::Both).unwrap(), css, "{}", name);
+    }
+    // the same everywhere
+    for name_set in [NameSet::Css, NameSet::X11, NameSet::Both] {
+        assert_eq!(hex("navajowhite", name_set).unwrap(), "#ffdead");
+        assert_eq!(hex("SteelBlue", name_set).unwrap(), "#4682b4");
+    }
+}
+
+#[test]
+fn test_x11_only_names() {
+    for (input, expected) in [
+        ("navajo white", "#ffdead"),
+        ("Navajo White", "#ffdead"),
+        ("gray42", "#6b6b6b"),
+        ("Grey42", "#6b6b6b"),
+        ("gray0", "#000000"),
+        ("gray100", "#ffffff"),
+        ("snow3", "#cdc9c9"),
+        ("light goldenrod", "#eedd82"),
+    ] {
+        assert_eq!(hex(input, NameSet::X11).unwrap(), expected, "{:?}", input);
+        assert_eq!(hex(input, NameSet::Both).unwrap(), expected, "{:?}", input);
+        assert!(hex(input, NameSet::Css).is_none(), "{:?}", input);
+    }
+    for input in ["gray101", "rebeccapurple", "lime", "navajo-white"] {
+        let result = parse_color_with(input, &with(NameSet::X11));
+        assert!(matches!(result, Err(ParseError::UnknownColorName(n)) if n == input), "{:?}", input);
+    }
+}
+
+#[test]
+fn test_x11_table() {
+    let names: Vec<&str> = x11_colors().map(|(name, _)| name).collect();
+    assert!(names.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(names.iter().filter(|name| name.starts_with("gray") && name[4..].parse::<u8>().is_ok()).count(), 101);
+    for (name, color) in x11_colors() {
+        assert_eq!(parse_color_with(name, &with(NameSet::X11)).unwrap(), color, "{}", name);
+    }
+}